@@ -1,3 +1,6 @@
+const AMBIENT_LEVEL: f32 = 0.08;
+const AO_LEVEL_MIN: f32 = 0.35;
+
 @group(1) @binding(0)
 var texture_atlas: texture_2d_array<f32>;
 
@@ -7,6 +10,8 @@ var texture_atlas_sampler: sampler;
 struct FragmentInput {
     @location(0) uv: vec2<f32>,
     @location(1) layer_index: u32,
+    @location(2) light: vec2<f32>,
+    @location(3) ao: f32,
 }
 
 struct FragmentOutput {
@@ -24,7 +29,11 @@ fn main(input: FragmentInput) -> FragmentOutput {
         input.layer_index
     );
 
-    output.color = color;
+    let light_level = max(input.light.x, input.light.y);
+    let occlusion = mix(AO_LEVEL_MIN, 1.0, input.ao);
+    let brightness = mix(AMBIENT_LEVEL, 1.0, light_level * light_level) * occlusion;
+
+    output.color = vec4<f32>(color.rgb * brightness, color.a);
     
     return output;
 }
@@ -11,12 +11,16 @@ struct VertexInput {
     @location(1) normal: vec3<f32>,
     @location(2) uv: vec2<f32>,
     @location(3) layer_index: u32,
+    @location(4) light: vec2<f32>,
+    @location(5) ao: f32,
 };
 
 struct VertexOutput {
     @builtin(position) Position: vec4<f32>,
     @location(0) uv: vec2<f32>,
     @location(1) layer_index: u32,
+    @location(2) light: vec2<f32>,
+    @location(3) ao: f32,
 };
 
 @vertex
@@ -25,6 +29,8 @@ fn main(input: VertexInput) -> VertexOutput {
     output.Position = camera_uniform_data.view_projection_matrix * vec4<f32>(input.position, 1.0);
     output.uv = input.uv;
     output.layer_index = input.layer_index;
+    output.light = input.light;
+    output.ao = input.ao;
 
     return output;
 }
@@ -0,0 +1,178 @@
+//! Per-tick cost of Physics with PERSON_MAX_COUNT persons on a shared floor, and the
+//! per-call cost of a single axis resolution among pillars, bisection before and swept after
+//!
+//! Run with `cargo bench --bench physics`
+
+use last_ditch::simulation::{
+    constants::PERSON_MAX_COUNT,
+    state::{
+        physics::axis_resolution::AxisResolution,
+        population::person::Person,
+        world::{
+            block::block_kind::BlockKind,
+            grid::{axis::Axis, Direction},
+        },
+        Physics, Population, World,
+    },
+};
+use last_ditch::utils::ldmath::FloatBox;
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+use ultraviolet::{IVec3, Vec3};
+
+const BENCH_SEED: u64 = 813;
+const BENCH_TICK_COUNT: u32 = 200;
+const BENCH_FLOOR_RADIUS: i32 = 24;
+const BENCH_SWEEP_COUNT: u32 = 100_000;
+
+fn create_world() -> World {
+    let mut world = World::new(BENCH_SEED);
+
+    World::set_block_cube(
+        IVec3::new(-BENCH_FLOOR_RADIUS, -BENCH_FLOOR_RADIUS, -1),
+        IVec3::new(BENCH_FLOOR_RADIUS, BENCH_FLOOR_RADIUS, -1),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut world,
+    );
+
+    for direction in &Direction::ALL[0..4] {
+        let wall_center = Direction::to_ivec3(direction) * BENCH_FLOOR_RADIUS;
+        let wall_extent = IVec3::new(wall_center.y.abs(), wall_center.x.abs(), 0);
+
+        World::set_block_cube(
+            wall_center - wall_extent,
+            wall_center + wall_extent + IVec3::new(0, 0, 2),
+            &Direction::North,
+            &BlockKind::Metal1,
+            &mut world,
+        );
+    }
+
+    for x in (-BENCH_FLOOR_RADIUS + 2..BENCH_FLOOR_RADIUS - 1).step_by(6) {
+        for y in (-BENCH_FLOOR_RADIUS + 2..BENCH_FLOOR_RADIUS - 1).step_by(6) {
+            World::set_block_cube(
+                IVec3::new(x, y, 0),
+                IVec3::new(x, y, 2),
+                &Direction::North,
+                &BlockKind::Metal1,
+                &mut world,
+            );
+        }
+    }
+
+    world
+}
+
+fn create_population() -> Population {
+    let mut population = Population::new(BENCH_SEED);
+
+    let row_length = (PERSON_MAX_COUNT as f32).sqrt().ceil() as usize;
+
+    for index in 0..PERSON_MAX_COUNT {
+        let mut person = Population::generate_person(&mut population);
+
+        let world_position = Vec3::new(
+            2.0 * (index % row_length) as f32 - row_length as f32,
+            2.0 * (index / row_length) as f32 - row_length as f32,
+            1.0 + 0.5 * (index % 4) as f32,
+        );
+
+        let velocity = Vec3::new(
+            ((index % 3) as f32 - 1.0) * 2.0,
+            ((index % 5) as f32 - 2.0) * 1.0,
+            0.0,
+        );
+
+        Person::set_world_position(world_position, &mut person);
+        Person::set_velocity(velocity, &mut person);
+
+        population.person_map.insert(person.person_id, person);
+    }
+
+    population
+}
+
+fn report(label: &str, elapsed: Duration) {
+    let tick_in_microseconds = elapsed.as_secs_f64() * 1_000_000.0 / BENCH_TICK_COUNT as f64;
+
+    println!(
+        "{label:<24} {tick_in_microseconds:>12.1} us/tick {:>10.2} us/person",
+        tick_in_microseconds / PERSON_MAX_COUNT as f64
+    );
+}
+
+fn report_sweep(label: &str, elapsed: Duration) {
+    let sweep_in_nanoseconds = elapsed.as_secs_f64() * 1_000_000_000.0 / BENCH_SWEEP_COUNT as f64;
+
+    println!("{label:<24} {sweep_in_nanoseconds:>12.1} ns/sweep");
+}
+
+fn run(world: &World) -> Duration {
+    let mut population = create_population();
+    let mut physics = Physics::new();
+
+    let instant = Instant::now();
+
+    for _ in 0..BENCH_TICK_COUNT {
+        Physics::tick(world, &mut population, &mut physics);
+    }
+
+    black_box(&population);
+
+    instant.elapsed()
+}
+
+fn run_sweep(
+    world: &World,
+    resolve_fn: impl Fn(&FloatBox, Axis, f32, &World) -> AxisResolution,
+) -> Duration {
+    let axis_array = [Axis::X, Axis::Y, Axis::Z];
+
+    let instant = Instant::now();
+
+    for index in 0..BENCH_SWEEP_COUNT {
+        let offset = (index % 97) as f32 * 0.37 - 18.0;
+
+        let float_box = FloatBox::new(Vec3::new(offset, -offset, 0.5), Vec3::new(0.3, 0.3, 0.95));
+
+        let delta_intent = if index % 2 == 0 { 2.5 } else { -2.5 };
+
+        black_box(resolve_fn(
+            &float_box,
+            axis_array[index as usize % 3],
+            delta_intent,
+            world,
+        ));
+    }
+
+    instant.elapsed()
+}
+
+fn main() {
+    let world = create_world();
+
+    println!(
+        "{PERSON_MAX_COUNT} persons, {} threads, {BENCH_TICK_COUNT} ticks per case",
+        rayon::current_num_threads()
+    );
+
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .expect("Failed to build thread pool");
+
+    report("single thread", thread_pool.install(|| run(&world)));
+    report("parallel", run(&world));
+
+    report_sweep(
+        "bisection (before)",
+        run_sweep(&world, Physics::compute_axis_resolution_bisection),
+    );
+    report_sweep(
+        "swept (after)",
+        run_sweep(&world, Physics::compute_axis_resolution),
+    );
+}
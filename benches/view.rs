@@ -0,0 +1,145 @@
+//! Per-tick cost of WorldView publication with the full tower generated
+//!
+//! Run with `cargo bench --bench view`
+
+use last_ditch::simulation::{
+    state::{population::person::person_id::PersonID, State, World},
+    supervisor::{
+        message::SeedData,
+        viewer::view::{SectorView, WorldView},
+        Message, Viewer,
+    },
+    Simulation,
+};
+use std::{
+    collections::HashMap,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+const BENCH_SEED: u64 = 813;
+const BENCH_TICK_COUNT: u32 = 200;
+const BENCH_GENERATE_TICK_LIMIT: u32 = 10_000;
+
+fn build_full_world_view(person_id: &PersonID, state: &State) -> WorldView {
+    let mut world_view = WorldView::default();
+
+    for sector_index in Viewer::get_visible_sector_index_vec(person_id, state) {
+        let Some(sector) = World::get_sector_by_index(&sector_index, &state.world.sector_map)
+        else {
+            continue;
+        };
+
+        world_view
+            .sector_view_map
+            .insert(sector_index, SectorView::new_from_sector(sector));
+    }
+
+    world_view
+}
+
+fn report(label: &str, elapsed: Duration, sector_count: usize) {
+    let tick_in_microseconds = elapsed.as_secs_f64() * 1_000_000.0 / BENCH_TICK_COUNT as f64;
+
+    println!(
+        "{label:<24} {tick_in_microseconds:>12.1} us/tick {:>10.1} sectors/tick",
+        sector_count as f64 / BENCH_TICK_COUNT as f64
+    );
+}
+
+fn main() {
+    let (message_tx, message_rx) = crossbeam::channel::unbounded::<Message>();
+    let (view_input, mut view_output) = Viewer::create_view_channel();
+
+    let mut simulation = Box::new(Simulation::new(message_rx, view_input));
+
+    message_tx
+        .send(Message::SetSeed(SeedData { seed: BENCH_SEED }))
+        .expect("Message Send Failed");
+    message_tx
+        .send(Message::Generate)
+        .expect("Message Send Failed");
+
+    let mut generate_tick_count = 0;
+
+    while generate_tick_count < BENCH_GENERATE_TICK_LIMIT {
+        Simulation::run_headless(
+            1,
+            &mut simulation.supervisor,
+            &mut simulation.state,
+            |_, _| (),
+        );
+
+        Viewer::get_view(&mut view_output);
+
+        generate_tick_count += 1;
+
+        let generating = !simulation.state.work.construct_worker.task_deque.is_empty();
+
+        if simulation.state.active && !generating {
+            break;
+        }
+    }
+
+    let visible_sector_count =
+        Viewer::get_visible_sector_index_vec(&PersonID::JUDGE_ID_1, &simulation.state).len();
+
+    println!(
+        "Generated in {generate_tick_count} ticks, {visible_sector_count} visible sectors, {BENCH_TICK_COUNT} ticks per case"
+    );
+
+    let instant = Instant::now();
+
+    for _ in 0..BENCH_TICK_COUNT {
+        black_box(build_full_world_view(
+            &PersonID::JUDGE_ID_1,
+            &simulation.state,
+        ));
+    }
+
+    report(
+        "full rebuild (before)",
+        instant.elapsed(),
+        visible_sector_count * BENCH_TICK_COUNT as usize,
+    );
+
+    let mut sector_version_map = HashMap::new();
+
+    Viewer::update_world_view_delta(
+        &PersonID::JUDGE_ID_1,
+        &simulation.state,
+        &mut sector_version_map,
+    );
+
+    let mut sector_count = 0;
+
+    let instant = Instant::now();
+
+    for _ in 0..BENCH_TICK_COUNT {
+        let world_view_delta = Viewer::update_world_view_delta(
+            &PersonID::JUDGE_ID_1,
+            &simulation.state,
+            &mut sector_version_map,
+        );
+
+        sector_count += world_view_delta.sector_view_vec.len();
+
+        black_box(world_view_delta);
+    }
+
+    report("delta (after)", instant.elapsed(), sector_count);
+
+    let mut sector_count = 0;
+
+    let instant = Instant::now();
+
+    for _ in 0..BENCH_TICK_COUNT {
+        Viewer::tick(&simulation.state, &mut simulation.supervisor);
+
+        for world_view_delta in view_output.world_view_delta_rx.try_iter() {
+            sector_count += world_view_delta.sector_view_vec.len();
+        }
+    }
+
+    report("Viewer::tick (after)", instant.elapsed(), sector_count);
+}
@@ -0,0 +1,228 @@
+use last_ditch::{
+    simulation::{
+        constants::{SCHEMATIC_DIRECTORY, SIMULATION_TICK_DURATION},
+        state::{population::person::person_id::PersonID, world::schematic::Schematic},
+        summary::Summary,
+        supervisor::{
+            message::{SchematicSetData, SeedData},
+            recorder::RecorderMode,
+            supervisor_status::SupervisorStatus,
+            Control, Message, Recorder, Server, Viewer,
+        },
+        Simulation,
+    },
+    utils::tracer::Tracer,
+};
+use std::{
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::OnceLock,
+    time::Instant,
+};
+
+static TRACER: OnceLock<Tracer> = OnceLock::new();
+
+const USAGE: &str = "Usage: headless [--seed <u64>] [--ticks <u32, 0 runs until quit>] [--generate] [--checksum-interval <u32>] [--record <path> | --replay <path>] [--control <address>] [--server <address>]";
+
+struct HeadlessArgs {
+    seed_option: Option<u64>,
+    ticks: u32,
+    generate: bool,
+    checksum_interval_option: Option<u32>,
+    recorder_mode: RecorderMode,
+    control_address_option: Option<String>,
+    server_address_option: Option<String>,
+}
+
+impl HeadlessArgs {
+    fn parse(mut arg_iter: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut headless_args = Self {
+            seed_option: None,
+            ticks: 1000,
+            generate: false,
+            checksum_interval_option: None,
+            recorder_mode: RecorderMode::Off,
+            control_address_option: None,
+            server_address_option: None,
+        };
+
+        while let Some(arg) = arg_iter.next() {
+            match arg.as_str() {
+                "--seed" => {
+                    let value = arg_iter.next().ok_or("--seed requires a value")?;
+
+                    let seed = value
+                        .parse::<u64>()
+                        .map_err(|_| format!("invalid seed: {value}"))?;
+
+                    headless_args.seed_option = Some(seed);
+                }
+                "--ticks" => {
+                    let value = arg_iter.next().ok_or("--ticks requires a value")?;
+
+                    let ticks = value
+                        .parse::<u32>()
+                        .map_err(|_| format!("invalid tick count: {value}"))?;
+
+                    headless_args.ticks = ticks;
+                }
+                "--generate" => headless_args.generate = true,
+                "--checksum-interval" => {
+                    let value = arg_iter
+                        .next()
+                        .ok_or("--checksum-interval requires a value")?;
+
+                    let checksum_interval = value
+                        .parse::<u32>()
+                        .map_err(|_| format!("invalid checksum interval: {value}"))?;
+
+                    headless_args.checksum_interval_option = Some(checksum_interval);
+                }
+                "--record" => {
+                    let path = arg_iter.next().ok_or("--record requires a path")?;
+
+                    headless_args.recorder_mode = RecorderMode::Record(PathBuf::from(path));
+                }
+                "--replay" => {
+                    let path = arg_iter.next().ok_or("--replay requires a path")?;
+
+                    headless_args.recorder_mode = RecorderMode::Replay(PathBuf::from(path));
+                }
+                "--control" => {
+                    let address = arg_iter.next().ok_or("--control requires an address")?;
+
+                    headless_args.control_address_option = Some(address);
+                }
+                "--server" => {
+                    let address = arg_iter.next().ok_or("--server requires an address")?;
+
+                    headless_args.server_address_option = Some(address);
+                }
+                _ => return Err(format!("unknown argument: {arg}")),
+            }
+        }
+
+        Ok(headless_args)
+    }
+}
+
+fn main() -> ExitCode {
+    let headless_args = match HeadlessArgs::parse(std::env::args().skip(1)) {
+        Ok(headless_args) => headless_args,
+        Err(error) => {
+            eprintln!("{error}\n{USAGE}");
+
+            return ExitCode::from(2);
+        }
+    };
+
+    let tracer = Tracer::new();
+    TRACER.set(tracer).unwrap();
+
+    let (message_tx, message_rx) = crossbeam::channel::unbounded::<Message>();
+    let (view_input, _view_output) = Viewer::create_view_channel();
+
+    let mut simulation = Box::new(Simulation::new(message_rx, view_input));
+
+    // Nothing drains the local view, so world deltas would queue for the whole run
+    Viewer::detach(&PersonID::JUDGE_ID_1, &mut simulation.supervisor.viewer);
+
+    simulation.supervisor.recorder = match Recorder::new(headless_args.recorder_mode) {
+        Ok(recorder) => recorder,
+        Err(error) => {
+            eprintln!("Failed to load replay: {error}");
+
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Some(control_address) = &headless_args.control_address_option {
+        simulation.supervisor.control = match Control::bind(control_address) {
+            Ok(control) => control,
+            Err(error) => {
+                eprintln!("Failed to bind control address {control_address}: {error}");
+
+                return ExitCode::FAILURE;
+            }
+        };
+    }
+
+    if let Some(server_address) = &headless_args.server_address_option {
+        simulation.supervisor.server = match Server::bind(server_address) {
+            Ok(server) => server,
+            Err(error) => {
+                eprintln!("Failed to bind server address {server_address}: {error}");
+
+                return ExitCode::FAILURE;
+            }
+        };
+    }
+
+    if let Some(checksum_interval) = headless_args.checksum_interval_option {
+        simulation.supervisor.checksum_interval = checksum_interval;
+    }
+
+    if let Some(seed) = headless_args.seed_option {
+        message_tx
+            .send(Message::SetSeed(SeedData { seed }))
+            .expect("Message Send Failed");
+    }
+
+    if headless_args.generate {
+        let schematic_set_data = SchematicSetData {
+            schematic_map: Schematic::load_directory(Path::new(SCHEMATIC_DIRECTORY)),
+        };
+
+        message_tx
+            .send(Message::SetSchematics(schematic_set_data))
+            .expect("Message Send Failed");
+
+        message_tx
+            .send(Message::Generate)
+            .expect("Message Send Failed");
+    }
+
+    let instant = Instant::now();
+
+    let serving = headless_args.server_address_option.is_some();
+
+    let tick_limit = if headless_args.ticks == 0 {
+        u32::MAX
+    } else {
+        headless_args.ticks
+    };
+
+    let tick_count = Simulation::run_headless(
+        tick_limit,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |supervisor, _| {
+            if supervisor.supervisor_status == SupervisorStatus::Run
+                && supervisor.checksum_tick == supervisor.tick
+            {
+                println!(
+                    "Checksum {}: {:016x}",
+                    supervisor.checksum_tick, supervisor.checksum
+                );
+            }
+
+            if serving {
+                std::thread::sleep(SIMULATION_TICK_DURATION);
+            }
+        },
+    );
+
+    let elapsed = instant.elapsed();
+
+    let summary = Summary::new(&simulation.state, &simulation.supervisor);
+
+    println!("{summary}");
+    println!("Ran {} ticks in {:.3}s", tick_count, elapsed.as_secs_f64());
+
+    #[cfg(feature = "profile")]
+    if let Some(tracer) = TRACER.get() {
+        Tracer::export(&tracer.flamegraph_name);
+    }
+
+    ExitCode::SUCCESS
+}
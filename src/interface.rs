@@ -3,6 +3,8 @@
 pub mod app;
 pub mod asset_manager;
 pub mod camera;
+pub mod client;
+pub mod console;
 pub mod constants;
 pub mod gpu;
 pub mod input;
@@ -15,7 +17,7 @@ use crate::{
         camera::Camera,
         constants::*,
         gpu::gpu_context::GPUContext,
-        input::{input_mode::InputMode, Input},
+        input::{input_mode::InputMode, key_inputs::KeyInputs, Input},
         interface_mode::InterfaceMode,
         renderer::{
             block_renderer::BlockRenderer, debug_renderer::DebugRenderer,
@@ -25,11 +27,19 @@ use crate::{
     },
     simulation::{
         self,
-        supervisor::{supervisor_status::SupervisorStatus, viewer::view::View, Message, Viewer},
+        supervisor::{
+            supervisor_status::SupervisorStatus,
+            viewer::{
+                view::{View, WorldView},
+                ViewOutput,
+            },
+            Message, Viewer,
+        },
     },
 };
 use std::{collections::VecDeque, sync::Arc, time::Instant};
 use tracing::instrument;
+use ultraviolet::Vec2;
 use winit::{
     dpi::PhysicalSize,
     event::{DeviceEvent, WindowEvent},
@@ -46,13 +56,13 @@ pub struct Interface<'window> {
     pub camera: Camera,
     pub renderer: Renderer,
     pub gpu_context: GPUContext<'window>,
-    pub view_output: triple_buffer::Output<View>,
+    pub view_output: ViewOutput,
 }
 
 impl<'window> Interface<'window> {
     pub fn new(
         message_tx: crossbeam::channel::Sender<Message>,
-        view_output: triple_buffer::Output<View>,
+        view_output: ViewOutput,
         event_loop: &ActiveEventLoop,
     ) -> Self {
         let interface_mode = InterfaceMode::Setup;
@@ -177,7 +187,7 @@ impl<'window> Interface<'window> {
         let next_instant = interface.last_instant + INTERFACE_FRAME_DURATION;
         interface.last_instant = instant;
 
-        let view = Viewer::get_view(&mut interface.view_output);
+        let (view, world_view) = Viewer::get_view(&mut interface.view_output);
 
         if view.supervisor_view.supervisor_status == SupervisorStatus::Done {
             event_loop.exit();
@@ -192,10 +202,18 @@ impl<'window> Interface<'window> {
                 &mut interface.asset_manager,
                 &mut interface.renderer,
             ),
-            InterfaceMode::Menu => Self::update_menu_mode(),
+            InterfaceMode::Menu => Self::update_menu_mode(
+                view,
+                &mut interface.gpu_context,
+                &mut interface.interface_mode,
+                &mut interface.input,
+                &mut interface.renderer.overlay_renderer,
+            ),
             InterfaceMode::Run => Self::update_run_mode(
                 view,
+                world_view,
                 &interface.gpu_context,
+                &mut interface.input,
                 &interface.asset_manager,
                 &mut interface.camera,
                 &mut interface.renderer,
@@ -230,6 +248,7 @@ impl<'window> Interface<'window> {
         message_deque.extend(overlay_message_deque);
 
         let input_message_deque = Input::get_message_deque(
+            input.person_id,
             &input.key_inputs,
             &mut input.mouse_inputs,
             &mut input.message_deque,
@@ -328,29 +347,49 @@ impl<'window> Interface<'window> {
     }
 
     #[instrument(skip_all)]
-    fn update_menu_mode() {}
+    fn update_menu_mode(
+        view: &View,
+        gpu_context: &mut GPUContext,
+        interface_mode: &mut InterfaceMode,
+        input: &mut Input,
+        overlay_renderer: &mut OverlayRenderer,
+    ) {
+        if view.supervisor_view.supervisor_status == SupervisorStatus::Run {
+            input.input_mode = InputMode::Game;
+
+            OverlayRenderer::set_main_window_active(false, gpu_context, overlay_renderer);
+
+            *interface_mode = InterfaceMode::Run;
+        }
+    }
 
     #[instrument(skip_all)]
     fn update_run_mode(
         view: &View,
+        world_view: &WorldView,
         gpu_context: &GPUContext,
+        input: &mut Input,
         asset_manager: &AssetManager,
         camera: &mut Camera,
         renderer: &mut Renderer,
     ) {
+        input.person_id = view.population_view.leadership_view.judge_id;
+
+        Self::update_console_input_mode(gpu_context, input, &renderer.overlay_renderer);
+
         Camera::apply_view(gpu_context, view, camera);
 
         BlockRenderer::apply_world_view(
             gpu_context,
             asset_manager,
-            &view.world_view,
+            world_view,
             &mut renderer.block_renderer,
         );
 
         SectorRenderer::apply_world_view(
             gpu_context,
             asset_manager,
-            &view.world_view,
+            world_view,
             &mut renderer.sector_renderer,
         );
 
@@ -366,6 +405,40 @@ impl<'window> Interface<'window> {
         DebugRenderer::apply_debug_view(gpu_context, view, &mut renderer.debug_renderer);
     }
 
+    fn update_console_input_mode(
+        gpu_context: &GPUContext,
+        input: &mut Input,
+        overlay_renderer: &OverlayRenderer,
+    ) {
+        let console_active = overlay_renderer.content.console_content.console_active;
+
+        match (console_active, &input.input_mode) {
+            (true, InputMode::Game) => {
+                input.input_mode = InputMode::Overlay;
+                input.key_inputs = KeyInputs::new();
+
+                gpu_context.window_arc.set_cursor_visible(true);
+
+                gpu_context
+                    .window_arc
+                    .set_cursor_grab(winit::window::CursorGrabMode::None)
+                    .expect("Failed to grab cursor");
+            }
+            (false, InputMode::Overlay) => {
+                input.input_mode = InputMode::Game;
+                input.mouse_inputs.delta = Vec2::zero();
+
+                gpu_context.window_arc.set_cursor_visible(false);
+
+                gpu_context
+                    .window_arc
+                    .set_cursor_grab(winit::window::CursorGrabMode::Locked)
+                    .expect("Failed to grab cursor");
+            }
+            _ => (),
+        }
+    }
+
     #[instrument(skip_all)]
     pub fn handle_window_event(event: &WindowEvent, interface: &mut Self) {
         match event {
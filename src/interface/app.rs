@@ -1,6 +1,9 @@
 use crate::{
-    interface::Interface,
-    simulation::{supervisor::Message, Simulation},
+    interface::{client::Client, Interface},
+    simulation::{
+        supervisor::{recorder::RecorderMode, Control, Message, Recorder, Server, Viewer},
+        Simulation,
+    },
 };
 use winit::{
     application::ApplicationHandler, event::WindowEvent, event_loop::ActiveEventLoop,
@@ -9,21 +12,72 @@ use winit::{
 
 #[derive(Default)]
 pub struct App<'window> {
+    recorder_mode: RecorderMode,
+    control_address_option: Option<String>,
+    server_address_option: Option<String>,
+    connect_address_option: Option<String>,
     interface_option: Option<Interface<'window>>,
     simulation_handle: Option<std::thread::JoinHandle<()>>,
 }
 
 impl<'window> App<'window> {
+    pub fn new(
+        recorder_mode: RecorderMode,
+        control_address_option: Option<String>,
+        server_address_option: Option<String>,
+        connect_address_option: Option<String>,
+    ) -> Self {
+        Self {
+            recorder_mode,
+            control_address_option,
+            server_address_option,
+            connect_address_option,
+            interface_option: None,
+            simulation_handle: None,
+        }
+    }
+
     pub fn start(
         event_loop: &ActiveEventLoop,
+        recorder_mode: &RecorderMode,
+        control_address_option: &Option<String>,
+        server_address_option: &Option<String>,
+        connect_address_option: &Option<String>,
         interface_option: &mut Option<Interface<'window>>,
         simulation_handle: &mut Option<std::thread::JoinHandle<()>>,
     ) {
         let (message_tx, message_rx) = crossbeam::channel::unbounded::<Message>();
-        let (view_input, view_output) = triple_buffer::triple_buffer(&Default::default());
+        let (view_input, view_output) = Viewer::create_view_channel();
+
+        if let Some(connect_address) = connect_address_option {
+            let mut client = Client::connect(connect_address, message_rx, view_input)
+                .expect("Failed to connect to server");
+
+            *interface_option = Some(Interface::new(message_tx, view_output, event_loop));
+
+            *simulation_handle = Some(std::thread::spawn(move || Client::run(&mut client)));
+
+            return;
+        }
 
         let mut simulation = Box::new(Simulation::new(message_rx, view_input));
 
+        simulation.supervisor.recorder =
+            Recorder::new(recorder_mode.clone()).expect("Failed to load replay");
+
+        // Only interactive sessions autosave, replays reproduce a recorded run
+        simulation.supervisor.autosave = !matches!(recorder_mode, RecorderMode::Replay(_));
+
+        if let Some(control_address) = control_address_option {
+            simulation.supervisor.control =
+                Control::bind(control_address).expect("Failed to bind control address");
+        }
+
+        if let Some(server_address) = server_address_option {
+            simulation.supervisor.server =
+                Server::bind(server_address).expect("Failed to bind server address");
+        }
+
         *interface_option = Some(Interface::new(message_tx, view_output, event_loop));
 
         *simulation_handle = Some(std::thread::spawn(move || {
@@ -36,6 +90,10 @@ impl<'window> ApplicationHandler for App<'window> {
     fn resumed(&mut self, event_loop: &ActiveEventLoop) {
         Self::start(
             event_loop,
+            &self.recorder_mode,
+            &self.control_address_option,
+            &self.server_address_option,
+            &self.connect_address_option,
             &mut self.interface_option,
             &mut self.simulation_handle,
         );
@@ -10,10 +10,7 @@ use crate::{
         constants::*,
         gpu::gpu_context::GPUContext,
     },
-    simulation::{
-        state::population::person::person_id::PersonID,
-        supervisor::viewer::view::{PersonView, View},
-    },
+    simulation::supervisor::viewer::view::{PersonView, View},
 };
 use tracing::instrument;
 use ultraviolet::{Mat4, Vec3, Vec4};
@@ -97,7 +94,7 @@ impl Camera {
         if let Some(person_view) = view
             .population_view
             .person_view_map
-            .get(&PersonID::JUDGE_ID_1)
+            .get(&view.population_view.leadership_view.judge_id)
         {
             Self::update_camera(&person_view, camera);
         }
@@ -0,0 +1,114 @@
+//! Remote Simulation bridge
+
+use crate::{
+    interface::constants::CLIENT_POLL_DURATION,
+    simulation::{
+        network::{ClientPacket, FrameStream, ServerPacket, ViewPacket},
+        state::population::person::person_id::PersonID,
+        supervisor::{
+            supervisor_status::SupervisorStatus,
+            viewer::{view::WorldViewDelta, ViewInput},
+            Message,
+        },
+    },
+};
+use std::{io, net::TcpStream};
+
+pub struct Client {
+    pub frame_stream: FrameStream,
+    pub message_rx: crossbeam::channel::Receiver<Message>,
+    pub view_input: ViewInput,
+    pub person_id_option: Option<PersonID>,
+}
+
+impl Client {
+    pub fn connect(
+        address: &str,
+        message_rx: crossbeam::channel::Receiver<Message>,
+        view_input: ViewInput,
+    ) -> io::Result<Self> {
+        let stream = TcpStream::connect(address)?;
+        let frame_stream = FrameStream::new(stream)?;
+
+        tracing::info!("Connected to {}", address);
+
+        let client = Self {
+            frame_stream,
+            message_rx,
+            view_input,
+            person_id_option: None,
+        };
+
+        Ok(client)
+    }
+
+    pub fn run(client: &mut Self) {
+        while Self::tick(client) {
+            std::thread::sleep(CLIENT_POLL_DURATION);
+        }
+    }
+
+    pub fn tick(client: &mut Self) -> bool {
+        while let Ok(message) = client.message_rx.try_recv() {
+            if matches!(message, Message::Quit) {
+                Self::publish_done(client);
+
+                return false;
+            }
+
+            FrameStream::send(&ClientPacket::Message(message), &mut client.frame_stream);
+        }
+
+        FrameStream::flush(&mut client.frame_stream);
+
+        for server_packet in FrameStream::receive::<ServerPacket>(&mut client.frame_stream) {
+            match server_packet {
+                ServerPacket::Welcome(person_id) => {
+                    tracing::info!("Assigned {:?}", person_id);
+
+                    client.person_id_option = Some(person_id);
+                }
+                ServerPacket::Reject(reason) => {
+                    tracing::error!("Connection rejected: {}", reason);
+
+                    client.frame_stream.connected = false;
+                }
+                ServerPacket::View(view_packet) => Self::apply_view_packet(view_packet, client),
+            }
+        }
+
+        if !client.frame_stream.connected {
+            tracing::info!("Disconnected from server");
+
+            Self::publish_done(client);
+
+            return false;
+        }
+
+        true
+    }
+
+    fn apply_view_packet(view_packet: ViewPacket, client: &mut Self) {
+        let view = client.view_input.view_buffer.input_buffer_mut();
+
+        view.supervisor_view = view_packet.supervisor_view;
+        view.population_view = view_packet.population_view;
+
+        client.view_input.view_buffer.publish();
+
+        if !WorldViewDelta::is_empty(&view_packet.world_view_delta) {
+            let _ = client
+                .view_input
+                .world_view_delta_tx
+                .send(view_packet.world_view_delta);
+        }
+    }
+
+    fn publish_done(client: &mut Self) {
+        let view = client.view_input.view_buffer.input_buffer_mut();
+
+        view.supervisor_view.supervisor_status = SupervisorStatus::Done;
+
+        client.view_input.view_buffer.publish();
+    }
+}
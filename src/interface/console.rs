@@ -0,0 +1,620 @@
+//! Developer console command language
+
+use crate::{
+    interface::constants::{CONSOLE_FILL_VOLUME_MAX, CONSOLE_STEP_MAX},
+    simulation::{
+        constants::{
+            PERSON_MAX_COUNT, SCHEMATIC_DIRECTORY, SIMULATION_TIME_SCALE_MAX,
+            SIMULATION_TIME_SCALE_MIN,
+        },
+        state::{
+            population::{nation::nation_kind::NationKind, person::person_id::PersonID},
+            world::{block::block_kind::BlockKind, grid::Direction, schematic::Schematic},
+        },
+        supervisor::{
+            message::{
+                FillData, PasteData, PersonInputData, RegionData, ReplaceData, SchematicData,
+                SchematicSetData, SeedData, SetBlockData, SpawnData, TeleportData, TimeScaleData,
+            },
+            Message,
+        },
+    },
+};
+use std::{collections::VecDeque, path::Path, str::FromStr};
+use strum::VariantArray;
+use ultraviolet::{IVec3, Vec3};
+
+pub const COMMAND_NAMES: [&str; 14] = [
+    "copy",
+    "fill",
+    "generate",
+    "help",
+    "paste",
+    "redo",
+    "replace",
+    "schematic",
+    "seed",
+    "setblock",
+    "spawn",
+    "time",
+    "tp",
+    "undo",
+];
+
+pub const TIME_ARGUMENTS: [&str; 4] = ["pause", "scale", "step", "unbounded"];
+
+pub const AIR_NAME: &str = "air";
+
+pub const HELP_TEXT: &str = "\
+tp <x> <y> <z>
+setblock <x> <y> <z> <kind|air> [direction]
+fill <x1> <y1> <z1> <x2> <y2> <z2> <kind|air> [direction]
+copy <x1> <y1> <z1> <x2> <y2> <z2>
+paste <x> <y> <z> [direction]
+replace <x1> <y1> <z1> <x2> <y2> <z2> <mask kind> <kind|air>
+undo | redo
+schematic <name> <x1> <y1> <z1> <x2> <y2> <z2>
+spawn <nation> <count>
+seed <value>
+time scale <0.25..16> | time pause | time step [count] | time unbounded
+generate";
+
+pub struct Console {}
+
+impl Console {
+    pub fn execute(
+        command_string: &str,
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        let token_vec: Vec<&str> = command_string.split_whitespace().collect();
+
+        let Some(command_name) = token_vec.first() else {
+            return Err(String::from("empty command"));
+        };
+
+        let argument_slice = &token_vec[1..];
+
+        match command_name.to_ascii_lowercase().as_str() {
+            "help" => Ok(String::from(HELP_TEXT)),
+            "tp" => Self::execute_teleport(argument_slice, person_id, message_deque),
+            "setblock" => Self::execute_set_block(argument_slice, person_id, message_deque),
+            "fill" => Self::execute_fill(argument_slice, person_id, message_deque),
+            "copy" => Self::execute_copy(argument_slice, person_id, message_deque),
+            "paste" => Self::execute_paste(argument_slice, person_id, message_deque),
+            "replace" => Self::execute_replace(argument_slice, person_id, message_deque),
+            "undo" => Self::execute_undo(argument_slice, person_id, message_deque),
+            "redo" => Self::execute_redo(argument_slice, person_id, message_deque),
+            "schematic" => Self::execute_schematic(argument_slice, message_deque),
+            "spawn" => Self::execute_spawn(argument_slice, message_deque),
+            "seed" => Self::execute_seed(argument_slice, message_deque),
+            "time" => Self::execute_time(argument_slice, message_deque),
+            "generate" => Self::execute_generate(argument_slice, message_deque),
+            _ => Err(format!("unknown command: {command_name} (try help)")),
+        }
+    }
+
+    pub fn complete(command_string: &str) -> (String, Vec<String>) {
+        let mut token_vec: Vec<&str> = command_string.split_whitespace().collect();
+
+        let starting_new_token = command_string.is_empty() || command_string.ends_with(' ');
+
+        let partial = if starting_new_token {
+            ""
+        } else {
+            token_vec.pop().unwrap_or("")
+        };
+
+        let token_index = token_vec.len();
+
+        let command_name = token_vec
+            .first()
+            .map(|token| token.to_ascii_lowercase())
+            .unwrap_or_default();
+
+        let candidate_vec: Vec<String> = Self::get_candidate_vec(&command_name, token_index)
+            .into_iter()
+            .filter(|candidate| {
+                candidate
+                    .to_ascii_lowercase()
+                    .starts_with(&partial.to_ascii_lowercase())
+            })
+            .collect();
+
+        let completion = match candidate_vec.len() {
+            0 => String::from(partial),
+            1 => format!("{} ", candidate_vec[0]),
+            _ => {
+                let common_prefix = Self::get_common_prefix(&candidate_vec);
+
+                if common_prefix.len() > partial.len() {
+                    common_prefix
+                } else {
+                    String::from(partial)
+                }
+            }
+        };
+
+        let mut completed_string = token_vec.join(" ");
+
+        if !completed_string.is_empty() {
+            completed_string.push(' ');
+        }
+
+        completed_string.push_str(&completion);
+
+        (completed_string, candidate_vec)
+    }
+
+    fn get_candidate_vec(command_name: &str, token_index: usize) -> Vec<String> {
+        match (command_name, token_index) {
+            (_, 0) => Self::to_string_vec(&COMMAND_NAMES),
+            ("replace", 7) => BlockKind::VARIANTS
+                .iter()
+                .map(|block_kind| block_kind.to_string())
+                .collect(),
+            ("setblock", 4) | ("fill", 7) | ("replace", 8) => {
+                let mut candidate_vec = vec![String::from(AIR_NAME)];
+
+                candidate_vec.extend(
+                    BlockKind::VARIANTS
+                        .iter()
+                        .map(|block_kind| block_kind.to_string()),
+                );
+
+                candidate_vec
+            }
+            ("setblock", 5) | ("fill", 8) | ("paste", 4) => Direction::ALL
+                .iter()
+                .map(|direction| direction.to_string())
+                .collect(),
+            ("spawn", 1) => NationKind::VARIANTS
+                .iter()
+                .map(|nation_kind| nation_kind.to_string())
+                .collect(),
+            ("time", 1) => Self::to_string_vec(&TIME_ARGUMENTS),
+            _ => Vec::new(),
+        }
+    }
+
+    fn to_string_vec(str_slice: &[&str]) -> Vec<String> {
+        str_slice.iter().map(|value| String::from(*value)).collect()
+    }
+
+    fn get_common_prefix(candidate_vec: &[String]) -> String {
+        let first_candidate = &candidate_vec[0];
+
+        let mut prefix_length = first_candidate.len();
+
+        for candidate in &candidate_vec[1..] {
+            prefix_length = first_candidate
+                .chars()
+                .zip(candidate.chars())
+                .take(prefix_length)
+                .take_while(|(char1, char2)| char1.eq_ignore_ascii_case(char2))
+                .count();
+        }
+
+        first_candidate.chars().take(prefix_length).collect()
+    }
+
+    fn execute_teleport(
+        argument_slice: &[&str],
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        let [x, y, z] = argument_slice else {
+            return Err(String::from("usage: tp <x> <y> <z>"));
+        };
+
+        let world_position = Vec3::new(
+            Self::parse_number::<f32>(x)?,
+            Self::parse_number::<f32>(y)?,
+            Self::parse_number::<f32>(z)?,
+        );
+
+        message_deque.push_back(Message::Teleport(TeleportData {
+            person_id,
+            world_position,
+        }));
+
+        Ok(format!(
+            "teleport to ({}, {}, {})",
+            world_position.x, world_position.y, world_position.z
+        ))
+    }
+
+    fn execute_set_block(
+        argument_slice: &[&str],
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if !(4..=5).contains(&argument_slice.len()) {
+            return Err(String::from(
+                "usage: setblock <x> <y> <z> <kind|air> [direction]",
+            ));
+        }
+
+        let grid_position = Self::parse_grid_position(&argument_slice[0..3])?;
+        let block_kind_option = Self::parse_block_kind_option(argument_slice[3])?;
+        let direction = Self::parse_direction_option(argument_slice.get(4))?;
+
+        let feedback = format!(
+            "set ({}, {}, {}) to {}",
+            grid_position.x,
+            grid_position.y,
+            grid_position.z,
+            Self::get_block_kind_name(&block_kind_option)
+        );
+
+        message_deque.push_back(Message::SetBlock(SetBlockData {
+            person_id,
+            grid_position,
+            block_kind_option,
+            direction,
+        }));
+
+        Ok(feedback)
+    }
+
+    fn execute_fill(
+        argument_slice: &[&str],
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if !(7..=8).contains(&argument_slice.len()) {
+            return Err(String::from(
+                "usage: fill <x1> <y1> <z1> <x2> <y2> <z2> <kind|air> [direction]",
+            ));
+        }
+
+        let min = Self::parse_grid_position(&argument_slice[0..3])?;
+        let max = Self::parse_grid_position(&argument_slice[3..6])?;
+        let block_kind_option = Self::parse_block_kind_option(argument_slice[6])?;
+        let direction = Self::parse_direction_option(argument_slice.get(7))?;
+
+        let extent = (max - min).abs() + IVec3::one();
+        let volume = extent.x as i64 * extent.y as i64 * extent.z as i64;
+
+        if volume > CONSOLE_FILL_VOLUME_MAX {
+            return Err(format!(
+                "fill volume {volume} exceeds limit of {CONSOLE_FILL_VOLUME_MAX}"
+            ));
+        }
+
+        let feedback = format!(
+            "fill {} cells with {}",
+            volume,
+            Self::get_block_kind_name(&block_kind_option)
+        );
+
+        message_deque.push_back(Message::Fill(FillData {
+            person_id,
+            min,
+            max,
+            block_kind_option,
+            direction,
+        }));
+
+        Ok(feedback)
+    }
+
+    fn execute_copy(
+        argument_slice: &[&str],
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if argument_slice.len() != 6 {
+            return Err(String::from("usage: copy <x1> <y1> <z1> <x2> <y2> <z2>"));
+        }
+
+        let min = Self::parse_grid_position(&argument_slice[0..3])?;
+        let max = Self::parse_grid_position(&argument_slice[3..6])?;
+        let volume = Self::get_region_volume(min, max)?;
+
+        message_deque.push_back(Message::CopyRegion(RegionData {
+            person_id,
+            min,
+            max,
+        }));
+
+        Ok(format!("copy {volume} cells"))
+    }
+
+    fn execute_paste(
+        argument_slice: &[&str],
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if !(3..=4).contains(&argument_slice.len()) {
+            return Err(String::from("usage: paste <x> <y> <z> [direction]"));
+        }
+
+        let grid_position = Self::parse_grid_position(&argument_slice[0..3])?;
+        let direction = Self::parse_direction_option(argument_slice.get(3))?;
+
+        message_deque.push_back(Message::PasteRegion(PasteData {
+            person_id,
+            grid_position,
+            direction,
+        }));
+
+        Ok(format!(
+            "paste at ({}, {}, {}) facing {}",
+            grid_position.x, grid_position.y, grid_position.z, direction
+        ))
+    }
+
+    fn execute_replace(
+        argument_slice: &[&str],
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if argument_slice.len() != 8 {
+            return Err(String::from(
+                "usage: replace <x1> <y1> <z1> <x2> <y2> <z2> <mask kind> <kind|air>",
+            ));
+        }
+
+        let min = Self::parse_grid_position(&argument_slice[0..3])?;
+        let max = Self::parse_grid_position(&argument_slice[3..6])?;
+        Self::get_region_volume(min, max)?;
+
+        let Some(mask_block_kind) = Self::parse_block_kind_option(argument_slice[6])? else {
+            return Err(String::from("mask kind cannot be air"));
+        };
+
+        let block_kind_option = Self::parse_block_kind_option(argument_slice[7])?;
+
+        let feedback = format!(
+            "replace {} with {}",
+            mask_block_kind,
+            Self::get_block_kind_name(&block_kind_option)
+        );
+
+        message_deque.push_back(Message::ReplaceRegion(ReplaceData {
+            person_id,
+            min,
+            max,
+            mask_block_kind,
+            block_kind_option,
+        }));
+
+        Ok(feedback)
+    }
+
+    fn execute_undo(
+        argument_slice: &[&str],
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if !argument_slice.is_empty() {
+            return Err(String::from("usage: undo"));
+        }
+
+        message_deque.push_back(Message::Undo(PersonInputData { person_id }));
+
+        Ok(String::from("undo"))
+    }
+
+    fn execute_redo(
+        argument_slice: &[&str],
+        person_id: PersonID,
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if !argument_slice.is_empty() {
+            return Err(String::from("usage: redo"));
+        }
+
+        message_deque.push_back(Message::Redo(PersonInputData { person_id }));
+
+        Ok(String::from("redo"))
+    }
+
+    fn execute_schematic(
+        argument_slice: &[&str],
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if argument_slice.len() != 7 {
+            return Err(String::from(
+                "usage: schematic <name> <x1> <y1> <z1> <x2> <y2> <z2>",
+            ));
+        }
+
+        let name = argument_slice[0];
+
+        if !Schematic::name_is_valid(name) {
+            return Err(format!("invalid schematic name: {name}"));
+        }
+
+        let min = Self::parse_grid_position(&argument_slice[1..4])?;
+        let max = Self::parse_grid_position(&argument_slice[4..7])?;
+        Self::get_region_volume(min, max)?;
+
+        message_deque.push_back(Message::SaveSchematic(SchematicData {
+            name: String::from(name),
+            min,
+            max,
+        }));
+
+        Ok(format!("save schematic {name}"))
+    }
+
+    fn get_region_volume(min: IVec3, max: IVec3) -> Result<i64, String> {
+        let extent = (max - min).abs() + IVec3::one();
+        let volume = extent.x as i64 * extent.y as i64 * extent.z as i64;
+
+        if volume > CONSOLE_FILL_VOLUME_MAX {
+            return Err(format!(
+                "region volume {volume} exceeds limit of {CONSOLE_FILL_VOLUME_MAX}"
+            ));
+        }
+
+        Ok(volume)
+    }
+
+    fn execute_spawn(
+        argument_slice: &[&str],
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        let [nation_name, count] = argument_slice else {
+            return Err(String::from("usage: spawn <nation> <count>"));
+        };
+
+        let nation_kind = NationKind::VARIANTS
+            .iter()
+            .find(|nation_kind| nation_kind.to_string().eq_ignore_ascii_case(nation_name))
+            .copied()
+            .ok_or_else(|| format!("unknown nation: {nation_name}"))?;
+
+        let count = Self::parse_number::<u32>(count)?;
+
+        if count == 0 || count as usize > PERSON_MAX_COUNT {
+            return Err(format!("count must be between 1 and {PERSON_MAX_COUNT}"));
+        }
+
+        message_deque.push_back(Message::Spawn(SpawnData { nation_kind, count }));
+
+        Ok(format!("spawn {count} {nation_kind}"))
+    }
+
+    fn execute_seed(
+        argument_slice: &[&str],
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        let [seed] = argument_slice else {
+            return Err(String::from("usage: seed <value>"));
+        };
+
+        let seed = Self::parse_number::<u64>(seed)?;
+
+        message_deque.push_back(Message::SetSeed(SeedData { seed }));
+
+        Ok(format!("seed {seed}"))
+    }
+
+    fn execute_time(
+        argument_slice: &[&str],
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        let usage =
+            "usage: time scale <0.25..16> | time pause | time step [count] | time unbounded";
+
+        let Some(time_argument) = argument_slice.first() else {
+            return Err(String::from(usage));
+        };
+
+        match (
+            time_argument.to_ascii_lowercase().as_str(),
+            &argument_slice[1..],
+        ) {
+            ("scale", [time_scale]) => {
+                let time_scale = Self::parse_number::<f32>(time_scale)?;
+
+                if !(SIMULATION_TIME_SCALE_MIN..=SIMULATION_TIME_SCALE_MAX).contains(&time_scale) {
+                    return Err(format!(
+                        "time scale must be between {SIMULATION_TIME_SCALE_MIN} and {SIMULATION_TIME_SCALE_MAX}"
+                    ));
+                }
+
+                message_deque.push_back(Message::SetTimeScale(TimeScaleData { time_scale }));
+
+                Ok(format!("time scale {time_scale}x"))
+            }
+            ("pause", []) => {
+                message_deque.push_back(Message::TogglePause);
+
+                Ok(String::from("toggle pause"))
+            }
+            ("step", step_argument_slice) if step_argument_slice.len() <= 1 => {
+                let step_count = match step_argument_slice.first() {
+                    Some(step_count) => Self::parse_number::<u32>(step_count)?,
+                    None => 1,
+                };
+
+                if step_count == 0 || step_count > CONSOLE_STEP_MAX {
+                    return Err(format!(
+                        "step count must be between 1 and {CONSOLE_STEP_MAX}"
+                    ));
+                }
+
+                for _ in 0..step_count {
+                    message_deque.push_back(Message::Step);
+                }
+
+                Ok(format!("step {step_count}"))
+            }
+            ("unbounded", []) => {
+                message_deque.push_back(Message::ToggleUnbounded);
+
+                Ok(String::from("toggle unbounded"))
+            }
+            _ => Err(String::from(usage)),
+        }
+    }
+
+    fn execute_generate(
+        argument_slice: &[&str],
+        message_deque: &mut VecDeque<Message>,
+    ) -> Result<String, String> {
+        if !argument_slice.is_empty() {
+            return Err(String::from("usage: generate"));
+        }
+
+        let schematic_set_data = SchematicSetData {
+            schematic_map: Schematic::load_directory(Path::new(SCHEMATIC_DIRECTORY)),
+        };
+
+        message_deque.push_back(Message::SetSchematics(schematic_set_data));
+        message_deque.push_back(Message::Generate);
+
+        Ok(String::from("generate"))
+    }
+
+    fn parse_number<T: FromStr>(value: &str) -> Result<T, String> {
+        value
+            .parse::<T>()
+            .map_err(|_| format!("invalid number: {value}"))
+    }
+
+    fn parse_grid_position(argument_slice: &[&str]) -> Result<IVec3, String> {
+        let grid_position = IVec3::new(
+            Self::parse_number::<i32>(argument_slice[0])?,
+            Self::parse_number::<i32>(argument_slice[1])?,
+            Self::parse_number::<i32>(argument_slice[2])?,
+        );
+
+        Ok(grid_position)
+    }
+
+    fn parse_block_kind_option(block_kind_name: &str) -> Result<Option<BlockKind>, String> {
+        if block_kind_name.eq_ignore_ascii_case(AIR_NAME) {
+            return Ok(None);
+        }
+
+        BlockKind::VARIANTS
+            .iter()
+            .find(|block_kind| block_kind.to_string().eq_ignore_ascii_case(block_kind_name))
+            .map(|block_kind| Some(block_kind.clone()))
+            .ok_or_else(|| format!("unknown block kind: {block_kind_name}"))
+    }
+
+    fn parse_direction_option(direction_name_option: Option<&&str>) -> Result<Direction, String> {
+        let Some(direction_name) = direction_name_option else {
+            return Ok(Direction::North);
+        };
+
+        Direction::ALL
+            .iter()
+            .find(|direction| direction.as_str().eq_ignore_ascii_case(direction_name))
+            .copied()
+            .ok_or_else(|| format!("unknown direction: {direction_name}"))
+    }
+
+    fn get_block_kind_name(block_kind_option: &Option<BlockKind>) -> String {
+        match block_kind_option {
+            Some(block_kind) => block_kind.to_string(),
+            None => String::from(AIR_NAME),
+        }
+    }
+}
@@ -10,6 +10,8 @@ pub const INTERFACE_FRAME_FREQUENCY: u64 = 60;
 pub const INTERFACE_FRAME_DURATION: Duration =
     Duration::from_nanos(1_000_000_000 / INTERFACE_FRAME_FREQUENCY);
 
+pub const CLIENT_POLL_DURATION: Duration = Duration::from_millis(2);
+
 pub const WINDOW_WIDTH: u32 = 2560;
 pub const WINDOW_HEIGHT: u32 = 1440;
 pub const WINDOW_ASPECT_RATIO: f32 = WINDOW_WIDTH as f32 / WINDOW_HEIGHT as f32;
@@ -25,6 +27,13 @@ pub const FOV_RADIANS: f32 = FOV * PI / 180.0;
 pub const NEAR_PLANE: f32 = 0.1;
 pub const FAR_PLANE: f32 = 200.0;
 
+pub const DEBUG_SECTOR_BORDER_RADIUS: i32 = 2;
+
 pub const MOUSE_SENSITIVITY: f32 = 0.2;
 
 pub const OVERSEER_MESSAGE_LIMIT: usize = 500;
+
+pub const CONSOLE_HISTORY_LIMIT: usize = 64;
+pub const CONSOLE_OUTPUT_LIMIT: usize = 128;
+pub const CONSOLE_FILL_VOLUME_MAX: i64 = 32_768;
+pub const CONSOLE_STEP_MAX: u32 = 100;
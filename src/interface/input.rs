@@ -11,10 +11,13 @@ use crate::{
         input::{input_mode::InputMode, key_inputs::KeyInputs, mouse_inputs::MouseInputs},
         renderer::{debug_renderer::DebugRenderer, overlay_renderer::OverlayRenderer},
     },
-    simulation::supervisor::{
-        self,
-        message::{move_input_data::MoveInputData, rotate_input_data::RotateInputData},
-        Message,
+    simulation::{
+        state::population::person::person_id::PersonID,
+        supervisor::{
+            self,
+            message::{MoveInputData, PersonInputData, RotateInputData},
+            Message,
+        },
     },
 };
 use std::collections::VecDeque;
@@ -28,6 +31,7 @@ use winit::{
 };
 
 pub struct Input {
+    pub person_id: PersonID,
     pub input_mode: InputMode,
     pub key_inputs: KeyInputs,
     pub mouse_inputs: MouseInputs,
@@ -36,16 +40,10 @@ pub struct Input {
 
 impl Input {
     pub fn new() -> Self {
+        let person_id = PersonID::JUDGE_ID_1;
         let input_mode = InputMode::Overlay;
 
-        let key_inputs = KeyInputs {
-            key_w: 0.0,
-            key_a: 0.0,
-            key_s: 0.0,
-            key_d: 0.0,
-            key_q: 0.0,
-            key_e: 0.0,
-        };
+        let key_inputs = KeyInputs::new();
 
         let mouse_inputs = MouseInputs {
             delta: Vec2::broadcast(0.0),
@@ -54,6 +52,7 @@ impl Input {
         let message_deque = VecDeque::new();
 
         Self {
+            person_id,
             input_mode,
             key_inputs,
             mouse_inputs,
@@ -62,12 +61,13 @@ impl Input {
     }
 
     pub fn get_message_deque(
+        person_id: PersonID,
         key_inputs: &KeyInputs,
         mouse_inputs: &mut MouseInputs,
         message_deque: &mut VecDeque<Message>,
     ) -> VecDeque<Message> {
-        let move_input_message = Self::get_move_input_message(key_inputs);
-        let rotate_input_message = Self::get_rotate_input_message(mouse_inputs);
+        let move_input_message = Self::get_move_input_message(person_id, key_inputs);
+        let rotate_input_message = Self::get_rotate_input_message(person_id, mouse_inputs);
 
         message_deque.push_back(move_input_message);
         message_deque.push_back(rotate_input_message);
@@ -75,18 +75,22 @@ impl Input {
         std::mem::take(message_deque)
     }
 
-    pub fn get_move_input_message(key_inputs: &KeyInputs) -> Message {
+    pub fn get_move_input_message(person_id: PersonID, key_inputs: &KeyInputs) -> Message {
         let move_input_data = MoveInputData {
+            person_id,
             input_x: key_inputs.key_a + key_inputs.key_d,
             input_y: key_inputs.key_w + key_inputs.key_s,
             input_z: key_inputs.key_q + key_inputs.key_e,
+            sprint: key_inputs.key_shift,
+            crouch: key_inputs.key_control,
         };
 
         Message::MoveInput(move_input_data)
     }
 
-    fn get_rotate_input_message(mouse_inputs: &mut MouseInputs) -> Message {
+    fn get_rotate_input_message(person_id: PersonID, mouse_inputs: &mut MouseInputs) -> Message {
         let rotate_input_data = RotateInputData {
+            person_id,
             input_x: MOUSE_SENSITIVITY * -mouse_inputs.delta.y,
             input_y: 0.0,
             input_z: MOUSE_SENSITIVITY * -mouse_inputs.delta.x,
@@ -117,8 +121,7 @@ impl Input {
                 overlay_renderer,
                 debug_renderer,
                 gpu_context,
-                &mut input.key_inputs,
-                &mut input.message_deque,
+                input,
             ),
             WindowEvent::MouseInput {
                 device_id,
@@ -128,6 +131,7 @@ impl Input {
                 device_id,
                 state,
                 button,
+                input.person_id,
                 overlay_renderer,
                 &mut input.message_deque,
             ),
@@ -167,40 +171,85 @@ impl Input {
         overlay_renderer: &mut OverlayRenderer,
         debug_renderer: &mut DebugRenderer,
         gpu_context: &mut GPUContext,
-        key_inputs: &mut KeyInputs,
-        message_deque: &mut VecDeque<Message>,
+        input: &mut Self,
     ) {
+        let person_id = input.person_id;
+        let key_inputs = &mut input.key_inputs;
+        let message_deque = &mut input.message_deque;
+
         match key_event.physical_key {
             PhysicalKey::Code(KeyCode::Tab) => {
                 if key_event.state == ElementState::Released {
                     OverlayRenderer::toggle_main_window_active(overlay_renderer, gpu_context);
                 }
             }
+            PhysicalKey::Code(KeyCode::Slash) => {
+                if key_event.state == ElementState::Released
+                    && !overlay_renderer.content.run_content.main_window_active
+                {
+                    OverlayRenderer::set_console_active(true, overlay_renderer);
+                }
+            }
             PhysicalKey::Code(KeyCode::Backquote) => {
                 if key_event.state == ElementState::Released {
-                    message_deque.push_back(Message::Debug);
+                    message_deque.push_back(Message::Debug(PersonInputData { person_id }));
 
                     DebugRenderer::toggle_debug_active(debug_renderer);
                 }
             }
             PhysicalKey::Code(KeyCode::Digit1) => {
                 if key_event.state == ElementState::Released {
-                    message_deque.push_back(Message::Option1);
+                    message_deque.push_back(Message::Option1(PersonInputData { person_id }));
                 }
             }
             PhysicalKey::Code(KeyCode::Digit2) => {
                 if key_event.state == ElementState::Released {
-                    message_deque.push_back(Message::Option2);
+                    message_deque.push_back(Message::Option2(PersonInputData { person_id }));
                 }
             }
             PhysicalKey::Code(KeyCode::Digit3) => {
                 if key_event.state == ElementState::Released {
-                    message_deque.push_back(Message::Option3);
+                    message_deque.push_back(Message::Option3(PersonInputData { person_id }));
                 }
             }
             PhysicalKey::Code(KeyCode::Digit4) => {
                 if key_event.state == ElementState::Released {
-                    message_deque.push_back(Message::Option4);
+                    message_deque.push_back(Message::Option4(PersonInputData { person_id }));
+                }
+            }
+            PhysicalKey::Code(KeyCode::KeyF) => {
+                if key_event.state == ElementState::Released {
+                    message_deque.push_back(Message::Door(PersonInputData { person_id }));
+                }
+            }
+            PhysicalKey::Code(KeyCode::KeyL) => {
+                if key_event.state == ElementState::Released {
+                    message_deque.push_back(Message::LockDoor(PersonInputData { person_id }));
+                }
+            }
+            PhysicalKey::Code(KeyCode::KeyP) => {
+                if key_event.state == ElementState::Released {
+                    message_deque.push_back(Message::TogglePause);
+                }
+            }
+            PhysicalKey::Code(KeyCode::Period) => {
+                if key_event.state == ElementState::Released {
+                    message_deque.push_back(Message::Step);
+                }
+            }
+            PhysicalKey::Code(KeyCode::Minus) => {
+                if key_event.state == ElementState::Released {
+                    message_deque.push_back(Message::SlowDown);
+                }
+            }
+            PhysicalKey::Code(KeyCode::Equal) => {
+                if key_event.state == ElementState::Released {
+                    message_deque.push_back(Message::SpeedUp);
+                }
+            }
+            PhysicalKey::Code(KeyCode::Backslash) => {
+                if key_event.state == ElementState::Released {
+                    message_deque.push_back(Message::ToggleUnbounded);
                 }
             }
             PhysicalKey::Code(KeyCode::KeyW) => {
@@ -245,9 +294,15 @@ impl Input {
                     key_inputs.key_e -= 1.0;
                 }
             }
+            PhysicalKey::Code(KeyCode::ShiftLeft) => {
+                key_inputs.key_shift = key_event.state == ElementState::Pressed;
+            }
+            PhysicalKey::Code(KeyCode::ControlLeft) => {
+                key_inputs.key_control = key_event.state == ElementState::Pressed;
+            }
             PhysicalKey::Code(KeyCode::Space) => {
                 if key_event.state == ElementState::Pressed && !key_event.repeat {
-                    message_deque.push_back(Message::JumpInput);
+                    message_deque.push_back(Message::JumpInput(PersonInputData { person_id }));
                 }
             }
             _ => (),
@@ -258,14 +313,17 @@ impl Input {
         _device_id: &DeviceId,
         state: &ElementState,
         button: &MouseButton,
+        person_id: PersonID,
         overlay_renderer: &OverlayRenderer,
         message_deque: &mut VecDeque<Message>,
     ) {
         if state == &ElementState::Pressed {
+            let person_input_data = PersonInputData { person_id };
+
             if button == &MouseButton::Left {
-                message_deque.push_back(Message::Interact1);
+                message_deque.push_back(Message::Interact1(person_input_data));
             } else if button == &MouseButton::Right {
-                message_deque.push_back(Message::Interact2);
+                message_deque.push_back(Message::Interact2(person_input_data));
             }
         }
     }
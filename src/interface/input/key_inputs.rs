@@ -1,3 +1,4 @@
+#[derive(Default)]
 pub struct KeyInputs {
     pub key_w: f32,
     pub key_a: f32,
@@ -5,4 +6,21 @@ pub struct KeyInputs {
     pub key_d: f32,
     pub key_q: f32,
     pub key_e: f32,
+    pub key_shift: bool,
+    pub key_control: bool,
+}
+
+impl KeyInputs {
+    pub fn new() -> Self {
+        Self {
+            key_w: 0.0,
+            key_a: 0.0,
+            key_s: 0.0,
+            key_d: 0.0,
+            key_q: 0.0,
+            key_e: 0.0,
+            key_shift: false,
+            key_control: false,
+        }
+    }
 }
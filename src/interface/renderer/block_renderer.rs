@@ -17,7 +17,10 @@ use crate::{
         },
     },
     simulation::{
-        state::world::grid::{self, Direction},
+        state::world::{
+            grid::{self, Direction},
+            sector::block_storage::BlockStorage,
+        },
         supervisor::viewer::view::WorldView,
     },
 };
@@ -95,7 +98,9 @@ impl BlockRenderer {
             HashMap::new();
 
         for sector_view in world_view.sector_view_map.values() {
-            for block in sector_view.block_vec.iter().flatten() {
+            for block in
+                BlockStorage::get_block_vec(sector_view.sector_index, &sector_view.block_storage)
+            {
                 let world_position =
                     *(grid::grid_position_to_world_position(block.grid_position)).as_array();
                 let rotation_xy = Direction::to_rotation(&block.direction);
@@ -5,6 +5,7 @@ use crate::{
     include_assets,
     interface::{
         camera::Camera,
+        constants::DEBUG_SECTOR_BORDER_RADIUS,
         gpu::gpu_context::GPUContext,
         renderer::{
             debug_renderer::{debug_channel::DebugChannel, debug_vertex::DebugVertex},
@@ -12,7 +13,7 @@ use crate::{
             Renderer,
         },
     },
-    simulation::{constants::*, supervisor::viewer::view::View},
+    simulation::{constants::*, state::world::grid, supervisor::viewer::view::View},
 };
 use std::collections::{HashMap, HashSet};
 use tracing::instrument;
@@ -261,7 +262,7 @@ impl DebugRenderer {
     }
 
     #[instrument(skip_all)]
-    pub fn apply_debug_view(gpu_context: &GPUContext, _view: &View, debug_renderer: &mut Self) {
+    pub fn apply_debug_view(gpu_context: &GPUContext, view: &View, debug_renderer: &mut Self) {
         if !debug_renderer.debug_active {
             return;
         }
@@ -270,22 +271,41 @@ impl DebugRenderer {
             .channel_set
             .contains(&DebugChannel::SectorBorders)
         {
-            let world_radius_in_sectors = WORLD_RADIUS_IN_SECTORS as i32;
+            let judge_id = view.population_view.leadership_view.judge_id;
+
+            let center = match view.population_view.person_view_map.get(&judge_id) {
+                Some(judge_person_view) => {
+                    let sector_coordinate = grid::world_position_to_sector_coordinate(
+                        judge_person_view.transform.world_position,
+                    );
+
+                    let grid_position = grid::sector_coordinate_to_grid_position(sector_coordinate);
+
+                    grid::grid_position_to_world_position(grid_position)
+                }
+                None => Vec3::zero(),
+            };
+
+            let border_radius = DEBUG_SECTOR_BORDER_RADIUS;
             let sector_size_in_cells: f32 = SECTOR_SIZE_IN_CELLS as f32;
 
-            let half_span =
-                (world_radius_in_sectors as f32 + CELL_RADIUS_IN_METERS) * sector_size_in_cells;
-            let min = Vec3::broadcast(-half_span);
-            let max = Vec3::broadcast(half_span);
+            let half_span = (border_radius as f32 + CELL_RADIUS_IN_METERS) * sector_size_in_cells;
+            let min = center - Vec3::broadcast(half_span);
+            let max = center + Vec3::broadcast(half_span);
 
-            let mut bounds: Vec<f32> = Vec::with_capacity(2 * world_radius_in_sectors as usize + 2);
+            let mut bounds: Vec<Vec3> = Vec::with_capacity(2 * border_radius as usize + 2);
 
-            for k in -(world_radius_in_sectors + 1)..=world_radius_in_sectors {
-                bounds.push((k as f32 + CELL_RADIUS_IN_METERS) * sector_size_in_cells);
+            for k in -(border_radius + 1)..=border_radius {
+                bounds.push(
+                    center
+                        + Vec3::broadcast(
+                            (k as f32 + CELL_RADIUS_IN_METERS) * sector_size_in_cells,
+                        ),
+                );
             }
 
-            for &y in &bounds {
-                for &z in &bounds {
+            for y in bounds.iter().map(|bound| bound.y) {
+                for z in bounds.iter().map(|bound| bound.z) {
                     Self::add_line(
                         DebugChannel::SectorBorders,
                         Vec3::new(min.x, y, z),
@@ -296,8 +316,8 @@ impl DebugRenderer {
                 }
             }
 
-            for &x in &bounds {
-                for &z in &bounds {
+            for x in bounds.iter().map(|bound| bound.x) {
+                for z in bounds.iter().map(|bound| bound.z) {
                     Self::add_line(
                         DebugChannel::SectorBorders,
                         Vec3::new(x, min.y, z),
@@ -308,8 +328,8 @@ impl DebugRenderer {
                 }
             }
 
-            for &x in &bounds {
-                for &y in &bounds {
+            for x in bounds.iter().map(|bound| bound.x) {
+                for y in bounds.iter().map(|bound| bound.y) {
                     Self::add_line(
                         DebugChannel::SectorBorders,
                         Vec3::new(x, y, min.z),
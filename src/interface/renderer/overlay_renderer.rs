@@ -4,23 +4,34 @@ pub mod content;
 
 use crate::{
     interface::{
+        console::Console,
+        constants::{CONSOLE_HISTORY_LIMIT, CONSOLE_OUTPUT_LIMIT},
         gpu::gpu_context::GPUContext,
         input::Input,
         interface_mode::InterfaceMode,
-        renderer::{overlay_renderer::content::Content, render_mode::RenderMode},
+        renderer::{
+            overlay_renderer::content::{console_content::ConsoleContent, Content},
+            render_mode::RenderMode,
+        },
     },
     simulation::{
-        state::{
-            population::person::person_id::PersonID,
-            world::grid::{self, Direction},
+        constants::SCHEMATIC_DIRECTORY,
+        state::world::{
+            grid::{self, Direction},
+            schematic::Schematic,
+        },
+        supervisor::{
+            message::{LoadData, SaveData, SchematicSetData, SeedData},
+            viewer::view::View,
+            Message,
         },
-        supervisor::{message::SeedData, viewer::view::View, Message},
     },
 };
 use egui::{FontId, FullOutput, Id, Ui};
 use std::{
     collections::VecDeque,
     hash::{DefaultHasher, Hash, Hasher},
+    path::Path,
     sync::Arc,
 };
 use tracing::instrument;
@@ -104,6 +115,7 @@ impl OverlayRenderer {
             InterfaceMode::Run => {
                 Self::show_hud(context, content);
                 Self::show_main_window(context, content);
+                Self::show_console(context, content, message_deque);
             }
         }
     }
@@ -190,12 +202,23 @@ impl OverlayRenderer {
                                     seed: Self::parse_seed(&content.menu_content.seed_input_string),
                                 };
 
+                                let schematic_set_data = SchematicSetData {
+                                    schematic_map: Schematic::load_directory(Path::new(
+                                        SCHEMATIC_DIRECTORY,
+                                    )),
+                                };
+
                                 message_deque.push_back(Message::SetSeed(seed_data));
+                                message_deque.push_back(Message::SetSchematics(schematic_set_data));
                                 message_deque.push_back(Message::Generate);
                             }
 
                             ui.add_space(20.0);
 
+                            Self::show_save_panel(ui, content, message_deque);
+
+                            ui.add_space(20.0);
+
                             let quit_clicked = ui
                                 .add_sized([200.0, 60.0], egui::Button::new("Quit"))
                                 .clicked();
@@ -208,6 +231,48 @@ impl OverlayRenderer {
             });
     }
 
+    fn show_save_panel(ui: &mut Ui, content: &mut Content, message_deque: &mut VecDeque<Message>) {
+        ui.label("Save:");
+
+        ui.add(
+            egui::TextEdit::singleline(&mut content.menu_content.save_name_input_string)
+                .desired_width(120.0)
+                .horizontal_align(egui::Align::Center),
+        );
+
+        ui.add_space(20.0);
+
+        let save_name = content.menu_content.save_name_input_string.trim();
+
+        ui.add_enabled_ui(!save_name.is_empty(), |ui| {
+            let save_clicked = ui
+                .add_sized([200.0, 60.0], egui::Button::new("Save"))
+                .clicked();
+
+            if save_clicked {
+                let save_data = SaveData {
+                    save_name: String::from(save_name),
+                };
+
+                message_deque.push_back(Message::Save(save_data));
+            }
+
+            ui.add_space(20.0);
+
+            let load_clicked = ui
+                .add_sized([200.0, 60.0], egui::Button::new("Load"))
+                .clicked();
+
+            if load_clicked {
+                let load_data = LoadData {
+                    save_name: String::from(save_name),
+                };
+
+                message_deque.push_back(Message::Load(load_data));
+            }
+        });
+    }
+
     fn show_main_window(context: &egui::Context, content: &mut Content) {
         if !content.run_content.main_window_active {
             return;
@@ -258,6 +323,199 @@ impl OverlayRenderer {
         }
     }
 
+    fn show_console(
+        context: &egui::Context,
+        content: &mut Content,
+        message_deque: &mut VecDeque<Message>,
+    ) {
+        let console_content = &mut content.console_content;
+
+        if !console_content.console_active {
+            return;
+        }
+
+        let screen_rect = context.available_rect();
+
+        let width = screen_rect.width() * 0.6;
+
+        egui::Area::new(Id::new("console_area"))
+            .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(16.0, -16.0))
+            .show(context, |ui| {
+                egui::Frame::window(&context.style())
+                    .fill(egui::Color32::from_rgba_unmultiplied(20, 20, 20, 220))
+                    .corner_radius(5.0)
+                    .show(ui, |ui| {
+                        ui.set_width(width);
+
+                        egui::ScrollArea::vertical()
+                            .max_height(screen_rect.height() * 0.3)
+                            .stick_to_bottom(true)
+                            .show(ui, |ui| {
+                                for output in &console_content.output_vec {
+                                    ui.monospace(output);
+                                }
+                            });
+
+                        Self::show_console_input(ui, console_content, message_deque);
+                    });
+            });
+    }
+
+    fn show_console_input(
+        ui: &mut Ui,
+        console_content: &mut ConsoleContent,
+        message_deque: &mut VecDeque<Message>,
+    ) {
+        let (tab_pressed, up_pressed, down_pressed, escape_pressed) = ui.input_mut(|input| {
+            (
+                input.consume_key(egui::Modifiers::NONE, egui::Key::Tab),
+                input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                input.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                input.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+            )
+        });
+
+        if escape_pressed {
+            console_content.console_active = false;
+            console_content.input_string.clear();
+            console_content.history_index_option = None;
+
+            return;
+        }
+
+        let mut cursor_to_end = false;
+
+        if tab_pressed {
+            let (completed_string, candidate_vec) =
+                Console::complete(&console_content.input_string);
+
+            if candidate_vec.len() > 1 {
+                Self::push_console_output(candidate_vec.join("  "), console_content);
+            }
+
+            console_content.input_string = completed_string;
+            cursor_to_end = true;
+        }
+
+        if up_pressed || down_pressed {
+            Self::navigate_console_history(up_pressed, console_content);
+
+            cursor_to_end = true;
+        }
+
+        let text_edit_id = Id::new("console_input");
+
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut console_content.input_string)
+                .id(text_edit_id)
+                .font(egui::TextStyle::Monospace)
+                .desired_width(f32::INFINITY),
+        );
+
+        if cursor_to_end {
+            if let Some(mut text_edit_state) = egui::TextEdit::load_state(ui.ctx(), text_edit_id) {
+                let cursor = egui::text::CCursor::new(console_content.input_string.chars().count());
+
+                text_edit_state
+                    .cursor
+                    .set_char_range(Some(egui::text::CCursorRange::one(cursor)));
+
+                text_edit_state.store(ui.ctx(), text_edit_id);
+            }
+        }
+
+        if response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter)) {
+            Self::submit_console_input(console_content, message_deque);
+
+            console_content.focus_requested = true;
+        }
+
+        if console_content.focus_requested {
+            response.request_focus();
+
+            console_content.focus_requested = false;
+        }
+    }
+
+    fn submit_console_input(
+        console_content: &mut ConsoleContent,
+        message_deque: &mut VecDeque<Message>,
+    ) {
+        let command_string = std::mem::take(&mut console_content.input_string);
+        let command_string = command_string.trim();
+
+        console_content.history_index_option = None;
+
+        if command_string.is_empty() {
+            return;
+        }
+
+        Self::push_console_output(format!("> {command_string}"), console_content);
+
+        match Console::execute(command_string, console_content.person_id, message_deque) {
+            Ok(feedback) => {
+                for line in feedback.lines() {
+                    Self::push_console_output(String::from(line), console_content);
+                }
+            }
+            Err(error) => Self::push_console_output(format!("error: {error}"), console_content),
+        }
+
+        if console_content.history_vec.last().map(String::as_str) != Some(command_string) {
+            console_content
+                .history_vec
+                .push(String::from(command_string));
+
+            if console_content.history_vec.len() > CONSOLE_HISTORY_LIMIT {
+                console_content.history_vec.remove(0);
+            }
+        }
+    }
+
+    fn navigate_console_history(backward: bool, console_content: &mut ConsoleContent) {
+        let history_length = console_content.history_vec.len();
+
+        if history_length == 0 {
+            return;
+        }
+
+        console_content.history_index_option =
+            match (console_content.history_index_option, backward) {
+                (None, true) => Some(history_length - 1),
+                (None, false) => None,
+                (Some(history_index), true) => Some(history_index.saturating_sub(1)),
+                (Some(history_index), false) => {
+                    if history_index + 1 < history_length {
+                        Some(history_index + 1)
+                    } else {
+                        None
+                    }
+                }
+            };
+
+        console_content.input_string = match console_content.history_index_option {
+            Some(history_index) => console_content.history_vec[history_index].clone(),
+            None => String::new(),
+        };
+    }
+
+    fn push_console_output(output: String, console_content: &mut ConsoleContent) {
+        console_content.output_vec.push(output);
+
+        if console_content.output_vec.len() > CONSOLE_OUTPUT_LIMIT {
+            let overflow = console_content.output_vec.len() - CONSOLE_OUTPUT_LIMIT;
+
+            console_content.output_vec.drain(0..overflow);
+        }
+    }
+
+    pub fn set_console_active(console_active: bool, overlay_renderer: &mut Self) {
+        let console_content = &mut overlay_renderer.content.console_content;
+
+        console_content.console_active = console_active;
+        console_content.focus_requested = console_active;
+    }
+
     fn show_crosshair(ui: &mut Ui) {
         let rect = ui.max_rect();
         let center = rect.center();
@@ -307,20 +565,13 @@ impl OverlayRenderer {
     pub fn apply_view_menu_mode(_view: &View, _overlay_renderer: &mut Self) {}
 
     pub fn apply_view_run_mode(view: &View, overlay_renderer: &mut Self) {
-        if view
-            .population_view
-            .person_view_map
-            .get(&PersonID::JUDGE_ID_1)
-            .is_none()
-        {
-            return;
-        }
+        let judge_id = view.population_view.leadership_view.judge_id;
 
-        let judge_person_view = view
-            .population_view
-            .person_view_map
-            .get(&PersonID::JUDGE_ID_1)
-            .expect("Judge 1 does not exist in Run Mode");
+        overlay_renderer.content.console_content.person_id = judge_id;
+
+        let Some(judge_person_view) = view.population_view.person_view_map.get(&judge_id) else {
+            return;
+        };
 
         let grid_position =
             grid::world_position_to_grid_position(judge_person_view.transform.world_position);
@@ -360,11 +611,31 @@ impl OverlayRenderer {
             judge_person_view.motion.mode.to_string()
         );
 
+        let motion_stance_string = format!("Motion Stance: {}\n", judge_person_view.motion.stance);
+
         let selected_block_kind_string = format!(
             "Selected Block: {:?}\n",
             judge_person_view.selected_block_kind
         );
 
+        let checksum_string = format!(
+            "Checksum: {:016x} ({})\n",
+            view.supervisor_view.checksum, view.supervisor_view.checksum_tick
+        );
+
+        let time_mode_string = if view.supervisor_view.paused {
+            String::from("Paused")
+        } else if view.supervisor_view.unbounded {
+            String::from("Unbounded")
+        } else {
+            format!("{}x", view.supervisor_view.time_scale)
+        };
+
+        let tick_rate_string = format!(
+            "Time: {} ({:.1} ticks/s)\n",
+            time_mode_string, view.supervisor_view.tick_rate
+        );
+
         let mut info_message = String::new();
 
         info_message.push_str(&position_string);
@@ -373,7 +644,10 @@ impl OverlayRenderer {
         info_message.push_str(&direction_string);
         info_message.push_str(&contact_set_string);
         info_message.push_str(&motion_mode_string);
+        info_message.push_str(&motion_stance_string);
         info_message.push_str(&selected_block_kind_string);
+        info_message.push_str(&tick_rate_string);
+        info_message.push_str(&checksum_string);
 
         overlay_renderer
             .content
@@ -1,7 +1,9 @@
 use crate::interface::renderer::overlay_renderer::content::{
-    menu_content::MenuContent, run_content::RunContent, setup_content::SetupContent,
+    console_content::ConsoleContent, menu_content::MenuContent, run_content::RunContent,
+    setup_content::SetupContent,
 };
 
+pub mod console_content;
 pub mod menu_content;
 pub mod run_content;
 pub mod setup_content;
@@ -11,6 +13,7 @@ pub struct Content {
     pub setup_content: SetupContent,
     pub menu_content: MenuContent,
     pub run_content: RunContent,
+    pub console_content: ConsoleContent,
 }
 
 impl Content {
@@ -18,11 +21,13 @@ impl Content {
         let setup_content = SetupContent::new();
         let menu_content = MenuContent::new();
         let run_content = RunContent::new();
+        let console_content = ConsoleContent::new();
 
         Self {
             setup_content,
             menu_content,
             run_content,
+            console_content,
         }
     }
 }
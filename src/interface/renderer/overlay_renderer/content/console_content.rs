@@ -0,0 +1,39 @@
+use crate::simulation::state::population::person::person_id::PersonID;
+
+pub struct ConsoleContent {
+    pub person_id: PersonID,
+    pub console_active: bool,
+    pub focus_requested: bool,
+    pub input_string: String,
+    pub history_vec: Vec<String>,
+    pub history_index_option: Option<usize>,
+    pub output_vec: Vec<String>,
+}
+
+impl ConsoleContent {
+    pub fn new() -> Self {
+        let person_id = PersonID::JUDGE_ID_1;
+        let console_active = false;
+        let focus_requested = false;
+        let input_string = String::new();
+        let history_vec = Vec::new();
+        let history_index_option = None;
+        let output_vec = Vec::new();
+
+        Self {
+            person_id,
+            console_active,
+            focus_requested,
+            input_string,
+            history_vec,
+            history_index_option,
+            output_vec,
+        }
+    }
+}
+
+impl Default for ConsoleContent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
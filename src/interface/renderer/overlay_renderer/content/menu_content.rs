@@ -1,12 +1,19 @@
+use crate::simulation::constants::SAVE_AUTOSAVE_NAME;
+
 #[derive(Default)]
 pub struct MenuContent {
     pub seed_input_string: String,
+    pub save_name_input_string: String,
 }
 
 impl MenuContent {
     pub fn new() -> Self {
         let seed_input_string = String::from("813");
+        let save_name_input_string = String::from(SAVE_AUTOSAVE_NAME);
 
-        Self { seed_input_string }
+        Self {
+            seed_input_string,
+            save_name_input_string,
+        }
     }
 }
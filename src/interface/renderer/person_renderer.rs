@@ -18,9 +18,7 @@ use crate::{
         },
     },
     simulation::{
-        constants::*,
-        state::{physics::body::Body, population::person::person_id::PersonID},
-        supervisor::viewer::view::PopulationView,
+        constants::*, state::physics::body::Body, supervisor::viewer::view::PopulationView,
     },
 };
 use std::collections::HashMap;
@@ -215,7 +213,7 @@ impl PersonRenderer {
             HashMap::new();
 
         for (person_id, person_view) in &population_view.person_view_map {
-            if person_id == &PersonID::JUDGE_ID_1 {
+            if person_id == &population_view.leadership_view.judge_id {
                 continue;
             }
 
@@ -208,6 +208,7 @@ impl SectorRenderer {
         for (sector_index, sector_view) in &world_view.sector_view_map {
             let sector_model = Self::get_or_build_sector_model(
                 sector_view,
+                world_view,
                 asset_manager,
                 &mut world_renderer.sector_mesh_cache,
             );
@@ -242,18 +243,21 @@ impl SectorRenderer {
     #[instrument(skip_all)]
     fn get_or_build_sector_model<'a>(
         sector_view: &SectorView,
+        world_view: &WorldView,
         asset_manager: &AssetManager,
         sector_mesh_cache: &'a mut HashMap<SectorIndex, SectorModel>,
     ) -> &'a SectorModel {
         match sector_mesh_cache.entry(sector_view.sector_index) {
             Entry::Vacant(vacant_entry) => {
-                let sector_model = SectorModel::from_sector_view(sector_view, asset_manager);
+                let sector_model =
+                    SectorModel::from_sector_view(sector_view, world_view, asset_manager);
 
                 vacant_entry.insert(sector_model)
             }
             Entry::Occupied(mut occupied_entry) => {
                 if occupied_entry.get().version != sector_view.version {
-                    let sector_model = SectorModel::from_sector_view(sector_view, asset_manager);
+                    let sector_model =
+                        SectorModel::from_sector_view(sector_view, world_view, asset_manager);
 
                     *occupied_entry.get_mut() = sector_model;
                 }
@@ -8,14 +8,31 @@ pub struct SectorFace {
     pub world_position: Vec3,
     pub direction: Direction,
     pub layer_index: LayerIndex,
+    pub light_array: [u8; 2],
+    pub ao_array: [u8; 4],
 }
 
 impl SectorFace {
-    pub fn new(world_position: Vec3, direction: Direction, layer_index: LayerIndex) -> Self {
+    pub fn new(
+        world_position: Vec3,
+        direction: Direction,
+        layer_index: LayerIndex,
+        light_array: [u8; 2],
+        ao_array: [u8; 4],
+    ) -> Self {
         Self {
             world_position,
             direction,
             layer_index,
+            light_array,
+            ao_array,
         }
     }
+
+    pub fn can_merge(sector_face1: &Self, sector_face2: &Self) -> bool {
+        sector_face1.direction == sector_face2.direction
+            && sector_face1.layer_index == sector_face2.layer_index
+            && sector_face1.light_array == sector_face2.light_array
+            && sector_face1.ao_array == sector_face2.ao_array
+    }
 }
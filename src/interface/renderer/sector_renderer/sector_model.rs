@@ -9,9 +9,10 @@ use crate::{
         state::world::{
             block::{block_shape::BlockShape, block_state::BlockState},
             grid::{self, axis::Axis, direction_set::DirectionSet, Direction},
-            sector::{sector_index::SectorIndex, Sector},
+            light::light_channel::LightChannel,
+            sector::sector_index::SectorIndex,
         },
-        supervisor::viewer::view::SectorView,
+        supervisor::viewer::view::{SectorView, WorldView},
     },
 };
 use ultraviolet::IVec3;
@@ -24,14 +25,24 @@ pub struct SectorModel {
 }
 
 impl SectorModel {
-    pub fn from_sector_view(sector_view: &SectorView, asset_manager: &AssetManager) -> Self {
-        let sector_model = Self::lysenko_optimization(sector_view, asset_manager);
+    const VERTEX_SIGN_ARRAY: [(i32, i32); 4] = [(-1, -1), (1, -1), (1, 1), (-1, 1)];
+
+    pub fn from_sector_view(
+        sector_view: &SectorView,
+        world_view: &WorldView,
+        asset_manager: &AssetManager,
+    ) -> Self {
+        let sector_model = Self::lysenko_optimization(sector_view, world_view, asset_manager);
 
         sector_model
     }
 
-    fn lysenko_optimization(sector_view: &SectorView, asset_manager: &AssetManager) -> Self {
-        let sector_face_vec = Self::collect_sector_geometry(sector_view, asset_manager);
+    fn lysenko_optimization(
+        sector_view: &SectorView,
+        world_view: &WorldView,
+        asset_manager: &AssetManager,
+    ) -> Self {
+        let sector_face_vec = Self::collect_sector_geometry(sector_view, world_view, asset_manager);
 
         let (sector_vertex_vec, sector_index_vec) = Self::get_sector_face_geometry(sector_face_vec);
 
@@ -47,6 +58,7 @@ impl SectorModel {
 
     fn collect_sector_geometry(
         sector_view: &SectorView,
+        world_view: &WorldView,
         asset_manager: &AssetManager,
     ) -> Vec<Vec<Vec<Option<SectorFace>>>> {
         let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
@@ -73,7 +85,7 @@ impl SectorModel {
                     let block_world_position =
                         grid::grid_position_to_world_position(block_grid_position);
 
-                    if let Some(block) = Sector::get_block(cell_index, &sector_view.block_vec) {
+                    if let Some(block) = SectorView::get_block(cell_index, sector_view) {
                         if block.block_shape == BlockShape::Block {
                             let BlockState::Block(ref block_data) = block.block_state else {
                                 panic!("block should have block data")
@@ -97,8 +109,27 @@ impl SectorModel {
                                 let normal = Direction::to_vec3(&direction);
                                 let world_position = block_world_position + normal * 0.5;
 
-                                let sector_face =
-                                    SectorFace::new(world_position, direction, layer_index);
+                                let light_array = Self::get_face_light_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let ao_array = Self::get_face_ao_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let sector_face = SectorFace::new(
+                                    world_position,
+                                    direction,
+                                    layer_index,
+                                    light_array,
+                                    ao_array,
+                                );
 
                                 sector_face_vec[Axis::Y as usize][slice_index][mask_index] =
                                     Some(sector_face);
@@ -117,8 +148,27 @@ impl SectorModel {
                                 let normal = Direction::to_vec3(&direction);
                                 let world_position = block_world_position + normal * 0.5;
 
-                                let sector_face =
-                                    SectorFace::new(world_position, direction, layer_index);
+                                let light_array = Self::get_face_light_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let ao_array = Self::get_face_ao_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let sector_face = SectorFace::new(
+                                    world_position,
+                                    direction,
+                                    layer_index,
+                                    light_array,
+                                    ao_array,
+                                );
 
                                 sector_face_vec[Axis::X as usize][slice_index][mask_index] =
                                     Some(sector_face);
@@ -137,8 +187,27 @@ impl SectorModel {
                                 let normal = Direction::to_vec3(&direction);
                                 let world_position = block_world_position + normal * 0.5;
 
-                                let sector_face =
-                                    SectorFace::new(world_position, direction, layer_index);
+                                let light_array = Self::get_face_light_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let ao_array = Self::get_face_ao_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let sector_face = SectorFace::new(
+                                    world_position,
+                                    direction,
+                                    layer_index,
+                                    light_array,
+                                    ao_array,
+                                );
 
                                 sector_face_vec[Axis::Y as usize][slice_index][mask_index] =
                                     Some(sector_face);
@@ -157,8 +226,27 @@ impl SectorModel {
                                 let normal = Direction::to_vec3(&direction);
                                 let world_position = block_world_position + normal * 0.5;
 
-                                let sector_face =
-                                    SectorFace::new(world_position, direction, layer_index);
+                                let light_array = Self::get_face_light_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let ao_array = Self::get_face_ao_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let sector_face = SectorFace::new(
+                                    world_position,
+                                    direction,
+                                    layer_index,
+                                    light_array,
+                                    ao_array,
+                                );
 
                                 sector_face_vec[Axis::X as usize][slice_index][mask_index] =
                                     Some(sector_face);
@@ -177,8 +265,27 @@ impl SectorModel {
                                 let normal = Direction::to_vec3(&direction);
                                 let world_position = block_world_position + normal * 0.5;
 
-                                let sector_face =
-                                    SectorFace::new(world_position, direction, layer_index);
+                                let light_array = Self::get_face_light_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let ao_array = Self::get_face_ao_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let sector_face = SectorFace::new(
+                                    world_position,
+                                    direction,
+                                    layer_index,
+                                    light_array,
+                                    ao_array,
+                                );
 
                                 sector_face_vec[Axis::Z as usize][slice_index][mask_index] =
                                     Some(sector_face);
@@ -197,8 +304,27 @@ impl SectorModel {
                                 let normal = Direction::to_vec3(&direction);
                                 let world_position = block_world_position + normal * 0.5;
 
-                                let sector_face =
-                                    SectorFace::new(world_position, direction, layer_index);
+                                let light_array = Self::get_face_light_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let ao_array = Self::get_face_ao_array(
+                                    block_grid_position,
+                                    &direction,
+                                    sector_view,
+                                    world_view,
+                                );
+
+                                let sector_face = SectorFace::new(
+                                    world_position,
+                                    direction,
+                                    layer_index,
+                                    light_array,
+                                    ao_array,
+                                );
 
                                 sector_face_vec[Axis::Z as usize][slice_index][mask_index] =
                                     Some(sector_face);
@@ -212,6 +338,94 @@ impl SectorModel {
         sector_face_vec
     }
 
+    fn get_face_light_array(
+        block_grid_position: IVec3,
+        direction: &Direction,
+        sector_view: &SectorView,
+        world_view: &WorldView,
+    ) -> [u8; 2] {
+        let front_grid_position = block_grid_position + Direction::to_ivec3(direction);
+        let (sector_index, cell_index) = grid::grid_position_to_indices(front_grid_position);
+
+        match Self::get_sector_view(&sector_index, sector_view, world_view) {
+            Some(front_sector_view) => [
+                SectorView::get_light_level(cell_index, &LightChannel::Sky, front_sector_view),
+                SectorView::get_light_level(cell_index, &LightChannel::Block, front_sector_view),
+            ],
+            None => [LIGHT_LEVEL_MAX, 0],
+        }
+    }
+
+    /// Occlusion per face vertex from the cells beside the face, 3 when unoccluded.
+    /// Vertices follow the order emitted by `emit_triangles`.
+    pub fn get_face_ao_array(
+        block_grid_position: IVec3,
+        direction: &Direction,
+        sector_view: &SectorView,
+        world_view: &WorldView,
+    ) -> [u8; 4] {
+        let front_grid_position = block_grid_position + Direction::to_ivec3(direction);
+
+        let (tangent_u, tangent_v) = match direction {
+            Direction::East | Direction::West => (IVec3::unit_y(), IVec3::unit_z()),
+            Direction::North | Direction::South => (IVec3::unit_x(), IVec3::unit_z()),
+            Direction::Up | Direction::Down => (IVec3::unit_x(), IVec3::unit_y()),
+        };
+
+        let mut ao_array = [0; 4];
+
+        for (vertex_index, (sign_u, sign_v)) in Self::VERTEX_SIGN_ARRAY.iter().enumerate() {
+            let offset_u = tangent_u * *sign_u;
+            let offset_v = tangent_v * *sign_v;
+
+            let side_u_is_opaque =
+                Self::is_opaque(front_grid_position + offset_u, sector_view, world_view);
+            let side_v_is_opaque =
+                Self::is_opaque(front_grid_position + offset_v, sector_view, world_view);
+            let corner_is_opaque = Self::is_opaque(
+                front_grid_position + offset_u + offset_v,
+                sector_view,
+                world_view,
+            );
+
+            ao_array[vertex_index] =
+                Self::get_vertex_ao(side_u_is_opaque, side_v_is_opaque, corner_is_opaque);
+        }
+
+        ao_array
+    }
+
+    pub fn get_vertex_ao(
+        side_u_is_opaque: bool,
+        side_v_is_opaque: bool,
+        corner_is_opaque: bool,
+    ) -> u8 {
+        if side_u_is_opaque && side_v_is_opaque {
+            0
+        } else {
+            3 - side_u_is_opaque as u8 - side_v_is_opaque as u8 - corner_is_opaque as u8
+        }
+    }
+
+    fn is_opaque(grid_position: IVec3, sector_view: &SectorView, world_view: &WorldView) -> bool {
+        let (sector_index, cell_index) = grid::grid_position_to_indices(grid_position);
+
+        Self::get_sector_view(&sector_index, sector_view, world_view)
+            .is_some_and(|sector_view| SectorView::is_opaque(cell_index, sector_view))
+    }
+
+    fn get_sector_view<'a>(
+        sector_index: &SectorIndex,
+        sector_view: &'a SectorView,
+        world_view: &'a WorldView,
+    ) -> Option<&'a SectorView> {
+        if *sector_index == sector_view.sector_index {
+            Some(sector_view)
+        } else {
+            world_view.sector_view_map.get(sector_index)
+        }
+    }
+
     fn get_sector_face_geometry(
         sector_face_vec: Vec<Vec<Vec<Option<SectorFace>>>>,
     ) -> (Vec<SectorVertexData>, Vec<u32>) {
@@ -272,7 +486,8 @@ impl SectorModel {
                         None => break,
                     };
 
-                    if test_face != sector_face || visited[test_mask_index] {
+                    if !SectorFace::can_merge(&test_face, &sector_face) || visited[test_mask_index]
+                    {
                         break;
                     }
 
@@ -290,7 +505,9 @@ impl SectorModel {
                             None => break 'outer,
                         };
 
-                        if test_face != sector_face || visited[test_mask_index] {
+                        if !SectorFace::can_merge(&test_face, &sector_face)
+                            || visited[test_mask_index]
+                        {
                             break 'outer;
                         }
                     }
@@ -451,6 +668,11 @@ impl SectorModel {
             Direction::Down => (uv3, uv2, uv1, uv0),
         };
 
+        let light_array = [
+            sector_face.light_array[0] as f32 / LIGHT_LEVEL_MAX as f32,
+            sector_face.light_array[1] as f32 / LIGHT_LEVEL_MAX as f32,
+        ];
+
         let initial_index = vertex_vec.len() as u32;
 
         vertex_vec.push(SectorVertexData {
@@ -458,6 +680,8 @@ impl SectorModel {
             normal_array,
             uv_array: uv0,
             layer_index: sector_face.layer_index.into(),
+            light_array,
+            ao: sector_face.ao_array[0] as f32 / 3.0,
         });
 
         vertex_vec.push(SectorVertexData {
@@ -465,6 +689,8 @@ impl SectorModel {
             normal_array,
             uv_array: uv1,
             layer_index: sector_face.layer_index.into(),
+            light_array,
+            ao: sector_face.ao_array[1] as f32 / 3.0,
         });
 
         vertex_vec.push(SectorVertexData {
@@ -472,6 +698,8 @@ impl SectorModel {
             normal_array,
             uv_array: uv2,
             layer_index: sector_face.layer_index.into(),
+            light_array,
+            ao: sector_face.ao_array[2] as f32 / 3.0,
         });
 
         vertex_vec.push(SectorVertexData {
@@ -479,6 +707,8 @@ impl SectorModel {
             normal_array,
             uv_array: uv3,
             layer_index: sector_face.layer_index.into(),
+            light_array,
+            ao: sector_face.ao_array[3] as f32 / 3.0,
         });
 
         let use_canonical = match sector_face.direction {
@@ -490,22 +720,32 @@ impl SectorModel {
             Direction::Down => false,
         };
 
+        let ao_array = sector_face.ao_array;
+
+        let vertex_order = if ao_array[0] + ao_array[2] >= ao_array[1] + ao_array[3] {
+            [0, 1, 2, 3]
+        } else {
+            [1, 2, 3, 0]
+        };
+
+        let [index0, index1, index2, index3] = vertex_order.map(|offset| initial_index + offset);
+
         if use_canonical {
-            index_vec.push(initial_index + 0);
-            index_vec.push(initial_index + 1);
-            index_vec.push(initial_index + 2);
+            index_vec.push(index0);
+            index_vec.push(index1);
+            index_vec.push(index2);
 
-            index_vec.push(initial_index + 0);
-            index_vec.push(initial_index + 2);
-            index_vec.push(initial_index + 3);
+            index_vec.push(index0);
+            index_vec.push(index2);
+            index_vec.push(index3);
         } else {
-            index_vec.push(initial_index + 0);
-            index_vec.push(initial_index + 2);
-            index_vec.push(initial_index + 1);
+            index_vec.push(index0);
+            index_vec.push(index2);
+            index_vec.push(index1);
 
-            index_vec.push(initial_index + 0);
-            index_vec.push(initial_index + 3);
-            index_vec.push(initial_index + 2);
+            index_vec.push(index0);
+            index_vec.push(index3);
+            index_vec.push(index2);
         }
     }
 
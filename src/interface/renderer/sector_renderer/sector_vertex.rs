@@ -7,14 +7,18 @@ pub struct SectorVertexData {
     pub normal_array: [f32; 3],
     pub uv_array: [f32; 2],
     pub layer_index: u32,
+    pub light_array: [f32; 2],
+    pub ao: f32,
 }
 
 impl SectorVertexData {
-    pub const ATTRS: [wgpu::VertexAttribute; 4] = wgpu::vertex_attr_array![
+    pub const ATTRS: [wgpu::VertexAttribute; 6] = wgpu::vertex_attr_array![
         0 => Float32x3,
         1 => Float32x3,
         2 => Float32x2,
         3 => Uint32,
+        4 => Float32x2,
+        5 => Float32,
     ];
 
     pub fn desc<'a>() -> wgpu::VertexBufferLayout<'a> {
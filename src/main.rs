@@ -1,5 +1,7 @@
-use last_ditch::{interface::app::App, utils::tracer::Tracer};
-use std::sync::OnceLock;
+use last_ditch::{
+    interface::app::App, simulation::supervisor::recorder::RecorderMode, utils::tracer::Tracer,
+};
+use std::{path::PathBuf, sync::OnceLock};
 use winit::event_loop::{ControlFlow, EventLoop};
 
 static TRACER: OnceLock<Tracer> = OnceLock::new();
@@ -8,10 +10,17 @@ fn main() {
     let tracer = Tracer::new();
     TRACER.set(tracer).unwrap();
 
+    let launch_options = get_launch_options(std::env::args().skip(1));
+
     let event_loop = EventLoop::new().unwrap();
     event_loop.set_control_flow(ControlFlow::Poll);
 
-    let mut app = App::default();
+    let mut app = App::new(
+        launch_options.recorder_mode,
+        launch_options.control_address_option,
+        launch_options.server_address_option,
+        launch_options.connect_address_option,
+    );
     event_loop.run_app(&mut app).unwrap();
 
     #[cfg(feature = "profile")]
@@ -19,3 +28,52 @@ fn main() {
         Tracer::export(&tracer.flamegraph_name);
     }
 }
+
+struct LaunchOptions {
+    recorder_mode: RecorderMode,
+    control_address_option: Option<String>,
+    server_address_option: Option<String>,
+    connect_address_option: Option<String>,
+}
+
+fn get_launch_options(mut arg_iter: impl Iterator<Item = String>) -> LaunchOptions {
+    let mut launch_options = LaunchOptions {
+        recorder_mode: RecorderMode::Off,
+        control_address_option: None,
+        server_address_option: None,
+        connect_address_option: None,
+    };
+
+    while let Some(arg) = arg_iter.next() {
+        match arg.as_str() {
+            "--record" => {
+                let path = arg_iter.next().expect("--record requires a path");
+
+                launch_options.recorder_mode = RecorderMode::Record(PathBuf::from(path));
+            }
+            "--replay" => {
+                let path = arg_iter.next().expect("--replay requires a path");
+
+                launch_options.recorder_mode = RecorderMode::Replay(PathBuf::from(path));
+            }
+            "--control" => {
+                let address = arg_iter.next().expect("--control requires an address");
+
+                launch_options.control_address_option = Some(address);
+            }
+            "--server" => {
+                let address = arg_iter.next().expect("--server requires an address");
+
+                launch_options.server_address_option = Some(address);
+            }
+            "--connect" => {
+                let address = arg_iter.next().expect("--connect requires an address");
+
+                launch_options.connect_address_option = Some(address);
+            }
+            _ => panic!("Unknown argument: {arg}"),
+        }
+    }
+
+    launch_options
+}
@@ -1,13 +1,15 @@
 //! Simulation evolution
 
 pub mod constants;
+pub mod network;
 pub mod state;
+pub mod summary;
 pub mod supervisor;
 pub mod utils;
 
 use crate::simulation::{
     state::State,
-    supervisor::{viewer::view::View, Message, Supervisor},
+    supervisor::{viewer::ViewInput, Message, Recorder, Supervisor},
 };
 use tracing::instrument;
 
@@ -17,10 +19,7 @@ pub struct Simulation {
 }
 
 impl Simulation {
-    pub fn new(
-        message_rx: crossbeam::channel::Receiver<Message>,
-        view_input: triple_buffer::Input<View>,
-    ) -> Self {
+    pub fn new(message_rx: crossbeam::channel::Receiver<Message>, view_input: ViewInput) -> Self {
         let supervisor = Supervisor::new(message_rx, view_input);
         let state = State::new();
 
@@ -41,4 +40,28 @@ impl Simulation {
             Supervisor::fix_timestep(supervisor);
         }
     }
+
+    #[instrument(skip_all)]
+    pub fn run_headless(
+        tick_limit: u32,
+        supervisor: &mut Supervisor,
+        state: &mut State,
+        mut tick_callback: impl FnMut(&Supervisor, &State),
+    ) -> u32 {
+        let mut tick_count = 0;
+
+        while tick_count < tick_limit {
+            tick_count += 1;
+
+            if !Supervisor::tick(state, supervisor) {
+                break;
+            }
+
+            tick_callback(supervisor, state);
+        }
+
+        Recorder::finish(&supervisor.recorder);
+
+        tick_count
+    }
 }
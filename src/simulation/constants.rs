@@ -1,5 +1,7 @@
 //! Simulation constants
 
+use ultraviolet::Vec3;
+
 pub const PROJECT_TITLE: &str = "Last Ditch";
 
 pub const TESTING: bool = true;
@@ -32,25 +34,17 @@ pub const SECTOR_AREA_IN_METERS: f32 = SECTOR_SIZE_IN_METERS * SECTOR_SIZE_IN_ME
 pub const SECTOR_VOLUME_IN_METERS: f32 =
     SECTOR_SIZE_IN_METERS * SECTOR_SIZE_IN_METERS * SECTOR_SIZE_IN_METERS;
 
-pub const WORLD_RADIUS_IN_SECTORS: usize = 2;
-pub const WORLD_SIZE_IN_SECTORS: usize = 2 * WORLD_RADIUS_IN_SECTORS + 1;
-pub const WORLD_AREA_IN_SECTORS: usize = WORLD_SIZE_IN_SECTORS * WORLD_SIZE_IN_SECTORS;
-pub const WORLD_VOLUME_IN_SECTORS: usize =
-    WORLD_SIZE_IN_SECTORS * WORLD_SIZE_IN_SECTORS * WORLD_SIZE_IN_SECTORS;
-
-pub const WORLD_RADIUS_IN_CELLS: usize =
-    SECTOR_SIZE_IN_CELLS * WORLD_RADIUS_IN_SECTORS + SECTOR_RADIUS_IN_CELLS;
-pub const WORLD_SIZE_IN_CELLS: usize = 2 * WORLD_RADIUS_IN_CELLS + 1;
-pub const WORLD_AREA_IN_CELLS: usize = WORLD_SIZE_IN_CELLS * WORLD_SIZE_IN_CELLS;
-pub const WORLD_VOLUME_IN_CELLS: usize =
-    WORLD_SIZE_IN_CELLS * WORLD_SIZE_IN_CELLS * WORLD_SIZE_IN_CELLS;
-
-pub const WORLD_RADIUS_IN_METERS: f32 =
-    CELL_SIZE_IN_METERS * WORLD_RADIUS_IN_CELLS as f32 + CELL_RADIUS_IN_METERS;
-pub const WORLD_SIZE_IN_METERS: f32 = 2.0 * WORLD_RADIUS_IN_METERS;
-pub const WORLD_AREA_IN_METERS: f32 = WORLD_SIZE_IN_METERS * WORLD_SIZE_IN_METERS;
-pub const WORLD_VOLUME_IN_METERS: f32 =
-    WORLD_SIZE_IN_METERS * WORLD_SIZE_IN_METERS * WORLD_SIZE_IN_METERS;
+pub const WORLD_LIMIT_IN_CELLS: i32 = 1 << 20;
+pub const WORLD_LOAD_RADIUS_IN_SECTORS: i32 = 2;
+pub const WORLD_UNLOAD_RADIUS_IN_SECTORS: i32 = 3;
+
+pub const LIGHT_LEVEL_MAX: u8 = 15;
+pub const LIGHT_SKY_HEIGHT_IN_CELLS: i32 = 1;
+
+pub const BLOCK_RANDOM_TICK_COUNT_PER_SECTOR: usize = 3;
+pub const BLOCK_DOOR_CLOSE_DELAY_IN_TICKS: u64 = 5 * SIMULATION_TICK_FREQUENCY;
+
+pub const NAVIGATION_DOOR_COST: u8 = 4;
 
 pub const SIMULATION_TICK_FREQUENCY: u64 = 20;
 pub const SIMULATION_MAX_TICKS_PER_FRAME: u32 = 5;
@@ -59,6 +53,9 @@ pub const SIMULATION_TICK_DURATION: std::time::Duration =
 pub const SIMULATION_TICK_IN_SECONDS: f32 = SIMULATION_TICK_DURATION.as_secs_f32();
 pub const SIMULATION_TICK_IN_SECONDS_SQUARED: f32 =
     SIMULATION_TICK_IN_SECONDS * SIMULATION_TICK_IN_SECONDS;
+pub const SIMULATION_CHECKSUM_INTERVAL: u32 = 20;
+pub const SIMULATION_TIME_SCALE_MIN: f32 = 0.25;
+pub const SIMULATION_TIME_SCALE_MAX: f32 = 16.0;
 
 pub const PERSON_MAX_COUNT: usize = 500;
 
@@ -70,17 +67,29 @@ pub const MOVEMENT_EPSILON: f32 = 1e-6;
 pub const GRAVITY_ACCELERATION: f32 = 36.0;
 
 pub const COLLISION_EPSILON: f32 = 0.001;
-pub const COLLISION_RESOLVE_ITERATIONS: usize = 40;
+pub const COLLISION_STEP_HEIGHT: f32 = CELL_UNIT_02 + COLLISION_EPSILON;
+pub const COLLISION_HASH_CELL_SIZE: f32 = 2.0;
+pub const COLLISION_SEPARATION_STIFFNESS: f32 = 0.5;
 
 pub const JUDGE_DEFAULT_GROUND_SPEED: f32 = 8.0;
 pub const JUDGE_DEFAULT_CLIMB_SPEED: f32 = 12.0;
 pub const JUDGE_DEFAULT_AIR_SPEED: f32 = 16.0;
 pub const JUDGE_DEFAULT_JUMP_SPEED: f32 = 16.0;
+pub const JUDGE_DEFAULT_SPRINT_SPEED: f32 = 12.0;
+pub const JUDGE_DEFAULT_CROUCH_SPEED: f32 = 4.0;
+pub const JUDGE_DEFAULT_GROUND_ACCELERATION: f32 = 64.0;
+pub const JUDGE_DEFAULT_GROUND_DECELERATION: f32 = 32.0;
+pub const JUDGE_DEFAULT_GROUND_FRICTION: f32 = 4.0;
+pub const JUDGE_DEFAULT_AIR_CONTROL: f32 = 0.3;
+pub const JUDGE_DEFAULT_AIR_ACCELERATION: f32 = 48.0;
+pub const JUDGE_DEFAULT_CROUCH_SCALE: f32 = 0.6;
 
 pub const JUDGE_DEFAULT_RADIUS_X: f32 = 0.3;
 pub const JUDGE_DEFAULT_RADIUS_Y: f32 = 0.3;
 pub const JUDGE_DEFAULT_RADIUS_Z: f32 = 0.95;
 
+pub const JUDGE_SPAWN_POSITION: Vec3 = Vec3::new(0.0, -32.0, 2.0);
+
 pub const PITCH_LIMIT: f32 = 89.99;
 
 pub const NATION_INITIAL_POPULATION: i32 = 4;
@@ -89,6 +98,14 @@ pub const PERSON_DEFAULT_GROUND_SPEED: f32 = 6.0;
 pub const PERSON_DEFAULT_CLIMB_SPEED: f32 = 8.0;
 pub const PERSON_DEFAULT_AIR_SPEED: f32 = 12.0;
 pub const PERSON_DEFAULT_JUMP_SPEED: f32 = 8.0;
+pub const PERSON_DEFAULT_SPRINT_SPEED: f32 = 9.0;
+pub const PERSON_DEFAULT_CROUCH_SPEED: f32 = 3.0;
+pub const PERSON_DEFAULT_GROUND_ACCELERATION: f32 = 40.0;
+pub const PERSON_DEFAULT_GROUND_DECELERATION: f32 = 24.0;
+pub const PERSON_DEFAULT_GROUND_FRICTION: f32 = 4.0;
+pub const PERSON_DEFAULT_AIR_CONTROL: f32 = 0.15;
+pub const PERSON_DEFAULT_AIR_ACCELERATION: f32 = 24.0;
+pub const PERSON_DEFAULT_CROUCH_SCALE: f32 = 0.7;
 
 pub const PERSON_DEFAULT_RADIUS_X: f32 = 0.3;
 pub const PERSON_DEFAULT_RADIUS_Y: f32 = 0.3;
@@ -96,7 +113,7 @@ pub const PERSON_DEFAULT_RADIUS_Z: f32 = 0.8;
 
 pub const TOWER_FLOOR_HEIGHT: usize = 8;
 pub const TOWER_FLOOR_COUNT: usize = 4;
-pub const TOWER_RADIUS: usize = WORLD_RADIUS_IN_CELLS - 10;
+pub const TOWER_RADIUS: usize = 32;
 pub const TOWER_OUTER_HALL_SIZE: usize = 4;
 pub const TOWER_CENTER_HALL_RADIUS: usize = 3;
 pub const TOWER_AREA_SIZE_MIN: usize = 5;
@@ -107,3 +124,28 @@ pub const TRADING_PLATFORM_RADIUS_Y: usize = 4;
 pub const TEMPLE_RADIUS_X: usize = 6;
 pub const TEMPLE_RADIUS_Y: usize = 5;
 pub const TEMPLE_SIZE_Z: usize = 9;
+
+pub const SAVE_FORMAT_MAGIC: [u8; 4] = *b"LDSV";
+pub const SAVE_FORMAT_VERSION: u32 = 14;
+pub const SAVE_DIRECTORY: &str = "saves";
+pub const SAVE_EXTENSION: &str = "ldsave";
+pub const SAVE_AUTOSAVE_NAME: &str = "autosave";
+
+pub const EDIT_JOURNAL_LIMIT: usize = 32;
+
+pub const SCHEMATIC_DIRECTORY: &str = "assets/schematics";
+pub const SCHEMATIC_EXTENSION: &str = "json";
+
+pub const SECTOR_STORE_DIRECTORY: &str = "last_ditch_sectors";
+pub const SECTOR_STORE_EXTENSION: &str = "ldsector";
+
+pub const REPLAY_FORMAT_MAGIC: [u8; 4] = *b"LDRP";
+pub const REPLAY_FORMAT_VERSION: u32 = 4;
+
+pub const CONTROL_CLIENT_LIMIT: usize = 8;
+pub const CONTROL_READ_BUFFER_LIMIT: usize = 64 * 1024;
+pub const CONTROL_WRITE_BUFFER_LIMIT: usize = 4 * 1024 * 1024;
+
+pub const NETWORK_CLIENT_LIMIT: usize = 4;
+pub const NETWORK_FRAME_SIZE_MAX: usize = 64 * 1024 * 1024;
+pub const NETWORK_WRITE_BUFFER_LIMIT: usize = 128 * 1024 * 1024;
@@ -0,0 +1,11 @@
+//! Client/server transport
+
+pub mod client_packet;
+pub mod frame_stream;
+pub mod server_packet;
+pub mod view_packet;
+
+pub use client_packet::ClientPacket;
+pub use frame_stream::FrameStream;
+pub use server_packet::ServerPacket;
+pub use view_packet::ViewPacket;
@@ -0,0 +1,7 @@
+use crate::simulation::supervisor::Message;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ClientPacket {
+    Message(Message),
+}
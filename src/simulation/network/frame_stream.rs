@@ -0,0 +1,134 @@
+use crate::simulation::constants::{NETWORK_FRAME_SIZE_MAX, NETWORK_WRITE_BUFFER_LIMIT};
+use serde::{de::DeserializeOwned, Serialize};
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+};
+
+pub struct FrameStream {
+    pub stream: TcpStream,
+    pub connected: bool,
+    pub read_buffer: Vec<u8>,
+    pub write_buffer: Vec<u8>,
+}
+
+impl FrameStream {
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        stream.set_nonblocking(true)?;
+        stream.set_nodelay(true)?;
+
+        let frame_stream = Self {
+            stream,
+            connected: true,
+            read_buffer: Vec::new(),
+            write_buffer: Vec::new(),
+        };
+
+        Ok(frame_stream)
+    }
+
+    pub fn send<T: Serialize>(value: &T, frame_stream: &mut Self) {
+        let payload = bincode::serialize(value).expect("Failed to serialize frame");
+
+        frame_stream
+            .write_buffer
+            .extend_from_slice(&(payload.len() as u32).to_le_bytes());
+        frame_stream.write_buffer.extend_from_slice(&payload);
+
+        Self::flush(frame_stream);
+
+        if frame_stream.write_buffer.len() > NETWORK_WRITE_BUFFER_LIMIT {
+            tracing::error!("Peer is not reading frames");
+
+            frame_stream.connected = false;
+        }
+    }
+
+    pub fn flush(frame_stream: &mut Self) {
+        while !frame_stream.write_buffer.is_empty() {
+            match frame_stream.stream.write(&frame_stream.write_buffer) {
+                Ok(0) => {
+                    frame_stream.connected = false;
+
+                    break;
+                }
+                Ok(byte_count) => {
+                    frame_stream.write_buffer.drain(..byte_count);
+                }
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => {
+                    frame_stream.connected = false;
+
+                    break;
+                }
+            }
+        }
+    }
+
+    pub fn receive<T: DeserializeOwned>(frame_stream: &mut Self) -> Vec<T> {
+        let mut chunk = [0u8; 64 * 1024];
+
+        loop {
+            match frame_stream.stream.read(&mut chunk) {
+                Ok(0) => {
+                    frame_stream.connected = false;
+
+                    break;
+                }
+                Ok(byte_count) => frame_stream
+                    .read_buffer
+                    .extend_from_slice(&chunk[..byte_count]),
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => {
+                    frame_stream.connected = false;
+
+                    break;
+                }
+            }
+        }
+
+        let mut value_vec = Vec::new();
+        let mut offset = 0;
+
+        while frame_stream.read_buffer.len() - offset >= 4 {
+            let length_bytes: [u8; 4] = frame_stream.read_buffer[offset..offset + 4]
+                .try_into()
+                .expect("Frame header is 4 bytes");
+
+            let frame_size = u32::from_le_bytes(length_bytes) as usize;
+
+            if frame_size > NETWORK_FRAME_SIZE_MAX {
+                tracing::error!("Frame size {} exceeds limit", frame_size);
+
+                frame_stream.connected = false;
+
+                break;
+            }
+
+            if frame_stream.read_buffer.len() - offset - 4 < frame_size {
+                break;
+            }
+
+            let payload = &frame_stream.read_buffer[offset + 4..offset + 4 + frame_size];
+
+            match bincode::deserialize::<T>(payload) {
+                Ok(value) => value_vec.push(value),
+                Err(error) => {
+                    tracing::error!("Invalid frame: {}", error);
+
+                    frame_stream.connected = false;
+
+                    break;
+                }
+            }
+
+            offset += 4 + frame_size;
+        }
+
+        frame_stream.read_buffer.drain(..offset);
+
+        value_vec
+    }
+}
@@ -0,0 +1,9 @@
+use crate::simulation::{network::ViewPacket, state::population::person::person_id::PersonID};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub enum ServerPacket {
+    Welcome(PersonID),
+    Reject(String),
+    View(ViewPacket),
+}
@@ -0,0 +1,9 @@
+use crate::simulation::supervisor::viewer::view::{PopulationView, SupervisorView, WorldViewDelta};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct ViewPacket {
+    pub supervisor_view: SupervisorView,
+    pub population_view: PopulationView,
+    pub world_view_delta: WorldViewDelta,
+}
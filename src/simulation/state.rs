@@ -1,31 +1,41 @@
 //! Current state of the simulation
 
 pub mod action;
+pub mod checksum;
 pub mod navigation;
 pub mod physics;
 pub mod population;
+pub mod save_file;
 pub mod time;
 pub mod work;
 pub mod world;
 
 pub use action::Action;
+pub use checksum::Checksum;
 pub use physics::Physics;
 pub use population::Population;
+pub use save_file::SaveFile;
+use serde::{Deserialize, Serialize};
 pub use time::Time;
 use tracing::instrument;
 pub use world::World;
 
 use crate::simulation::state::{
     navigation::Navigation,
+    physics::spatial_hash::SpatialHash,
     population::{person::Person, sight::Sight},
     work::Work,
-    world::grid::Direction,
+    world::{
+        door::Door,
+        grid::{self, Direction},
+    },
 };
 use rand_chacha::{
     rand_core::{RngCore, SeedableRng},
     ChaCha8Rng,
 };
 
+#[derive(Serialize, Deserialize)]
 pub struct State {
     pub active: bool,
     pub rng: ChaCha8Rng,
@@ -60,7 +70,7 @@ impl State {
         }
     }
 
-    pub fn place_block(person: &Person, world: &mut World) {
+    pub fn place_block(person: &Person, spatial_hash: &SpatialHash, world: &mut World) {
         let range = 8.0;
         let origin = person.sight.world_position;
         let forward = Sight::get_forward(&person.sight);
@@ -70,6 +80,12 @@ impl State {
             let placement_position = hit_position + normal;
             let placement_direction = Direction::from_rotation(person.transform.rotation_xy);
 
+            let placement_float_box = grid::get_cell_float_box(placement_position);
+
+            if !SpatialHash::query(&placement_float_box, spatial_hash).is_empty() {
+                return;
+            }
+
             World::set_block(
                 placement_position,
                 &placement_direction,
@@ -91,6 +107,30 @@ impl State {
         }
     }
 
+    pub fn toggle_door(person: &Person, world: &mut World) {
+        let range = 8.0;
+        let origin = person.sight.world_position;
+        let direction = Sight::get_forward(&person.sight);
+
+        if let Some((hit_grid_position, _)) =
+            World::raycast_to_block(origin, direction, range, world)
+        {
+            Door::toggle_open(hit_grid_position, &person.identity.nation_kind, world);
+        }
+    }
+
+    pub fn toggle_door_lock(person: &Person, world: &mut World) {
+        let range = 8.0;
+        let origin = person.sight.world_position;
+        let direction = Sight::get_forward(&person.sight);
+
+        if let Some((hit_grid_position, _)) =
+            World::raycast_to_block(origin, direction, range, world)
+        {
+            Door::toggle_locked(hit_grid_position, &person.identity.nation_kind, world);
+        }
+    }
+
     pub fn seed(seed: u64, state: &mut Self) {
         state.rng = ChaCha8Rng::seed_from_u64(seed);
 
@@ -101,9 +141,13 @@ impl State {
     #[instrument(skip_all)]
     pub fn tick(state: &mut Self) -> bool {
         if state.active {
+            // The hash is not saved, so rebuild it before acts query it after a load
+            SpatialHash::build(&state.population, &mut state.physics.spatial_hash);
+
             Action::tick(state);
             World::tick(&mut state.world);
             Population::tick(&mut state.population);
+            World::update_sectors(&state.population, &mut state.world);
             Physics::tick(&state.world, &mut state.population, &mut state.physics);
             Navigation::tick(&state.world, &mut state.navigation);
             Work::tick(state);
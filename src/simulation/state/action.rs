@@ -1,23 +1,31 @@
 pub mod act;
 
-use crate::{
-    simulation::{
-        constants::{MOVEMENT_EPSILON, PITCH_LIMIT},
-        state::{
-            action::act::{
-                move_data::MoveData, Act, JumpData, PlaceBlockData, RemoveBlockData, RotateData,
-            },
-            physics::body::{self, ContactSet},
-            population::{motion, person::Person},
-            Population, State, World,
+use crate::simulation::{
+    constants::{MOVEMENT_EPSILON, PITCH_LIMIT},
+    state::{
+        action::act::{
+            move_data::MoveData, Act, CopyRegionData, DoorData, JumpData, LockDoorData,
+            PasteRegionData, PlaceBlockData, RedoData, RemoveBlockData, ReplaceRegionData,
+            RotateData, UndoData,
         },
+        physics::{
+            body::{self, body_label::BodyLabel, Body, ContactSet},
+            collider::Collider,
+        },
+        population::{motion, person::Person},
+        world::{
+            grid::axis::Axis,
+            region_edit::{edit_journal::EditJournal, RegionEdit},
+        },
+        Physics, Population, State, World,
     },
-    utils::ldmath::float_ext,
 };
-use std::{collections::VecDeque, f32::EPSILON};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 use tracing::instrument;
 use ultraviolet::Vec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Action {
     pub active: bool,
     pub act_deque: VecDeque<Act>,
@@ -38,10 +46,13 @@ impl Action {
         for act in act_deque {
             match act {
                 Act::Rotate(rotate_data) => Self::apply_rotate(&rotate_data, &mut state.population),
-                Act::Move(move_data) => Self::apply_move(&move_data, &mut state.population),
+                Act::Move(move_data) => {
+                    Self::apply_move(&move_data, &state.world, &mut state.population)
+                }
                 Act::Jump(jump_data) => Self::apply_jump(&jump_data, &mut state.population),
                 Act::PlaceBlock(place_block_data) => Self::apply_place_block(
                     &place_block_data,
+                    &state.physics,
                     &mut state.world,
                     &mut state.population,
                 ),
@@ -50,6 +61,23 @@ impl Action {
                     &mut state.world,
                     &mut state.population,
                 ),
+                Act::CopyRegion(copy_region_data) => {
+                    Self::apply_copy_region(&copy_region_data, &mut state.world)
+                }
+                Act::PasteRegion(paste_region_data) => {
+                    Self::apply_paste_region(&paste_region_data, &mut state.world)
+                }
+                Act::ReplaceRegion(replace_region_data) => {
+                    Self::apply_replace_region(&replace_region_data, &mut state.world)
+                }
+                Act::Undo(undo_data) => Self::apply_undo(&undo_data, &mut state.world),
+                Act::Redo(redo_data) => Self::apply_redo(&redo_data, &mut state.world),
+                Act::Door(door_data) => {
+                    Self::apply_door(&door_data, &mut state.world, &state.population)
+                }
+                Act::LockDoor(lock_door_data) => {
+                    Self::apply_lock_door(&lock_door_data, &mut state.world, &state.population)
+                }
             }
         }
     }
@@ -70,48 +98,96 @@ impl Action {
         }
     }
 
-    pub fn apply_move(move_data: &MoveData, population: &mut Population) {
+    pub fn apply_move(move_data: &MoveData, world: &World, population: &mut Population) {
         if let Some(person) = population.person_map.get_mut(&move_data.person_id) {
+            Self::apply_stance(move_data.stance, world, person);
+
+            person.motion.move_direction = if move_data.move_direction.mag_sq() > MOVEMENT_EPSILON {
+                move_data.move_direction
+            } else {
+                Vec3::zero()
+            };
+
             match person.motion.mode {
                 motion::Mode::Ground => Self::apply_ground_move(move_data, person),
                 motion::Mode::Climb => Self::apply_climb_move(move_data, person),
-                motion::Mode::Air => Self::apply_air_move(move_data, person),
+                motion::Mode::Air => (),
             }
         }
     }
 
-    fn apply_ground_move(move_data: &MoveData, person: &mut Person) {
-        if move_data.move_direction.mag_sq() > MOVEMENT_EPSILON {
-            if ContactSet::has(body::Contact::Ladder, &person.body.contact_set) {
-                if float_ext::not_equal(move_data.move_direction.z, 0.0, EPSILON) {
-                    person.motion.mode = motion::Mode::Climb;
+    fn apply_stance(stance: motion::Stance, world: &World, person: &mut Person) {
+        if stance == person.motion.stance {
+            return;
+        }
 
-                    return;
-                }
+        let core_collider_radius = Collider::get_radius(
+            Body::get_collider(&BodyLabel::Core, &person.body).expect("Body is missing core"),
+        );
+
+        if stance == motion::Stance::Crouch {
+            let crouch_collider_radius = Vec3::new(
+                core_collider_radius.x,
+                core_collider_radius.y,
+                core_collider_radius.z * person.motion.crouch_scale,
+            );
+
+            person.motion.stand_core_radius = core_collider_radius;
+
+            Person::set_core_radius(crouch_collider_radius, person);
+        } else if person.motion.stance == motion::Stance::Crouch {
+            // Restored as stored rather than divided back out, which would drift each cycle
+            let stand_collider_radius = person.motion.stand_core_radius;
+
+            if !Self::has_headroom(stand_collider_radius, world, person) {
+                return;
             }
 
-            let horizontal_movement_direction =
-                Vec3::new(move_data.move_direction.x, move_data.move_direction.y, 0.0);
+            Person::set_core_radius(stand_collider_radius, person);
+        }
 
-            let horizontal_movement_direction =
-                if horizontal_movement_direction.mag_sq() > MOVEMENT_EPSILON {
-                    horizontal_movement_direction.normalized()
-                } else {
-                    Vec3::zero()
-                };
+        person.motion.stance = stance;
+    }
 
-            let local_velocity = person.motion.ground_speed * horizontal_movement_direction;
-            let velocity = person.transform.rotor * local_velocity;
+    fn has_headroom(core_collider_radius: Vec3, world: &World, person: &Person) -> bool {
+        let core_float_box = &Body::get_collider(&BodyLabel::Core, &person.body)
+            .expect("Body is missing core")
+            .float_box;
 
-            person.motion.velocity.x = velocity.x;
-            person.motion.velocity.y = velocity.y;
-        } else {
-            person.motion.velocity.x = 0.0;
-            person.motion.velocity.y = 0.0;
+        let rise = 2.0 * (core_collider_radius.z - core_float_box.radius.z);
+
+        let axis_resolution =
+            Physics::compute_axis_resolution(core_float_box, Axis::Z, rise, world);
+
+        axis_resolution.velocity_mask == 1.0
+    }
+
+    fn apply_ground_move(move_data: &MoveData, person: &mut Person) {
+        if move_data.move_direction.mag_sq() > MOVEMENT_EPSILON
+            && ContactSet::has(body::Contact::Ladder, &person.body.contact_set)
+        {
+            let climb_up = move_data.move_direction.z > 0.0;
+            let climb_down = move_data.move_direction.z < 0.0
+                && !ContactSet::has(body::Contact::Ground, &person.body.contact_set);
+
+            if climb_up || climb_down {
+                person.motion.mode = motion::Mode::Climb;
+                person.motion.velocity = Vec3::zero();
+            }
         }
     }
 
     fn apply_climb_move(move_data: &MoveData, person: &mut Person) {
+        if move_data.move_direction.z < 0.0
+            && ContactSet::has(body::Contact::Ground, &person.body.contact_set)
+        {
+            person.motion.mode = motion::Mode::Ground;
+
+            Self::apply_ground_move(move_data, person);
+
+            return;
+        }
+
         if move_data.move_direction.mag_sq() > MOVEMENT_EPSILON {
             let local_horizontal_movement_direction =
                 Vec3::new(move_data.move_direction.x, move_data.move_direction.y, 0.0);
@@ -131,24 +207,6 @@ impl Action {
         }
     }
 
-    fn apply_air_move(move_data: &MoveData, person: &mut Person) {
-        if move_data.move_direction.mag_sq() > MOVEMENT_EPSILON {
-            let local_horizontal_move_direction =
-                Vec3::new(move_data.move_direction.x, move_data.move_direction.y, 0.0);
-
-            let horizontal_move_direction = person.sight.rotor * local_horizontal_move_direction;
-            let vertical_move_direction = Vec3::new(0.0, 0.0, move_data.move_direction.z);
-
-            let move_direction = (horizontal_move_direction + vertical_move_direction).normalized();
-
-            let velocity = move_direction * person.motion.air_speed;
-
-            person.motion.velocity = velocity;
-        } else {
-            person.motion.velocity = Vec3::zero();
-        }
-    }
-
     pub fn apply_jump(jump_data: &JumpData, population: &mut Population) {
         if let Some(person) = population.person_map.get_mut(&jump_data.person_id) {
             match person.motion.mode {
@@ -165,11 +223,12 @@ impl Action {
 
     fn apply_place_block(
         place_block_data: &PlaceBlockData,
+        physics: &Physics,
         world: &mut World,
         population: &mut Population,
     ) {
         if let Some(person) = population.person_map.get_mut(&place_block_data.person_id) {
-            State::place_block(person, world);
+            State::place_block(person, &physics.spatial_hash, world);
         }
     }
 
@@ -182,4 +241,85 @@ impl Action {
             State::remove_block(person, world);
         }
     }
+
+    fn apply_copy_region(copy_region_data: &CopyRegionData, world: &mut World) {
+        let region = RegionEdit::copy(&copy_region_data.int_box, world);
+
+        let edit_journal = world
+            .edit_journal_map
+            .entry(copy_region_data.person_id)
+            .or_default();
+
+        edit_journal.clipboard_option = Some(region);
+    }
+
+    fn apply_paste_region(paste_region_data: &PasteRegionData, world: &mut World) {
+        let Some(mut edit_journal) = world.edit_journal_map.remove(&paste_region_data.person_id)
+        else {
+            return;
+        };
+
+        if let Some(region) = &edit_journal.clipboard_option {
+            let block_change_vec = RegionEdit::paste(
+                paste_region_data.grid_position,
+                &paste_region_data.direction,
+                region,
+                world,
+            );
+
+            EditJournal::record(block_change_vec, &mut edit_journal);
+        }
+
+        world
+            .edit_journal_map
+            .insert(paste_region_data.person_id, edit_journal);
+    }
+
+    fn apply_replace_region(replace_region_data: &ReplaceRegionData, world: &mut World) {
+        let block_change_vec = RegionEdit::replace(
+            &replace_region_data.int_box,
+            &replace_region_data.mask_block_kind,
+            &replace_region_data.block_kind_option,
+            world,
+        );
+
+        let edit_journal = world
+            .edit_journal_map
+            .entry(replace_region_data.person_id)
+            .or_default();
+
+        EditJournal::record(block_change_vec, edit_journal);
+    }
+
+    fn apply_undo(undo_data: &UndoData, world: &mut World) {
+        if let Some(mut edit_journal) = world.edit_journal_map.remove(&undo_data.person_id) {
+            RegionEdit::undo(&mut edit_journal, world);
+
+            world
+                .edit_journal_map
+                .insert(undo_data.person_id, edit_journal);
+        }
+    }
+
+    fn apply_redo(redo_data: &RedoData, world: &mut World) {
+        if let Some(mut edit_journal) = world.edit_journal_map.remove(&redo_data.person_id) {
+            RegionEdit::redo(&mut edit_journal, world);
+
+            world
+                .edit_journal_map
+                .insert(redo_data.person_id, edit_journal);
+        }
+    }
+
+    fn apply_door(door_data: &DoorData, world: &mut World, population: &Population) {
+        if let Some(person) = population.person_map.get(&door_data.person_id) {
+            State::toggle_door(person, world);
+        }
+    }
+
+    fn apply_lock_door(lock_door_data: &LockDoorData, world: &mut World, population: &Population) {
+        if let Some(person) = population.person_map.get(&lock_door_data.person_id) {
+            State::toggle_door_lock(person, world);
+        }
+    }
 }
@@ -1,20 +1,43 @@
+pub mod copy_region_data;
+pub mod door_data;
 pub mod jump_data;
+pub mod lock_door_data;
 pub mod move_data;
+pub mod paste_region_data;
 pub mod place_block_data;
+pub mod redo_data;
 pub mod remove_block_data;
+pub mod replace_region_data;
 pub mod rotate_data;
+pub mod undo_data;
 
+use serde::{Deserialize, Serialize};
+
+pub use copy_region_data::CopyRegionData;
+pub use door_data::DoorData;
 pub use jump_data::JumpData;
+pub use lock_door_data::LockDoorData;
 pub use move_data::MoveData;
+pub use paste_region_data::PasteRegionData;
 pub use place_block_data::PlaceBlockData;
+pub use redo_data::RedoData;
 pub use remove_block_data::RemoveBlockData;
+pub use replace_region_data::ReplaceRegionData;
 pub use rotate_data::RotateData;
+pub use undo_data::UndoData;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Act {
     Rotate(RotateData),
     Move(MoveData),
     Jump(JumpData),
     PlaceBlock(PlaceBlockData),
     RemoveBlock(RemoveBlockData),
+    CopyRegion(CopyRegionData),
+    PasteRegion(PasteRegionData),
+    ReplaceRegion(ReplaceRegionData),
+    Undo(UndoData),
+    Redo(RedoData),
+    Door(DoorData),
+    LockDoor(LockDoorData),
 }
@@ -0,0 +1,8 @@
+use crate::{simulation::state::population::person::person_id::PersonID, utils::ldmath::IntBox};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CopyRegionData {
+    pub person_id: PersonID,
+    pub int_box: IntBox,
+}
@@ -1,8 +1,10 @@
-use crate::simulation::state::population::person::person_id::PersonID;
+use crate::simulation::state::population::{motion::Stance, person::person_id::PersonID};
+use serde::{Deserialize, Serialize};
 use ultraviolet::Vec3;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct MoveData {
     pub person_id: PersonID,
     pub move_direction: Vec3,
+    pub stance: Stance,
 }
@@ -0,0 +1,10 @@
+use crate::simulation::state::{population::person::person_id::PersonID, world::grid::Direction};
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PasteRegionData {
+    pub person_id: PersonID,
+    pub grid_position: IVec3,
+    pub direction: Direction,
+}
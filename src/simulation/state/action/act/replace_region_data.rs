@@ -0,0 +1,15 @@
+use crate::{
+    simulation::state::{
+        population::person::person_id::PersonID, world::block::block_kind::BlockKind,
+    },
+    utils::ldmath::IntBox,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReplaceRegionData {
+    pub person_id: PersonID,
+    pub int_box: IntBox,
+    pub mask_block_kind: BlockKind,
+    pub block_kind_option: Option<BlockKind>,
+}
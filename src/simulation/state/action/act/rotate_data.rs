@@ -1,7 +1,8 @@
 use crate::simulation::state::population::person::person_id::PersonID;
+use serde::{Deserialize, Serialize};
 use ultraviolet::Vec3;
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct RotateData {
     pub person_id: PersonID,
     pub rotation_angles: Vec3,
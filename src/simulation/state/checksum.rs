@@ -0,0 +1,98 @@
+//! Canonical hash of State for determinism checks
+
+use crate::simulation::state::{
+    world::sector::{sector_index::SectorIndex, Sector},
+    State,
+};
+use rand_chacha::ChaCha8Rng;
+use std::{collections::BTreeMap, io};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+struct ChecksumWriter {
+    hash: u64,
+}
+
+impl io::Write for ChecksumWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        for byte in buf {
+            self.hash ^= *byte as u64;
+            self.hash = self.hash.wrapping_mul(FNV_PRIME);
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+pub struct Checksum {}
+
+impl Checksum {
+    pub fn compute(state: &State) -> u64 {
+        let mut checksum_writer = ChecksumWriter {
+            hash: FNV_OFFSET_BASIS,
+        };
+
+        let person_vec: Vec<_> = state
+            .population
+            .person_map
+            .values()
+            .map(|person| {
+                (
+                    person.person_id,
+                    &person.transform,
+                    person.motion.velocity,
+                    &person.motion.mode,
+                )
+            })
+            .collect();
+
+        // Resident and evicted sectors hash alike, so eviction never moves the checksum
+        let mut sector_checksum_map: BTreeMap<SectorIndex, u64> =
+            state.world.sector_store.checksum_map.clone();
+
+        for (sector_index, sector) in &state.world.sector_map {
+            if !Sector::is_empty(sector) {
+                sector_checksum_map.insert(*sector_index, Self::compute_sector(sector));
+            }
+        }
+
+        let rng_position_vec = [
+            Self::get_rng_position(&state.rng),
+            Self::get_rng_position(&state.world.rng),
+            Self::get_rng_position(&state.population.rng),
+        ];
+
+        let canonical_state = (
+            state.world.time.tick,
+            sector_checksum_map,
+            &state.world.block_tick_queue,
+            person_vec,
+            &state.world.tower,
+            rng_position_vec,
+        );
+
+        bincode::serialize_into(&mut checksum_writer, &canonical_state)
+            .expect("Failed to hash State");
+
+        checksum_writer.hash
+    }
+
+    pub fn compute_sector(sector: &Sector) -> u64 {
+        let mut checksum_writer = ChecksumWriter {
+            hash: FNV_OFFSET_BASIS,
+        };
+
+        bincode::serialize_into(&mut checksum_writer, sector).expect("Failed to hash Sector");
+
+        checksum_writer.hash
+    }
+
+    fn get_rng_position(rng: &ChaCha8Rng) -> ([u8; 32], u64, u128) {
+        (rng.get_seed(), rng.get_stream(), rng.get_word_pos())
+    }
+}
@@ -2,12 +2,14 @@ pub mod graph;
 pub mod path;
 
 pub use graph::Graph;
+use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
-use crate::simulation::{constants::*, state::World};
+use crate::simulation::state::World;
 use std::collections::{HashMap, VecDeque};
 use ultraviolet::IVec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Navigation {
     pub active: bool,
     pub graph: Graph,
@@ -48,16 +50,21 @@ impl Navigation {
     }
 
     pub fn init_graph(world: &World, graph: &mut Graph) {
-        let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
+        *graph = Graph::new();
 
-        for z in -sector_radius_in_cells..=sector_radius_in_cells {
-            for y in -sector_radius_in_cells..=sector_radius_in_cells {
-                for x in -sector_radius_in_cells..=sector_radius_in_cells {
-                    let grid_position = IVec3::new(x, y, z);
-                    let is_solid = World::get_block(grid_position, &world.sector_vec).is_some();
+        Self::update_graph(world, graph);
+    }
 
-                    Graph::set_solid(grid_position, is_solid, graph);
-                }
+    pub fn update_graph(world: &World, graph: &mut Graph) {
+        for sector_index in Graph::get_sector_index_vec(graph) {
+            if !world.sector_map.contains_key(&sector_index) {
+                Graph::remove_sector(&sector_index, graph);
+            }
+        }
+
+        for sector in world.sector_map.values() {
+            if !Graph::is_sector_current(sector, graph) {
+                Graph::update_sector(sector, graph);
             }
         }
     }
@@ -93,5 +100,7 @@ impl Navigation {
     }
 
     #[instrument(skip_all)]
-    pub fn tick(_world: &World, _navigation: &mut Self) {}
+    pub fn tick(world: &World, navigation: &mut Self) {
+        Self::update_graph(world, &mut navigation.graph);
+    }
 }
@@ -1,11 +1,21 @@
-use crate::simulation::constants::{
-    WORLD_RADIUS_IN_CELLS, WORLD_SIZE_IN_CELLS, WORLD_VOLUME_IN_CELLS,
+use crate::simulation::{
+    constants::{NAVIGATION_DOOR_COST, SECTOR_VOLUME_IN_CELLS},
+    state::world::{
+        block::block_state::BlockState,
+        cell::cell_index::CellIndex,
+        grid,
+        sector::{block_storage::BlockStorage, sector_index::SectorIndex, Sector},
+    },
 };
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use ultraviolet::IVec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Graph {
-    solid_vec: Vec<bool>,
-    cost_vec: Vec<u8>,
+    solid_map: BTreeMap<SectorIndex, Vec<bool>>,
+    cost_map: BTreeMap<SectorIndex, Vec<u8>>,
+    sector_version_map: BTreeMap<SectorIndex, u64>,
 }
 
 impl Graph {
@@ -40,32 +50,89 @@ impl Graph {
     ];
 
     pub fn new() -> Self {
-        let solid_vec = vec![false; WORLD_VOLUME_IN_CELLS];
-        let cost_vec = vec![1u8; WORLD_VOLUME_IN_CELLS];
+        let solid_map = BTreeMap::new();
+        let cost_map = BTreeMap::new();
+        let sector_version_map = BTreeMap::new();
 
         Self {
-            solid_vec,
-            cost_vec,
+            solid_map,
+            cost_map,
+            sector_version_map,
         }
     }
 
-    pub fn get_index(position: IVec3) -> usize {
-        let position_indexable = position + IVec3::broadcast(WORLD_RADIUS_IN_CELLS as i32);
+    pub fn is_sector_current(sector: &Sector, graph: &Self) -> bool {
+        graph.sector_version_map.get(&sector.sector_index) == Some(&sector.version)
+    }
+
+    pub fn update_sector(sector: &Sector, graph: &mut Self) {
+        let sector_index = sector.sector_index;
+
+        graph
+            .sector_version_map
+            .insert(sector_index, sector.version);
+
+        graph.solid_map.remove(&sector_index);
+        graph.cost_map.remove(&sector_index);
+
+        if Sector::is_empty(sector) {
+            return;
+        }
 
-        ((position_indexable.z as usize * WORLD_SIZE_IN_CELLS + position_indexable.y as usize)
-            * WORLD_SIZE_IN_CELLS
-            + position_indexable.x as usize) as usize
+        let mut solid_vec = vec![false; SECTOR_VOLUME_IN_CELLS];
+        let mut cost_vec = vec![1; SECTOR_VOLUME_IN_CELLS];
+
+        let mut has_cost = false;
+
+        for cell_index in grid::cell_index_vec() {
+            let Some(palette_entry) = BlockStorage::get_entry(cell_index, &sector.block_storage)
+            else {
+                continue;
+            };
+
+            let index = CellIndex::as_index(&cell_index);
+
+            match &palette_entry.block_state {
+                BlockState::Door(door_data) => {
+                    if door_data.is_locked {
+                        solid_vec[index] = true;
+                    } else if !door_data.is_open {
+                        cost_vec[index] = NAVIGATION_DOOR_COST;
+                        has_cost = true;
+                    }
+                }
+                _ => solid_vec[index] = true,
+            }
+        }
+
+        graph.solid_map.insert(sector_index, solid_vec);
+
+        if has_cost {
+            graph.cost_map.insert(sector_index, cost_vec);
+        }
+    }
+
+    pub fn remove_sector(sector_index: &SectorIndex, graph: &mut Self) {
+        graph.solid_map.remove(sector_index);
+        graph.cost_map.remove(sector_index);
+        graph.sector_version_map.remove(sector_index);
+    }
+
+    pub fn get_sector_index_vec(graph: &Self) -> Vec<SectorIndex> {
+        graph.sector_version_map.keys().copied().collect()
     }
 
     pub fn grid_position_is_valid(position: IVec3) -> bool {
-        let world_radius_in_cells = WORLD_RADIUS_IN_CELLS as i32;
-
-        position.x >= -world_radius_in_cells
-            && position.x <= world_radius_in_cells
-            && position.y >= -world_radius_in_cells
-            && position.y <= world_radius_in_cells
-            && position.z >= -world_radius_in_cells
-            && position.z <= world_radius_in_cells
+        grid::grid_position_is_valid(position)
+    }
+
+    pub fn is_solid(position: IVec3, graph: &Self) -> bool {
+        let (sector_index, cell_index) = grid::grid_position_to_indices(position);
+
+        graph
+            .solid_map
+            .get(&sector_index)
+            .is_some_and(|solid_vec| solid_vec[CellIndex::as_index(&cell_index)])
     }
 
     #[inline]
@@ -74,9 +141,7 @@ impl Graph {
             return false;
         }
 
-        let index = Self::get_index(position);
-
-        if graph.solid_vec[index] {
+        if Self::is_solid(position, graph) {
             return false;
         }
 
@@ -86,26 +151,35 @@ impl Graph {
             return false;
         }
 
-        let index_below = Self::get_index(position_below);
-
-        let is_solid_ground = graph.solid_vec[index_below];
+        let is_solid_ground = Self::is_solid(position_below, graph);
 
         is_solid_ground
     }
 
     pub fn set_solid(position: IVec3, is_solid: bool, graph: &mut Self) {
         if Self::grid_position_is_valid(position) {
-            let index = Self::get_index(position);
+            let (sector_index, cell_index) = grid::grid_position_to_indices(position);
+
+            if !is_solid && !graph.solid_map.contains_key(&sector_index) {
+                return;
+            }
+
+            let solid_vec = graph
+                .solid_map
+                .entry(sector_index)
+                .or_insert_with(|| vec![false; SECTOR_VOLUME_IN_CELLS]);
 
-            graph.solid_vec[index] = is_solid;
+            solid_vec[CellIndex::as_index(&cell_index)] = is_solid;
         }
     }
 
     #[inline]
     pub fn get_cost(position: IVec3, graph: &Self) -> i32 {
-        let index = Self::get_index(position);
+        let (sector_index, cell_index) = grid::grid_position_to_indices(position);
 
-        graph.cost_vec[index] as i32
+        graph.cost_map.get(&sector_index).map_or(1, |cost_vec| {
+            cost_vec[CellIndex::as_index(&cell_index)] as i32
+        })
     }
 
     #[inline]
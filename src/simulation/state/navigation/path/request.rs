@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Request {
     pub path_request_id: u64,
     pub start: IVec3,
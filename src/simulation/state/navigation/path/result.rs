@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Result {
     pub path_request_id: u64,
     pub path_vec: Vec<IVec3>,
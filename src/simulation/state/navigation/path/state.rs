@@ -1,4 +1,5 @@
 use crate::simulation::state::navigation::{self, Graph};
+use serde::{Deserialize, Serialize};
 use std::collections::{BinaryHeap, HashMap};
 use ultraviolet::IVec3;
 
@@ -15,7 +16,7 @@ pub struct AStarNode {
     pub parent: IVec3,
 }
 
-#[derive(Eq, PartialEq)]
+#[derive(Eq, PartialEq, Serialize, Deserialize)]
 struct OpenNode {
     pub position: IVec3,
     pub f_cost: i32,
@@ -33,6 +34,7 @@ impl PartialOrd for OpenNode {
     }
 }
 
+#[derive(Serialize, Deserialize)]
 pub struct State {
     pub id: u64,
     pub finished: bool,
@@ -52,6 +54,10 @@ impl State {
             f_cost: 0,
         });
 
+        let mut g_cost = HashMap::new();
+
+        g_cost.insert(start, 0);
+
         Self {
             id,
             finished: false,
@@ -59,7 +65,7 @@ impl State {
             end,
             open_heap,
             origin: Default::default(),
-            g_cost: Default::default(),
+            g_cost,
         }
     }
 
@@ -1,5 +1,7 @@
 use crate::simulation::state::navigation::path;
+use serde::{Deserialize, Serialize};
 
+#[derive(Serialize, Deserialize)]
 pub struct Task {
     pub path_request: path::Request,
     pub path_state: path::State,
@@ -7,6 +7,7 @@ pub mod collision_shape;
 pub mod hit;
 pub mod integration_result;
 pub mod resolution_result;
+pub mod spatial_hash;
 
 use crate::{
     simulation::{
@@ -19,9 +20,10 @@ use crate::{
                 hit::Hit,
                 integration_result::IntegrationResult,
                 resolution_result::ResolutionResult,
+                spatial_hash::SpatialHash,
             },
             population::{
-                motion,
+                motion::{self, Motion},
                 person::{person_id::PersonID, Person},
                 Population,
             },
@@ -34,46 +36,179 @@ use crate::{
     },
     utils::ldmath::{float_ext, FloatBox},
 };
-use std::f32;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, f32};
 use tracing::instrument;
 use ultraviolet::Vec3;
 
-#[derive(Default)]
+const BISECTION_ITERATION_COUNT: usize = 40;
+
+#[derive(Default, Serialize, Deserialize)]
 pub struct Physics {
     pub active: bool,
     pub gravity: Vec3,
+    #[serde(skip)]
+    pub spatial_hash: SpatialHash,
 }
 
 impl Physics {
     pub fn new() -> Self {
         let active = false;
         let gravity = Vec3::new(0.0, 0.0, -GRAVITY_ACCELERATION);
+        let spatial_hash = SpatialHash::new();
 
-        Self { active, gravity }
+        Self {
+            active,
+            gravity,
+            spatial_hash,
+        }
     }
 
     #[instrument(skip_all)]
     pub fn tick(world: &World, population: &mut Population, physics: &mut Self) {
-        if let Some(judge) = population.person_map.get_mut(&PersonID::JUDGE_ID_1) {
-            let integration_result = Self::integrate_person(physics, judge);
+        // Each parallel pass reads shared snapshots and writes a single person, so the
+        // result is independent of how rayon schedules the work
+        population
+            .person_map
+            .par_iter_mut()
+            .for_each(|(_, person)| Self::tick_person(world, physics, person));
+
+        SpatialHash::build(population, &mut physics.spatial_hash);
+
+        Self::separate_persons(world, population, physics);
+    }
+
+    fn tick_person(world: &World, physics: &Self, person: &mut Person) {
+        let mut integration_result = Self::integrate_person(physics, person);
+
+        if person.motion.mode == motion::Mode::Climb {
+            Self::limit_climb(world, person, &mut integration_result);
+        }
+
+        let resolution_result =
+            Self::compute_resolution_person(&integration_result.delta_intent, world, person);
+
+        let world_position = person.transform.world_position + resolution_result.delta_resolved;
+        let velocity = integration_result.velocity_intent * resolution_result.velocity_mask;
+
+        Person::set_world_position(world_position, person);
+        Person::set_velocity(velocity, person);
+
+        Self::update_contact(world, person);
+    }
+
+    fn separate_persons(world: &World, population: &mut Population, physics: &Self) {
+        let separation_map: HashMap<PersonID, Vec3> = physics
+            .spatial_hash
+            .float_box_map
+            .par_iter()
+            .filter_map(|(person_id, _)| {
+                let separation = Self::compute_separation(person_id, &physics.spatial_hash);
+
+                if separation.mag_sq() > 0.0 {
+                    Some((*person_id, separation))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        population
+            .person_map
+            .par_iter_mut()
+            .for_each(|(person_id, person)| {
+                if let Some(separation) = separation_map.get(person_id) {
+                    Self::apply_separation(*separation, world, person);
+                }
+            });
+    }
+
+    fn compute_separation(person_id: &PersonID, spatial_hash: &SpatialHash) -> Vec3 {
+        let mut separation = Vec3::zero();
+
+        if !SpatialHash::is_colliding(person_id, spatial_hash) {
+            return separation;
+        }
+
+        let float_box =
+            SpatialHash::get_float_box(person_id, spatial_hash).expect("Person is not hashed");
+
+        for other_person_id in SpatialHash::query(float_box, spatial_hash) {
+            if other_person_id == *person_id
+                || !SpatialHash::is_colliding(&other_person_id, spatial_hash)
+            {
+                continue;
+            }
+
+            let other_float_box = SpatialHash::get_float_box(&other_person_id, spatial_hash)
+                .expect("Person is not hashed");
+
+            let offset = float_box.center_position - other_float_box.center_position;
+            let penetration = float_box.radius + other_float_box.radius - offset.abs();
+
+            let axis_index = if penetration.x < penetration.y {
+                Axis::index(Axis::X)
+            } else {
+                Axis::index(Axis::Y)
+            };
+
+            if penetration[axis_index] <= 0.0 {
+                continue;
+            }
+
+            let sign = if offset[axis_index] != 0.0 {
+                offset[axis_index].signum()
+            } else if *person_id < other_person_id {
+                -1.0
+            } else {
+                1.0
+            };
+
+            separation[axis_index] +=
+                sign * 0.5 * COLLISION_SEPARATION_STIFFNESS * penetration[axis_index];
+        }
 
-            let resolution_result =
-                Self::compute_resolution_person(&integration_result.delta_intent, world, judge);
+        separation
+    }
 
-            let world_position = judge.transform.world_position + resolution_result.delta_resolved;
-            let velocity = integration_result.velocity_intent * resolution_result.velocity_mask;
+    fn apply_separation(separation: Vec3, world: &World, person: &mut Person) {
+        let mut core_float_box = Body::get_collider(&BodyLabel::Core, &person.body)
+            .expect("Body is missing core")
+            .clone()
+            .float_box;
+
+        let mut delta_resolved = Vec3::zero();
 
-            Person::set_world_position(world_position, judge);
-            Person::set_velocity(velocity, judge);
+        for delta_axis in [Axis::X, Axis::Y] {
+            let axis_index = Axis::index(delta_axis);
 
-            Self::update_contact(world, judge);
+            let axis_resolution = Self::compute_axis_resolution(
+                &core_float_box,
+                delta_axis,
+                separation[axis_index],
+                world,
+            );
+
+            delta_resolved[axis_index] = axis_resolution.delta_resolved;
+
+            core_float_box =
+                FloatBox::translated(Axis::unit(delta_axis) * delta_resolved, &core_float_box);
         }
+
+        let world_position = person.transform.world_position + delta_resolved;
+
+        Person::set_world_position(world_position, person);
     }
 
     fn integrate_person(physics: &Self, judge: &mut Person) -> IntegrationResult {
         let mut integration_result = IntegrationResult::new();
 
-        let initial_velocity = judge.motion.velocity;
+        let initial_velocity = match judge.motion.mode {
+            motion::Mode::Ground => Self::compute_ground_velocity(judge),
+            motion::Mode::Climb => judge.motion.velocity,
+            motion::Mode::Air => Self::compute_air_velocity(judge),
+        };
 
         let acceleration = if judge.motion.mode == motion::Mode::Ground {
             physics.gravity
@@ -90,6 +225,100 @@ impl Physics {
         integration_result
     }
 
+    fn compute_ground_velocity(person: &Person) -> Vec3 {
+        let motion = &person.motion;
+
+        let local_move_direction = Vec3::new(motion.move_direction.x, motion.move_direction.y, 0.0);
+
+        let move_direction = if local_move_direction.mag_sq() > MOVEMENT_EPSILON {
+            person.transform.rotor * local_move_direction.normalized()
+        } else {
+            Vec3::zero()
+        };
+
+        let mut horizontal_velocity = Vec3::new(motion.velocity.x, motion.velocity.y, 0.0);
+
+        let acceleration = if ContactSet::has(body::Contact::Ground, &person.body.contact_set) {
+            let speed = horizontal_velocity.mag();
+
+            if speed > 0.0 {
+                let mut speed_drop = speed * motion.ground_friction * SIMULATION_TICK_IN_SECONDS;
+
+                if move_direction == Vec3::zero() {
+                    speed_drop += motion.ground_deceleration * SIMULATION_TICK_IN_SECONDS;
+                }
+
+                horizontal_velocity *= (speed - speed_drop).max(0.0) / speed;
+            }
+
+            motion.ground_acceleration
+        } else {
+            motion.air_control * motion.ground_acceleration
+        };
+
+        // Acceleration only fills the gap to the stance speed along the move direction,
+        // so momentum from jumps and falls carries through
+        let target_speed = Motion::get_stance_speed(motion);
+        let current_speed = horizontal_velocity.dot(move_direction);
+
+        let speed_gain =
+            (target_speed - current_speed).clamp(0.0, acceleration * SIMULATION_TICK_IN_SECONDS);
+
+        horizontal_velocity += move_direction * speed_gain;
+
+        Vec3::new(
+            horizontal_velocity.x,
+            horizontal_velocity.y,
+            motion.velocity.z,
+        )
+    }
+
+    fn compute_air_velocity(person: &Person) -> Vec3 {
+        let motion = &person.motion;
+
+        let local_horizontal_move_direction =
+            Vec3::new(motion.move_direction.x, motion.move_direction.y, 0.0);
+
+        let move_direction = person.sight.rotor * local_horizontal_move_direction
+            + Vec3::new(0.0, 0.0, motion.move_direction.z);
+
+        let target_velocity = if move_direction.mag_sq() > MOVEMENT_EPSILON {
+            move_direction.normalized() * motion.air_speed
+        } else {
+            Vec3::zero()
+        };
+
+        let velocity_offset = target_velocity - motion.velocity;
+        let velocity_change = motion.air_acceleration * SIMULATION_TICK_IN_SECONDS;
+
+        if velocity_offset.mag() <= velocity_change {
+            target_velocity
+        } else {
+            motion.velocity + velocity_offset.normalized() * velocity_change
+        }
+    }
+
+    fn limit_climb(world: &World, person: &Person, integration_result: &mut IntegrationResult) {
+        if integration_result.delta_intent.z <= 0.0 {
+            return;
+        }
+
+        let ground_float_box = Body::get_collider(&BodyLabel::Ground, &person.body)
+            .expect("Body is missing ground")
+            .clone()
+            .float_box;
+
+        if let Some(ladder_top) = Self::get_ladder_top(&ground_float_box, world) {
+            let delta_limit =
+                (ladder_top - FloatBox::get_min(&ground_float_box).z - COLLISION_EPSILON).max(0.0);
+
+            if integration_result.delta_intent.z > delta_limit {
+                integration_result.delta_intent.z = delta_limit;
+                integration_result.velocity_intent.z = 0.0;
+            }
+        }
+    }
+
     fn compute_resolution_person(
         delta_intent: &Vec3,
         world: &World,
@@ -103,6 +332,7 @@ impl Physics {
             .float_box;
 
         let mut resolution_result = ResolutionResult::new();
+        let mut grounded = false;
 
         for delta_axis in [Axis::Z, Axis::X, Axis::Y] {
             let axis_index = Axis::index(delta_axis);
@@ -114,19 +344,164 @@ impl Physics {
                 world,
             );
 
-            resolution_result.delta_resolved[axis_index] = axis_resolution.delta_resolved;
-            resolution_result.velocity_mask[axis_index] = axis_resolution.velocity_mask;
+            let mut delta_resolved = Axis::unit(delta_axis) * axis_resolution.delta_resolved;
+            let mut velocity_mask = axis_resolution.velocity_mask;
+
+            if delta_axis == Axis::Z {
+                grounded = delta_intent.z < 0.0 && velocity_mask == 0.0;
+            } else if grounded && velocity_mask == 0.0 {
+                if let Some((step_delta, step_velocity_mask)) = Self::compute_step_resolution(
+                    &core_float_box,
+                    delta_axis,
+                    delta_intent[axis_index],
+                    axis_resolution.delta_resolved,
+                    world,
+                ) {
+                    delta_resolved = step_delta;
+                    velocity_mask = step_velocity_mask;
+                }
+            }
 
-            core_float_box = FloatBox::translated(
-                Axis::unit(delta_axis) * resolution_result.delta_resolved,
+            resolution_result.delta_resolved += delta_resolved;
+            resolution_result.velocity_mask[axis_index] = velocity_mask;
+
+            core_float_box = FloatBox::translated(delta_resolved, &core_float_box);
+        }
+
+        if grounded {
+            let snap_resolution = Self::compute_axis_resolution(
                 &core_float_box,
+                Axis::Z,
+                -(COLLISION_STEP_HEIGHT + 2.0 * COLLISION_EPSILON),
+                world,
             );
+
+            if snap_resolution.velocity_mask == 0.0 {
+                resolution_result.delta_resolved.z += snap_resolution.delta_resolved;
+            }
         }
 
         resolution_result
     }
 
-    fn compute_axis_resolution(
+    fn compute_step_resolution(
+        float_box: &FloatBox,
+        delta_axis: Axis,
+        delta_intent: f32,
+        delta_blocked: f32,
+        world: &World,
+    ) -> Option<(Vec3, f32)> {
+        let rise_resolution =
+            Self::compute_axis_resolution(float_box, Axis::Z, COLLISION_STEP_HEIGHT, world);
+
+        let raised_float_box =
+            FloatBox::translated(Vec3::unit_z() * rise_resolution.delta_resolved, float_box);
+
+        let step_resolution =
+            Self::compute_axis_resolution(&raised_float_box, delta_axis, delta_intent, world);
+
+        if step_resolution.delta_resolved.abs() <= delta_blocked.abs() + COLLISION_EPSILON {
+            return None;
+        }
+
+        let stepped_float_box = FloatBox::translated(
+            Axis::unit(delta_axis) * step_resolution.delta_resolved,
+            &raised_float_box,
+        );
+
+        let settle_resolution = Self::compute_axis_resolution(
+            &stepped_float_box,
+            Axis::Z,
+            -rise_resolution.delta_resolved,
+            world,
+        );
+
+        let step_delta = Axis::unit(delta_axis) * step_resolution.delta_resolved
+            + Vec3::unit_z() * (rise_resolution.delta_resolved + settle_resolution.delta_resolved);
+
+        Some((step_delta, step_resolution.velocity_mask))
+    }
+
+    pub fn compute_axis_resolution(
+        float_box: &FloatBox,
+        delta_axis: Axis,
+        delta_intent: f32,
+        world: &World,
+    ) -> AxisResolution {
+        let mut axis_resolution = AxisResolution::new();
+
+        let axis_index = Axis::index(delta_axis);
+        let delta_axis_unit = Axis::unit(delta_axis);
+        let delta_intent_sign = delta_intent.signum();
+
+        let swept_float_box = FloatBox::from_bounds(
+            FloatBox::get_min(float_box) + delta_axis_unit * delta_intent.min(0.0),
+            FloatBox::get_max(float_box) + delta_axis_unit * delta_intent.max(0.0),
+        );
+
+        let mut delta_free = delta_intent.abs();
+
+        for block_float_box in Self::get_solid_float_box_vec(&swept_float_box, world) {
+            let Some(gap) =
+                Self::get_axis_gap(float_box, &block_float_box, delta_axis, delta_intent)
+            else {
+                continue;
+            };
+
+            let is_nearest =
+                gap < delta_free || (gap == delta_free && axis_resolution.hit_option.is_none());
+
+            if is_nearest {
+                delta_free = gap;
+
+                let contact_float_box =
+                    FloatBox::translated(delta_axis_unit * delta_intent_sign * gap, float_box);
+
+                let mut contact_point =
+                    Self::get_overlap_center(&contact_float_box, &block_float_box);
+
+                contact_point[axis_index] = if delta_intent > 0.0 {
+                    FloatBox::get_min(&block_float_box)[axis_index]
+                } else {
+                    FloatBox::get_max(&block_float_box)[axis_index]
+                };
+
+                axis_resolution.hit_option = Some(Hit {
+                    collider_kind: ColliderKind::Solid,
+                    contact_point,
+                    normal: -delta_axis_unit * delta_intent_sign,
+                });
+            }
+        }
+
+        axis_resolution.delta_resolved = delta_intent_sign * delta_free;
+
+        if delta_intent != 0.0 {
+            axis_resolution.time_of_impact = delta_free / delta_intent.abs();
+        }
+
+        let collision_occurred = float_ext::not_equal(
+            axis_resolution.delta_resolved,
+            delta_intent,
+            COLLISION_EPSILON,
+        );
+
+        if collision_occurred {
+            let separation_bias = COLLISION_EPSILON * delta_intent_sign;
+
+            axis_resolution.delta_resolved -= separation_bias;
+            axis_resolution.velocity_mask = 0.0;
+        } else {
+            axis_resolution.velocity_mask = 1.0;
+            axis_resolution.hit_option = None;
+        };
+
+        axis_resolution
+    }
+
+    /// The bisection solver replaced by the swept one, kept to cross-check and bench it
+    #[doc(hidden)]
+    pub fn compute_axis_resolution_bisection(
         float_box: &FloatBox,
         delta_axis: Axis,
         delta_intent: f32,
@@ -140,9 +515,7 @@ impl Physics {
         let delta_axis_unit = Axis::unit(delta_axis);
         let delta_intent_sign = delta_intent.signum();
 
-        // let normal = delta_axis_unit * -delta_intent_sign;
-
-        for _ in 0..COLLISION_RESOLVE_ITERATIONS {
+        for _ in 0..BISECTION_ITERATION_COUNT {
             let t_mid = 0.5 * (t_min + t_max);
 
             let delta_translated = delta_intent * t_mid;
@@ -181,6 +554,68 @@ impl Physics {
         axis_resolution
     }
 
+    fn get_axis_gap(
+        float_box: &FloatBox,
+        block_float_box: &FloatBox,
+        delta_axis: Axis,
+        delta_intent: f32,
+    ) -> Option<f32> {
+        let axis_index = Axis::index(delta_axis);
+
+        // Boxes that only touch across the sweep still block it, matching FloatBox::overlap
+        for cross_axis in Axis::ALL {
+            if cross_axis != delta_axis {
+                let cross_index = Axis::index(cross_axis);
+
+                let distance = (float_box.center_position[cross_index]
+                    - block_float_box.center_position[cross_index])
+                    .abs();
+
+                if distance > float_box.radius[cross_index] + block_float_box.radius[cross_index] {
+                    return None;
+                }
+            }
+        }
+
+        let min = FloatBox::get_min(float_box)[axis_index];
+        let max = FloatBox::get_max(float_box)[axis_index];
+
+        let block_min = FloatBox::get_min(block_float_box)[axis_index];
+        let block_max = FloatBox::get_max(block_float_box)[axis_index];
+
+        // Blocks behind the leading face never stop the sweep, so penetrations can be escaped
+        let gap = if delta_intent > 0.0 && block_max > max {
+            block_min - max
+        } else if delta_intent < 0.0 && block_min < min {
+            min - block_max
+        } else {
+            return None;
+        };
+
+        Some(gap.max(0.0))
+    }
+
+    fn get_overlap_center(left: &FloatBox, right: &FloatBox) -> Vec3 {
+        let min = FloatBox::get_min(left).max_by_component(FloatBox::get_min(right));
+        let max = FloatBox::get_max(left).min_by_component(FloatBox::get_max(right));
+
+        (min + max) * 0.5
+    }
+
+    fn get_solid_float_box_vec(float_box: &FloatBox, world: &World) -> Vec<FloatBox> {
+        let mut solid_float_box_vec = Vec::new();
+
+        for grid_position in grid::get_float_box_grid_overlap_vec(float_box) {
+            if let Some(block) = World::get_block(grid_position, &world.sector_map) {
+                if block.block_shape != BlockShape::Ladder {
+                    solid_float_box_vec.extend(Self::get_block_float_box_iter(&block));
+                }
+            }
+        }
+
+        solid_float_box_vec
+    }
+
     fn update_contact(world: &World, judge: &mut Person) {
         let ground_float_box = Body::get_collider(&BodyLabel::Ground, &judge.body)
             .expect("Body is missing ground")
@@ -196,6 +631,13 @@ impl Physics {
             ContactSet::add(body::Contact::Ground, &mut judge.body.contact_set);
         }
 
+        if ground_hit_vec
+            .iter()
+            .any(|hit| hit.collider_kind == ColliderKind::Trigger)
+        {
+            ContactSet::add(body::Contact::Ladder, &mut judge.body.contact_set);
+        }
+
         if judge.motion.mode == motion::Mode::Climb
             && !ContactSet::has(body::Contact::Ladder, &judge.body.contact_set)
         {
@@ -204,43 +646,81 @@ impl Physics {
     }
 
     fn get_hit_vec(float_box: &FloatBox, world: &World) -> Vec<Hit> {
-        let overlap_grid_positions = grid::get_float_box_grid_overlap_vec(float_box);
-
-        let mut hit_vec = Vec::with_capacity(overlap_grid_positions.len());
-
-        for grid_position in overlap_grid_positions {
-            if let Some(block) = World::get_block(grid_position, &world.sector_vec) {
-                match block.block_shape {
-                    BlockShape::Block => {
-                        let contact_point = Vec3::from(grid_position);
-
+        let mut hit_vec = Vec::new();
+
+        for grid_position in grid::get_float_box_grid_overlap_vec(float_box) {
+            if let Some(block) = World::get_block(grid_position, &world.sector_map) {
+                let collider_kind = if block.block_shape == BlockShape::Ladder {
+                    ColliderKind::Trigger
+                } else {
+                    ColliderKind::Solid
+                };
+
+                for block_float_box in Self::get_block_float_box_iter(&block) {
+                    if FloatBox::overlap(float_box, &block_float_box) {
                         let hit = Hit {
-                            collider_kind: ColliderKind::Solid,
-                            contact_point,
+                            collider_kind,
+                            contact_point: Self::get_overlap_center(float_box, &block_float_box),
+                            normal: Self::get_overlap_normal(float_box, &block_float_box),
                         };
 
                         hit_vec.push(hit);
                     }
-                    _ => {
-                        let block_float_box_array = Block::get_float_box_array(block);
+                }
+            }
+        }
 
-                        for block_float_box in block_float_box_array {
-                            if FloatBox::overlap(float_box, block_float_box) {
-                                let contact_point = Vec3::from(grid_position);
+        hit_vec
+    }
 
-                                let hit = Hit {
-                                    collider_kind: ColliderKind::Solid,
-                                    contact_point,
-                                };
+    fn get_overlap_normal(float_box: &FloatBox, block_float_box: &FloatBox) -> Vec3 {
+        let offset = float_box.center_position - block_float_box.center_position;
+        let penetration = float_box.radius + block_float_box.radius - offset.abs();
 
-                                hit_vec.push(hit);
-                            }
+        let axis = Axis::ALL
+            .into_iter()
+            .min_by(|left, right| {
+                penetration[Axis::index(*left)].total_cmp(&penetration[Axis::index(*right)])
+            })
+            .expect("Axis list is empty");
+
+        let sign = if offset[Axis::index(axis)] < 0.0 {
+            -1.0
+        } else {
+            1.0
+        };
+
+        Axis::unit(axis) * sign
+    }
+
+    fn get_ladder_top(float_box: &FloatBox, world: &World) -> Option<f32> {
+        let mut ladder_top_option = None;
+
+        for grid_position in grid::get_float_box_grid_overlap_vec(float_box) {
+            if let Some(block) = World::get_block(grid_position, &world.sector_map) {
+                if block.block_shape == BlockShape::Ladder {
+                    for block_float_box in Self::get_block_float_box_iter(&block) {
+                        if FloatBox::overlap(float_box, &block_float_box) {
+                            let block_top = FloatBox::get_max(&block_float_box).z;
+
+                            ladder_top_option =
+                                Some(ladder_top_option.map_or(block_top, |ladder_top: f32| {
+                                    ladder_top.max(block_top)
+                                }));
                         }
                     }
                 }
             }
         }
 
-        hit_vec
+        ladder_top_option
+    }
+
+    fn get_block_float_box_iter(block: &Block) -> impl Iterator<Item = FloatBox> + '_ {
+        let block_world_position = Vec3::from(block.grid_position);
+
+        Block::get_float_box_array(block)
+            .iter()
+            .map(move |float_box| FloatBox::translated(block_world_position, float_box))
     }
 }
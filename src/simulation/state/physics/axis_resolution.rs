@@ -1,7 +1,11 @@
+use crate::simulation::state::physics::hit::Hit;
+
 #[derive(Clone, Debug)]
 pub struct AxisResolution {
     pub delta_resolved: f32,
     pub velocity_mask: f32,
+    pub time_of_impact: f32,
+    pub hit_option: Option<Hit>,
 }
 
 impl AxisResolution {
@@ -9,6 +13,8 @@ impl AxisResolution {
         Self {
             delta_resolved: 0.0,
             velocity_mask: 1.0,
+            time_of_impact: 1.0,
+            hit_option: None,
         }
     }
 }
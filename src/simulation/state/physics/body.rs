@@ -9,16 +9,18 @@ use crate::{
     simulation::state::physics::{body::body_label::BodyLabel, collider::Collider},
     utils::ldmath::FloatBox,
 };
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use ultraviolet::Vec3;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Body {
     pub world_position: Vec3,
     pub radius: Vec3,
     pub collider_map: HashMap<BodyLabel, usize>,
     pub collider_vec: Vec<Collider>,
     pub contact_set: ContactSet,
+    pub is_colliding: bool,
 }
 
 impl Body {
@@ -28,6 +30,7 @@ impl Body {
         let collider_map = HashMap::new();
         let collider_vec = Vec::new();
         let contact_set = ContactSet::EMPTY;
+        let is_colliding = true;
 
         Self {
             world_position,
@@ -35,6 +38,7 @@ impl Body {
             collider_map,
             collider_vec,
             contact_set,
+            is_colliding,
         }
     }
 
@@ -48,6 +52,10 @@ impl Body {
         }
     }
 
+    pub fn set_colliding(is_colliding: bool, body: &mut Self) {
+        body.is_colliding = is_colliding;
+    }
+
     pub fn add_collider(
         body_label: &BodyLabel,
         local_position: Vec3,
@@ -1,7 +1,8 @@
 use crate::simulation::state::physics::body::contact::Contact;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct ContactSet(u32);
 
 impl ContactSet {
@@ -3,9 +3,10 @@ pub mod collider_kind;
 pub use collider_kind::ColliderKind;
 
 use crate::utils::ldmath::FloatBox;
+use serde::{Deserialize, Serialize};
 use ultraviolet::Vec3;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Collider {
     pub local_position: Vec3,
     pub float_box: FloatBox,
@@ -5,4 +5,5 @@ use ultraviolet::Vec3;
 pub struct Hit {
     pub collider_kind: ColliderKind,
     pub contact_point: Vec3,
+    pub normal: Vec3,
 }
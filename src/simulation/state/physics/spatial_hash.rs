@@ -0,0 +1,146 @@
+use crate::{
+    simulation::{
+        constants::*,
+        state::{
+            physics::body::{body_label::BodyLabel, Body},
+            population::{person::person_id::PersonID, Population},
+        },
+    },
+    utils::ldmath::FloatBox,
+};
+use std::collections::{BTreeMap, HashMap};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug)]
+pub struct SpatialHash {
+    pub cell_map: HashMap<IVec3, Vec<PersonID>>,
+    pub float_box_map: BTreeMap<PersonID, FloatBox>,
+    pub colliding_map: BTreeMap<PersonID, bool>,
+}
+
+impl SpatialHash {
+    pub fn new() -> Self {
+        let cell_map = HashMap::new();
+        let float_box_map = BTreeMap::new();
+        let colliding_map = BTreeMap::new();
+
+        Self {
+            cell_map,
+            float_box_map,
+            colliding_map,
+        }
+    }
+
+    pub fn clear(spatial_hash: &mut Self) {
+        spatial_hash.cell_map.clear();
+        spatial_hash.float_box_map.clear();
+        spatial_hash.colliding_map.clear();
+    }
+
+    pub fn build(population: &Population, spatial_hash: &mut Self) {
+        Self::clear(spatial_hash);
+
+        for (person_id, person) in &population.person_map {
+            if let Some(core_collider) = Body::get_collider(&BodyLabel::Core, &person.body) {
+                Self::insert(
+                    *person_id,
+                    &core_collider.float_box,
+                    person.body.is_colliding,
+                    spatial_hash,
+                );
+            }
+        }
+    }
+
+    pub fn insert(
+        person_id: PersonID,
+        float_box: &FloatBox,
+        is_colliding: bool,
+        spatial_hash: &mut Self,
+    ) {
+        let (cell_min, cell_max) = Self::get_cell_range(float_box);
+
+        for z in cell_min.z..=cell_max.z {
+            for y in cell_min.y..=cell_max.y {
+                for x in cell_min.x..=cell_max.x {
+                    spatial_hash
+                        .cell_map
+                        .entry(IVec3::new(x, y, z))
+                        .or_default()
+                        .push(person_id);
+                }
+            }
+        }
+
+        spatial_hash
+            .float_box_map
+            .insert(person_id, float_box.clone());
+
+        spatial_hash.colliding_map.insert(person_id, is_colliding);
+    }
+
+    pub fn is_colliding(person_id: &PersonID, spatial_hash: &Self) -> bool {
+        spatial_hash
+            .colliding_map
+            .get(person_id)
+            .copied()
+            .unwrap_or(false)
+    }
+
+    pub fn get_float_box<'a>(person_id: &PersonID, spatial_hash: &'a Self) -> Option<&'a FloatBox> {
+        spatial_hash.float_box_map.get(person_id)
+    }
+
+    pub fn query(float_box: &FloatBox, spatial_hash: &Self) -> Vec<PersonID> {
+        let (cell_min, cell_max) = Self::get_cell_range(float_box);
+
+        let mut person_id_vec = Vec::new();
+
+        for z in cell_min.z..=cell_max.z {
+            for y in cell_min.y..=cell_max.y {
+                for x in cell_min.x..=cell_max.x {
+                    if let Some(cell_person_id_vec) =
+                        spatial_hash.cell_map.get(&IVec3::new(x, y, z))
+                    {
+                        for person_id in cell_person_id_vec {
+                            if FloatBox::overlap(float_box, &spatial_hash.float_box_map[person_id])
+                            {
+                                person_id_vec.push(*person_id);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        person_id_vec.sort();
+        person_id_vec.dedup();
+
+        person_id_vec
+    }
+
+    fn get_cell_range(float_box: &FloatBox) -> (IVec3, IVec3) {
+        let min = FloatBox::get_min(float_box) / COLLISION_HASH_CELL_SIZE;
+        let max = FloatBox::get_max(float_box) / COLLISION_HASH_CELL_SIZE;
+
+        let cell_min = IVec3::new(
+            min.x.floor() as i32,
+            min.y.floor() as i32,
+            min.z.floor() as i32,
+        );
+
+        let cell_max = IVec3::new(
+            max.x.floor() as i32,
+            max.y.floor() as i32,
+            max.z.floor() as i32,
+        );
+
+        (cell_min, cell_max)
+    }
+}
+
+impl Default for SpatialHash {
+    fn default() -> Self {
+        Self::new()
+    }
+}
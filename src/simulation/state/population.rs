@@ -16,22 +16,30 @@ use crate::{
                 identity::sex::Sex,
                 nation::{nation_kind::NationKind, Nation},
                 person::{person_id::PersonID, Person},
+                sight::Sight,
             },
+            world::grid::{self, Direction},
         },
     },
-    utils::{id_generator::IDGenerator, ldmath::rand_chacha_ext::gen_bool},
+    utils::{
+        id_generator::IDGenerator,
+        ldmath::rand_chacha_ext::{gen_bool, gen_range_i32},
+    },
 };
 use rand_chacha::{rand_core::SeedableRng, ChaCha8Rng};
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use tracing::instrument;
 use ultraviolet::{IVec3, Vec3};
 
+#[derive(Serialize, Deserialize)]
 pub struct Population {
     pub active: bool,
     pub rng: ChaCha8Rng,
     pub id_generator: IDGenerator,
-    pub nation_map: HashMap<NationKind, Nation>,
-    pub person_map: HashMap<PersonID, Person>,
+    pub nation_map: BTreeMap<NationKind, Nation>,
+    pub person_map: BTreeMap<PersonID, Person>,
+    pub controlled_id_set: BTreeSet<PersonID>,
 }
 
 impl Population {
@@ -40,7 +48,8 @@ impl Population {
         let rng = ChaCha8Rng::seed_from_u64(seed);
         let id_generator = IDGenerator::new();
         let nation_map = Self::setup_nation_map();
-        let person_map = HashMap::new();
+        let person_map = BTreeMap::new();
+        let controlled_id_set = BTreeSet::new();
 
         Self {
             active,
@@ -48,15 +57,17 @@ impl Population {
             id_generator,
             nation_map,
             person_map,
+            controlled_id_set,
         }
     }
 
     pub fn reset(population: &mut Self) {
         population.nation_map = Self::setup_nation_map();
-        population.person_map = HashMap::new();
+        population.person_map = BTreeMap::new();
+        population.controlled_id_set = BTreeSet::new();
     }
 
-    fn setup_nation_map() -> HashMap<NationKind, Nation> {
+    fn setup_nation_map() -> BTreeMap<NationKind, Nation> {
         let tower_radius = TOWER_RADIUS as i32;
 
         let home_radius = tower_radius - 10;
@@ -74,7 +85,7 @@ impl Population {
         let mut wolf_nation = Nation::new(NationKind::Wolf);
         wolf_nation.home_grid_position = IVec3::new(home_radius, 0, home_height);
 
-        let nation_map = HashMap::from([
+        let nation_map = BTreeMap::from([
             (NationKind::Lion, lion_nation),
             (NationKind::Eagle, eagle_nation),
             (NationKind::Horse, horse_nation),
@@ -94,20 +105,132 @@ impl Population {
             false => Sex::Male,
         };
 
-        let person_radius = Vec3::new(
+        let core_collider_radius = Vec3::new(
             PERSON_DEFAULT_RADIUS_X,
             PERSON_DEFAULT_RADIUS_Y,
             PERSON_DEFAULT_RADIUS_Z,
         );
 
+        Self::add_body_colliders(core_collider_radius, &mut person);
+
+        person
+    }
+
+    pub fn generate_judge(person_id: PersonID, population: &mut Self) {
+        tracing::info!("Generating Judge {:?}", person_id);
+
+        let mut judge = Person::new(person_id);
+
+        let judge_index = PersonID::JUDGE_ID_ARRAY
+            .iter()
+            .position(|judge_id| *judge_id == person_id)
+            .expect("Judge ID is not reserved");
+
+        let world_position = JUDGE_SPAWN_POSITION + Vec3::new(2.0 * judge_index as f32, 0.0, 0.0);
+
+        let core_collider_radius = Vec3::new(
+            JUDGE_DEFAULT_RADIUS_X,
+            JUDGE_DEFAULT_RADIUS_Y,
+            JUDGE_DEFAULT_RADIUS_Z,
+        );
+
+        Self::add_body_colliders(core_collider_radius, &mut judge);
+
+        let sight_local_position = Person::get_sight_local_position(core_collider_radius);
+
+        Sight::set_local_position(sight_local_position, &mut judge.sight);
+
+        Person::set_world_position(world_position, &mut judge);
+        Person::set_rotation(0.0, 0.0, &mut judge);
+
+        judge.motion.mode = motion::Mode::Ground;
+        judge.motion.ground_speed = JUDGE_DEFAULT_GROUND_SPEED;
+        judge.motion.climb_speed = JUDGE_DEFAULT_CLIMB_SPEED;
+        judge.motion.air_speed = JUDGE_DEFAULT_AIR_SPEED;
+        judge.motion.jump_speed = JUDGE_DEFAULT_JUMP_SPEED;
+        judge.motion.sprint_speed = JUDGE_DEFAULT_SPRINT_SPEED;
+        judge.motion.crouch_speed = JUDGE_DEFAULT_CROUCH_SPEED;
+        judge.motion.ground_acceleration = JUDGE_DEFAULT_GROUND_ACCELERATION;
+        judge.motion.ground_deceleration = JUDGE_DEFAULT_GROUND_DECELERATION;
+        judge.motion.ground_friction = JUDGE_DEFAULT_GROUND_FRICTION;
+        judge.motion.air_control = JUDGE_DEFAULT_AIR_CONTROL;
+        judge.motion.air_acceleration = JUDGE_DEFAULT_AIR_ACCELERATION;
+        judge.motion.crouch_scale = JUDGE_DEFAULT_CROUCH_SCALE;
+
+        population.controlled_id_set.insert(judge.person_id);
+        population.person_map.insert(judge.person_id, judge);
+    }
+
+    pub fn generate_citizen(nation_kind: &NationKind, population: &mut Self) -> Person {
+        let mut person = Self::generate_person(population);
+
+        let nation = &population.nation_map[nation_kind];
+
+        let temple_radius_x = TEMPLE_RADIUS_X as i32;
+        let temple_radius_y = TEMPLE_RADIUS_Y as i32;
+
+        let home_offset = IVec3::new(
+            gen_range_i32(
+                -temple_radius_x + 2,
+                temple_radius_x - 2,
+                &mut population.rng,
+            ),
+            gen_range_i32(
+                -temple_radius_y + 2,
+                temple_radius_y - 2,
+                &mut population.rng,
+            ),
+            2,
+        );
+
+        let grid_position = nation.home_grid_position + home_offset;
+        let world_position = grid::grid_position_to_world_position(grid_position);
+
+        Person::set_world_position(world_position, &mut person);
+
+        let direction = match nation_kind {
+            NationKind::Lion => Direction::South,
+            NationKind::Eagle => Direction::East,
+            NationKind::Horse => Direction::North,
+            NationKind::Wolf => Direction::West,
+        };
+
+        let rotation_xy = Direction::to_rotation(&direction);
+
+        Person::set_rotation(rotation_xy, 0.0, &mut person);
+
+        person
+    }
+
+    fn add_body_colliders(core_collider_radius: Vec3, person: &mut Person) {
+        let core_collider_local_position =
+            Vec3::new(0.0, 0.0, core_collider_radius.z - CELL_RADIUS_IN_METERS);
+
+        let ground_collider_radius = Vec3::new(
+            core_collider_radius.x,
+            core_collider_radius.y,
+            0.1 * core_collider_radius.z,
+        );
+
+        let ground_collider_local_position = Vec3::new(
+            0.0,
+            0.0,
+            ground_collider_radius.z - CELL_RADIUS_IN_METERS - (ground_collider_radius.z * 0.5),
+        );
+
         Body::add_collider(
             &BodyLabel::Core,
-            Vec3::zero(),
-            person_radius,
+            core_collider_local_position,
+            core_collider_radius,
             &mut person.body,
         );
 
-        person
+        Body::add_collider(
+            &BodyLabel::Ground,
+            ground_collider_local_position,
+            ground_collider_radius,
+            &mut person.body,
+        );
     }
 
     #[instrument(skip_all)]
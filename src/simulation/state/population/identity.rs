@@ -8,8 +8,9 @@ use crate::simulation::state::population::{
     identity::{age::Age, ethnicity::Ethnicity, role::Role, sex::Sex},
     nation::nation_kind::NationKind,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Identity {
     pub age: Age,
     pub sex: Sex,
@@ -4,8 +4,9 @@ pub mod skin_tone;
 use crate::simulation::state::population::identity::appearance::{
     hair_color::HairColor, skin_tone::SkinTone,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Appearance {
     pub skin_tone: SkinTone,
     pub hair_color: HairColor,
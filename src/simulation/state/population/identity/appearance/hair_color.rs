@@ -1,4 +1,6 @@
-#[derive(Clone, Hash, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum HairColor {
     Person1,
     Person2,
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
-#[derive(Clone, Display, Hash, PartialEq, Eq, EnumString)]
+#[derive(Clone, Display, Hash, PartialEq, Eq, EnumString, Serialize, Deserialize)]
 pub enum SkinTone {
     Person1,
     Person2,
@@ -1,14 +1,15 @@
 use crate::simulation::state::population::nation::nation_kind::NationKind;
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ethnicity {
-    pub ethnicity_map: HashMap<NationKind, f32>,
+    pub ethnicity_map: BTreeMap<NationKind, f32>,
 }
 
 impl Ethnicity {
     pub fn new() -> Self {
-        let ethnicity_map = HashMap::from([
+        let ethnicity_map = BTreeMap::from([
             (NationKind::Eagle, 0.0),
             (NationKind::Lion, 0.0),
             (NationKind::Wolf, 0.0),
@@ -19,7 +20,7 @@ impl Ethnicity {
     }
 
     pub fn from_nation_kind(nation_kind: &NationKind) -> Self {
-        let mut ethnicity_map = HashMap::from([
+        let mut ethnicity_map = BTreeMap::from([
             (NationKind::Eagle, 0.0),
             (NationKind::Lion, 0.0),
             (NationKind::Wolf, 0.0),
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 
-#[derive(Clone, Display, Debug, EnumString, Hash, PartialEq, Eq)]
+#[derive(Clone, Display, Debug, EnumString, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Sex {
     Male,
     Female,
@@ -1,17 +1,31 @@
 pub mod mode;
+pub mod stance;
 
 pub use mode::Mode;
+pub use stance::Stance;
 
 use crate::simulation::constants::*;
+use serde::{Deserialize, Serialize};
 use ultraviolet::Vec3;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Motion {
     pub mode: self::Mode,
+    pub stance: self::Stance,
     pub ground_speed: f32,
+    pub sprint_speed: f32,
+    pub crouch_speed: f32,
     pub climb_speed: f32,
     pub air_speed: f32,
     pub jump_speed: f32,
+    pub ground_acceleration: f32,
+    pub ground_deceleration: f32,
+    pub ground_friction: f32,
+    pub air_control: f32,
+    pub air_acceleration: f32,
+    pub crouch_scale: f32,
+    pub stand_core_radius: Vec3,
+    pub move_direction: Vec3,
     pub velocity: Vec3,
 }
 
@@ -19,13 +33,32 @@ impl Motion {
     pub fn new() -> Self {
         Self {
             mode: self::Mode::Ground,
+            stance: self::Stance::Walk,
             ground_speed: PERSON_DEFAULT_GROUND_SPEED,
+            sprint_speed: PERSON_DEFAULT_SPRINT_SPEED,
+            crouch_speed: PERSON_DEFAULT_CROUCH_SPEED,
             climb_speed: PERSON_DEFAULT_CLIMB_SPEED,
             air_speed: PERSON_DEFAULT_AIR_SPEED,
             jump_speed: PERSON_DEFAULT_JUMP_SPEED,
+            ground_acceleration: PERSON_DEFAULT_GROUND_ACCELERATION,
+            ground_deceleration: PERSON_DEFAULT_GROUND_DECELERATION,
+            ground_friction: PERSON_DEFAULT_GROUND_FRICTION,
+            air_control: PERSON_DEFAULT_AIR_CONTROL,
+            air_acceleration: PERSON_DEFAULT_AIR_ACCELERATION,
+            crouch_scale: PERSON_DEFAULT_CROUCH_SCALE,
+            stand_core_radius: Vec3::zero(),
+            move_direction: Vec3::zero(),
             velocity: Vec3::zero(),
         }
     }
+
+    pub fn get_stance_speed(motion: &Self) -> f32 {
+        match motion.stance {
+            Stance::Walk => motion.ground_speed,
+            Stance::Sprint => motion.sprint_speed,
+            Stance::Crouch => motion.crouch_speed,
+        }
+    }
 }
 
 impl Default for Motion {
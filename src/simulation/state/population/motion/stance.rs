@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Stance {
+    Walk,
+    Sprint,
+    Crouch,
+}
+
+impl Stance {
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Stance::Walk => "walk",
+            Stance::Sprint => "sprint",
+            Stance::Crouch => "crouch",
+        }
+    }
+}
+
+impl fmt::Display for Stance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -5,9 +5,10 @@ use crate::simulation::state::{
     population::nation::{leadership::Leadership, nation_kind::NationKind},
     world::block::block_kind::BlockKind,
 };
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Nation {
     pub nation_kind: NationKind,
     pub home_grid_position: IVec3,
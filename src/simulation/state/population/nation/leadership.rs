@@ -1,6 +1,7 @@
 use crate::simulation::state::population::person::person_id::PersonID;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Leadership {
     pub judge_id: PersonID,
 }
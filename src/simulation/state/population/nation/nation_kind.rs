@@ -1,4 +1,21 @@
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumString, VariantArray};
+
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    EnumString,
+    Eq,
+    Hash,
+    PartialEq,
+    PartialOrd,
+    Ord,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
 pub enum NationKind {
     Lion,
     Eagle,
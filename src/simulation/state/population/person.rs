@@ -1,18 +1,23 @@
 pub mod person_id;
 
-use crate::simulation::state::{
-    physics::body::Body,
-    population::{
-        identity::{appearance::Appearance, Identity},
-        motion::Motion,
-        person::person_id::PersonID,
-        sight::Sight,
-        transform::Transform,
+use crate::simulation::{
+    constants::CELL_RADIUS_IN_METERS,
+    state::{
+        physics::body::{body_label::BodyLabel, Body},
+        population::{
+            identity::{appearance::Appearance, Identity},
+            motion::Motion,
+            person::person_id::PersonID,
+            sight::Sight,
+            transform::Transform,
+        },
+        world::block::block_kind::BlockKind,
     },
-    world::block::block_kind::BlockKind,
 };
+use serde::{Deserialize, Serialize};
 use ultraviolet::Vec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Person {
     pub person_id: PersonID,
     pub identity: Identity,
@@ -66,4 +71,31 @@ impl Person {
     pub fn set_velocity(velocity: Vec3, person: &mut Self) {
         person.motion.velocity = velocity;
     }
+
+    pub fn set_core_radius(core_collider_radius: Vec3, person: &mut Self) {
+        let core_collider_local_position =
+            Vec3::new(0.0, 0.0, core_collider_radius.z - CELL_RADIUS_IN_METERS);
+
+        Body::set_collider_radius(&BodyLabel::Core, core_collider_radius, &mut person.body);
+        Body::set_collider_local_position(
+            &BodyLabel::Core,
+            core_collider_local_position,
+            &mut person.body,
+        );
+
+        let sight_local_position = Self::get_sight_local_position(core_collider_radius);
+
+        Sight::set_local_position(sight_local_position, &mut person.sight);
+
+        Self::set_world_position(person.transform.world_position, person);
+    }
+
+    pub fn get_sight_local_position(core_collider_radius: Vec3) -> Vec3 {
+        Vec3::new(
+            0.0,
+            0.0,
+            ((0.9 * core_collider_radius.z) - CELL_RADIUS_IN_METERS)
+                + (0.9 * core_collider_radius.z),
+        )
+    }
 }
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct PersonID(u32);
 
 impl PersonID {
@@ -9,6 +10,13 @@ impl PersonID {
     pub const JUDGE_ID_3: PersonID = PersonID::new(3);
     pub const JUDGE_ID_4: PersonID = PersonID::new(4);
 
+    pub const JUDGE_ID_ARRAY: [PersonID; 4] = [
+        Self::JUDGE_ID_1,
+        Self::JUDGE_ID_2,
+        Self::JUDGE_ID_3,
+        Self::JUDGE_ID_4,
+    ];
+
     pub const fn new(id_value: u32) -> Self {
         Self(id_value)
     }
@@ -2,9 +2,10 @@ use crate::{
     simulation::constants::{SECTOR_RADIUS_IN_METERS, SECTOR_SIZE_IN_METERS},
     utils::ldmath::rotor3_ext,
 };
+use serde::{Deserialize, Serialize};
 use ultraviolet::{Rotor3, Vec3};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Sight {
     pub local_position: Vec3,
     pub world_position: Vec3,
@@ -1,7 +1,8 @@
 use crate::utils::ldmath::rotor3_ext;
+use serde::{Deserialize, Serialize};
 use ultraviolet::{Rotor3, Vec3};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Transform {
     pub world_position: Vec3,
     pub rotation_xy: f32,
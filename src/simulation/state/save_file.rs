@@ -0,0 +1,88 @@
+//! Versioned on-disk format for State and its Scheduler
+
+use crate::simulation::{
+    constants::{SAVE_DIRECTORY, SAVE_EXTENSION, SAVE_FORMAT_MAGIC, SAVE_FORMAT_VERSION},
+    state::{
+        world::{sector::Sector, sector_store::SectorStore},
+        State,
+    },
+    supervisor::scheduler::Scheduler,
+};
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::{Path, PathBuf},
+};
+
+pub struct SaveFile {}
+
+impl SaveFile {
+    pub fn get_path(save_name: &str) -> PathBuf {
+        let path = Path::new(SAVE_DIRECTORY)
+            .join(save_name)
+            .with_extension(SAVE_EXTENSION);
+
+        path
+    }
+
+    pub fn name_is_valid(save_name: &str) -> bool {
+        !save_name.is_empty()
+            && save_name
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || "_-".contains(character))
+    }
+
+    pub fn write(path: &Path, state: &State, scheduler: &Scheduler) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        writer.write_all(&SAVE_FORMAT_MAGIC)?;
+        writer.write_all(&SAVE_FORMAT_VERSION.to_le_bytes())?;
+
+        let stored_sector_vec = SectorStore::read_all(&state.world.sector_store)?;
+
+        bincode::serialize_into(&mut writer, &(state, scheduler, stored_sector_vec))
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        writer.flush()
+    }
+
+    pub fn read(path: &Path) -> io::Result<(State, Scheduler)> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+
+        if magic != SAVE_FORMAT_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a save file",
+            ));
+        }
+
+        let mut version_bytes = [0u8; 4];
+        reader.read_exact(&mut version_bytes)?;
+
+        let version = u32::from_le_bytes(version_bytes);
+
+        if version != SAVE_FORMAT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported save version {version} (expected {SAVE_FORMAT_VERSION})"),
+            ));
+        }
+
+        let (mut state, scheduler, stored_sector_vec): (State, Scheduler, Vec<Sector>) =
+            bincode::deserialize_from(&mut reader)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        for sector in &stored_sector_vec {
+            SectorStore::write(sector, &mut state.world.sector_store)?;
+        }
+
+        Ok((state, scheduler))
+    }
+}
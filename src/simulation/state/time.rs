@@ -1,11 +1,14 @@
 //! Time within simulation
 
+use serde::{Deserialize, Serialize};
 use std::time::Instant;
 
 use tracing::instrument;
 
+#[derive(Serialize, Deserialize)]
 pub struct Time {
     pub tick: u64,
+    #[serde(skip, default = "Instant::now")]
     pub instant: Instant,
 }
 
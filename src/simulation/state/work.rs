@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use tracing::instrument;
 
 use crate::simulation::state::{
@@ -10,6 +11,7 @@ pub mod construct_worker;
 pub mod navigation_task;
 pub mod navigation_worker;
 
+#[derive(Serialize, Deserialize)]
 pub struct Work {
     pub construct_worker: ConstructWorker,
     pub navigation_worker: NavigationWorker,
@@ -1,8 +1,9 @@
 pub mod generate_data;
 
 use crate::simulation::state::{work::construct_task::generate_data::GenerateData, State};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum ConstructTask {
     Generate(GenerateData),
 }
@@ -2,26 +2,23 @@ use crate::{
     simulation::{
         constants::*,
         state::{
-            physics::body::{body_label::BodyLabel, Body},
             population::{
-                identity::sex::Sex,
-                motion,
-                nation::nation_kind::NationKind,
-                person::{person_id::PersonID, Person},
-                sight::Sight,
+                identity::sex::Sex, nation::nation_kind::NationKind, person::person_id::PersonID,
             },
             world::{
                 area::{
                     self,
                     area_id::AreaID,
                     template::{
-                        ElevatorCapTemplate, ElevatorTemplate, GenericRoomTemplate, Template,
-                        TempleTemplate, TradingPlatformTemplate, WireframeTemplate,
+                        ElevatorCapTemplate, ElevatorTemplate, GenericRoomTemplate,
+                        SchematicTemplate, Template, TempleTemplate, TradingPlatformTemplate,
+                        WireframeTemplate,
                     },
                     Area, AreaKind, Connection,
                 },
                 block::block_kind::BlockKind,
                 grid::{self, Direction, Line},
+                schematic::Schematic,
                 tower::{self, Tower},
             },
             Population, State, World,
@@ -32,10 +29,11 @@ use crate::{
         ldmath::rand_chacha_ext::{gen_bool, gen_f32, gen_range_i32},
     },
 };
-use std::collections::HashMap;
-use ultraviolet::{IVec3, Vec3};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use ultraviolet::IVec3;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct GenerateData {
     pub stage_index: usize,
     pub stage_cost_map: HashMap<usize, u32>,
@@ -62,7 +60,7 @@ impl GenerateData {
                 Population::reset(&mut state.population);
                 World::reset(&mut state.world);
 
-                Self::generate_judge(&mut state.population);
+                Population::generate_judge(PersonID::JUDGE_ID_1, &mut state.population);
             }
             1 => {
                 Self::generate_nations(&mut state.population);
@@ -96,74 +94,14 @@ impl GenerateData {
         generate_world_data.stage_index >= generate_world_data.stage_cost_map.len()
     }
 
-    fn generate_judge(population: &mut Population) {
-        tracing::info!("Generating Judge 1");
-
-        let mut judge = Person::new(PersonID::JUDGE_ID_1);
-
-        let world_position = Vec3::new(0.0, -32.0, 2.0);
-
-        let core_collider_radius = Vec3::new(
-            JUDGE_DEFAULT_RADIUS_X,
-            JUDGE_DEFAULT_RADIUS_Y,
-            JUDGE_DEFAULT_RADIUS_Z,
-        );
-
-        let ground_collider_radius = Vec3::new(
-            JUDGE_DEFAULT_RADIUS_X,
-            JUDGE_DEFAULT_RADIUS_Y,
-            0.1 * JUDGE_DEFAULT_RADIUS_Z,
-        );
-
-        let ground_collider_local_position = Vec3::new(
-            0.0,
-            0.0,
-            ground_collider_radius.z - CELL_RADIUS_IN_METERS - (ground_collider_radius.z * 0.5),
-        );
-
-        Body::add_collider(
-            &BodyLabel::Core,
-            Vec3::zero(),
-            core_collider_radius,
-            &mut judge.body,
-        );
-
-        Body::add_collider(
-            &BodyLabel::Ground,
-            ground_collider_local_position,
-            ground_collider_radius,
-            &mut judge.body,
-        );
-
-        let sight_local_position = Vec3::new(
-            0.0,
-            0.0,
-            ((0.9 * core_collider_radius.z) - CELL_RADIUS_IN_METERS)
-                + (0.9 * core_collider_radius.z),
-        );
-
-        Sight::set_local_position(sight_local_position, &mut judge.sight);
-
-        Person::set_world_position(world_position, &mut judge);
-        Person::set_rotation(0.0, 0.0, &mut judge);
-
-        judge.motion.mode = motion::Mode::Ground;
-        judge.motion.ground_speed = JUDGE_DEFAULT_GROUND_SPEED;
-        judge.motion.climb_speed = JUDGE_DEFAULT_CLIMB_SPEED;
-        judge.motion.air_speed = JUDGE_DEFAULT_AIR_SPEED;
-        judge.motion.jump_speed = JUDGE_DEFAULT_JUMP_SPEED;
-
-        population.person_map.insert(judge.person_id, judge);
-    }
-
     fn generate_nations(population: &mut Population) {
         tracing::info!("Generating Nations");
 
-        let nation_map = population.nation_map.clone();
+        let nation_kind_vec: Vec<NationKind> = population.nation_map.keys().copied().collect();
 
-        for (_, nation) in nation_map {
+        for nation_kind in nation_kind_vec {
             for index in 1..=NATION_INITIAL_POPULATION {
-                let mut person = Population::generate_person(population);
+                let mut person = Population::generate_citizen(&nation_kind, population);
 
                 person.identity.sex = if index <= NATION_INITIAL_POPULATION / 2 {
                     Sex::Female
@@ -171,39 +109,6 @@ impl GenerateData {
                     Sex::Male
                 };
 
-                let temple_radius_x = TEMPLE_RADIUS_X as i32;
-                let temple_radius_y = TEMPLE_RADIUS_Y as i32;
-
-                let home_offset = IVec3::new(
-                    gen_range_i32(
-                        -temple_radius_x + 2,
-                        temple_radius_x - 2,
-                        &mut population.rng,
-                    ),
-                    gen_range_i32(
-                        -temple_radius_y + 2,
-                        temple_radius_y - 2,
-                        &mut population.rng,
-                    ),
-                    2,
-                );
-
-                let grid_position = nation.home_grid_position + home_offset;
-                let world_position = grid::grid_position_to_world_position(grid_position);
-
-                Person::set_world_position(world_position, &mut person);
-
-                let direction = match nation.nation_kind {
-                    NationKind::Lion => Direction::South,
-                    NationKind::Eagle => Direction::East,
-                    NationKind::Horse => Direction::North,
-                    NationKind::Wolf => Direction::West,
-                };
-
-                let rotation_xy = Direction::to_rotation(&direction);
-
-                Person::set_rotation(rotation_xy, 0.0, &mut person);
-
                 population.person_map.insert(person.person_id, person);
             }
         }
@@ -242,6 +147,8 @@ impl GenerateData {
         );
 
         for floor_number in -tower_floor_count..0 {
+            Self::select_room_styles(floor_number, world);
+
             let floor = world
                 .tower
                 .floor_map
@@ -523,7 +430,7 @@ impl GenerateData {
                 .map(|(area_id, _)| *area_id)
                 .collect();
 
-            let mut new_room_area_map: HashMap<AreaID, Area> = HashMap::new();
+            let mut new_room_area_map: BTreeMap<AreaID, Area> = BTreeMap::new();
 
             for area_id in lower_room_id_vec {
                 let area = floor.id_area_map.remove(&area_id).unwrap();
@@ -627,6 +534,48 @@ impl GenerateData {
         }
     }
 
+    fn select_room_styles(floor_number: i32, world: &mut World) {
+        let schematic_vec: Vec<Schematic> = world
+            .schematic_map
+            .values()
+            .filter(|schematic| schematic.area_kind_option.is_some())
+            .cloned()
+            .collect();
+
+        if schematic_vec.is_empty() {
+            return;
+        }
+
+        let floor = world
+            .tower
+            .floor_map
+            .get_mut(&floor_number)
+            .expect("Floors should exist!");
+
+        for area in floor.id_area_map.values_mut() {
+            if !matches!(area.style, area::Style::GenericRoom) {
+                continue;
+            }
+
+            let candidate_vec: Vec<&Schematic> = schematic_vec
+                .iter()
+                .filter(|schematic| {
+                    schematic.area_kind_option.as_ref() == Some(&area.area_kind)
+                        && Schematic::fits(area, schematic)
+                })
+                .collect();
+
+            // The last choice keeps the hand-coded generic room
+            let choice = gen_range_i32(0, candidate_vec.len() as i32, &mut world.rng) as usize;
+
+            if let Some(schematic) = candidate_vec.get(choice) {
+                area.style = area::Style::Schematic {
+                    name: schematic.name.clone(),
+                };
+            }
+        }
+    }
+
     fn construct_nation_temples(population: &Population, world: &mut World) {
         tracing::info!("Constructing Nation Temples");
 
@@ -687,6 +636,7 @@ impl GenerateData {
             area::Style::ElevatorCap => ElevatorCapTemplate::construct(area, world),
             area::Style::TradingPlatform => TradingPlatformTemplate::construct(area, world),
             area::Style::Temple { nation_kind: _ } => TempleTemplate::construct(area, world),
+            area::Style::Schematic { name: _ } => SchematicTemplate::construct(area, world),
         }
     }
 }
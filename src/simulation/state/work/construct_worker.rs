@@ -1,7 +1,8 @@
 use crate::simulation::state::{work::construct_task::ConstructTask, State};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct ConstructWorker {
     pub budget: u32,
     pub task_deque: VecDeque<ConstructTask>,
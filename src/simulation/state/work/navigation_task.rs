@@ -3,8 +3,9 @@ pub mod find_path_data;
 use crate::simulation::state::{
     navigation::Navigation, work::navigation_task::find_path_data::FindPathData,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub enum NavigationTask {
     FindPath(FindPathData),
 }
@@ -1,9 +1,10 @@
 use crate::simulation::state::navigation::{path, Navigation};
 use egui::ahash::HashSet;
+use serde::{Deserialize, Serialize};
 use std::collections::{BinaryHeap, HashMap};
 use ultraviolet::IVec3;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct FindPathData {
     pub start: IVec3,
     pub end: IVec3,
@@ -1,7 +1,8 @@
 use crate::simulation::state::{work::navigation_task::NavigationTask, State};
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct NavigationWorker {
     pub budget: u32,
     pub task_deque: VecDeque<NavigationTask>,
@@ -2,15 +2,22 @@
 
 pub mod area;
 pub mod block;
+pub mod block_tick;
 pub mod cell;
+pub mod door;
 pub mod grid;
+pub mod light;
+pub mod region_edit;
+pub mod schematic;
 pub mod sector;
+pub mod sector_store;
 pub mod tower;
 
 use crate::{
     simulation::{
         constants::*,
         state::{
+            population::person::person_id::PersonID,
             world::{
                 area::{area_id::AreaID, Area, AreaKind},
                 block::{
@@ -19,12 +26,15 @@ use crate::{
                     block_state::{block_data::BlockData, BlockState},
                     Block,
                 },
-                cell::cell_index::CellIndex,
+                block_tick::{block_tick_queue::BlockTickQueue, BlockTick},
                 grid::{direction_set::DirectionSet, Direction},
+                region_edit::edit_journal::EditJournal,
+                schematic::Schematic,
                 sector::{sector_index::SectorIndex, Sector},
+                sector_store::SectorStore,
                 tower::Tower,
             },
-            Time,
+            Population, Time,
         },
     },
     utils::{
@@ -36,14 +46,27 @@ use rand_chacha::{
     rand_core::{RngCore, SeedableRng},
     ChaCha8Rng,
 };
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    io,
+};
 use tracing::instrument;
 use ultraviolet::{IVec3, Vec3};
 
+#[derive(Serialize, Deserialize)]
 pub struct World {
     pub active: bool,
     pub rng: ChaCha8Rng,
     pub time: Time,
-    pub sector_vec: Vec<Sector>,
+    pub sector_map: BTreeMap<SectorIndex, Sector>,
+    #[serde(skip)]
+    pub sector_store: SectorStore,
+    pub light_change_vec: Vec<IVec3>,
+    pub light_sector_set: BTreeSet<SectorIndex>,
+    pub block_tick_queue: BlockTickQueue,
+    pub edit_journal_map: BTreeMap<PersonID, EditJournal>,
+    pub schematic_map: BTreeMap<String, Schematic>,
     pub tower: Tower,
     pub area_id_generator: IDGenerator,
     pub structure_id_generator: IDGenerator,
@@ -54,7 +77,13 @@ impl World {
         let active = false;
         let rng = ChaCha8Rng::seed_from_u64(seed);
         let time = Time::new();
-        let sector_vec = Self::setup_sector_vec();
+        let sector_map = BTreeMap::new();
+        let sector_store = SectorStore::new();
+        let light_change_vec = Vec::new();
+        let light_sector_set = BTreeSet::new();
+        let block_tick_queue = BlockTickQueue::new();
+        let edit_journal_map = BTreeMap::new();
+        let schematic_map = BTreeMap::new();
         let tower = Tower::new();
         let area_id_generator = IDGenerator::new();
         let structure_id_generator = IDGenerator::new();
@@ -63,7 +92,13 @@ impl World {
             active,
             rng,
             time,
-            sector_vec,
+            sector_map,
+            sector_store,
+            light_change_vec,
+            light_sector_set,
+            block_tick_queue,
+            edit_journal_map,
+            schematic_map,
             tower,
             area_id_generator,
             structure_id_generator,
@@ -73,84 +108,162 @@ impl World {
     #[instrument(skip_all)]
     pub fn tick(world: &mut Self) {
         Time::tick(&mut world.time);
+        BlockTick::tick(world);
     }
 
     pub fn reset(world: &mut Self) {
-        world.sector_vec = Self::setup_sector_vec();
+        world.sector_map.clear();
+        world.light_change_vec.clear();
+        world.light_sector_set.clear();
+        world.edit_journal_map.clear();
+
+        // Schematics arrive through SetSchematics messages, so replays never read local files
 
+        BlockTickQueue::clear(&mut world.block_tick_queue);
+        SectorStore::clear(&mut world.sector_store);
         Tower::reset(&mut world.tower);
     }
 
-    fn setup_sector_vec() -> Vec<Sector> {
-        grid::sector_index_vec()
-            .into_iter()
-            .map(|sector_index| {
-                let version = 0;
-                let grid_position = grid::sector_index_to_grid_position(sector_index);
-                let block_vec = vec![None; SECTOR_VOLUME_IN_CELLS];
-
-                Sector {
-                    version,
-                    sector_index: sector_index,
-                    grid_position,
-                    block_vec,
+    #[instrument(skip_all)]
+    pub fn update_sectors(population: &Population, world: &mut Self) {
+        let focus_sector_index_set: BTreeSet<SectorIndex> = population
+            .person_map
+            .values()
+            .map(|person| grid::world_position_to_sector_index(person.transform.world_position))
+            .collect();
+
+        let load_radius = WORLD_LOAD_RADIUS_IN_SECTORS;
+
+        for focus_sector_index in &focus_sector_index_set {
+            let focus_sector_coordinate = SectorIndex::to_sector_coordinate(focus_sector_index);
+
+            for z in -load_radius..=load_radius {
+                for y in -load_radius..=load_radius {
+                    for x in -load_radius..=load_radius {
+                        let sector_coordinate = focus_sector_coordinate + IVec3::new(x, y, z);
+                        let sector_index = SectorIndex::new(sector_coordinate);
+
+                        Self::load_sector(sector_index, world);
+                    }
                 }
+            }
+        }
+
+        let evicted_sector_index_vec: Vec<SectorIndex> = world
+            .sector_map
+            .keys()
+            .filter(|sector_index| {
+                let sector_coordinate = SectorIndex::to_sector_coordinate(sector_index);
+
+                focus_sector_index_set.iter().all(|focus_sector_index| {
+                    let offset =
+                        sector_coordinate - SectorIndex::to_sector_coordinate(focus_sector_index);
+
+                    offset.abs().component_max() > WORLD_UNLOAD_RADIUS_IN_SECTORS
+                })
             })
-            .collect()
+            .copied()
+            .collect();
+
+        for sector_index in evicted_sector_index_vec {
+            if let Err(error) = Self::evict_sector(&sector_index, world) {
+                tracing::error!(
+                    "Sector evict failed, keeping {:?} resident: {}",
+                    sector_index,
+                    error
+                );
+            }
+        }
     }
 
-    pub fn get_sector(grid_position: IVec3, sector_vec_slice: &[Sector]) -> &Sector {
+    pub fn load_sector(sector_index: SectorIndex, world: &mut Self) -> &mut Sector {
+        if let Err(error) = Self::restore_sector(&sector_index, world) {
+            tracing::error!("Sector restore failed: {:?} {}", sector_index, error);
+        }
+
+        world.sector_map.entry(sector_index).or_insert_with(|| {
+            world.light_sector_set.insert(sector_index);
+
+            Sector::new(sector_index)
+        })
+    }
+
+    pub fn restore_sector(sector_index: &SectorIndex, world: &mut Self) -> io::Result<()> {
+        if world.sector_map.contains_key(sector_index)
+            || !SectorStore::contains(sector_index, &world.sector_store)
+        {
+            return Ok(());
+        }
+
+        let sector = SectorStore::take(sector_index, &mut world.sector_store)?;
+
+        world.sector_map.insert(*sector_index, sector);
+        world.light_sector_set.insert(*sector_index);
+
+        Ok(())
+    }
+
+    fn evict_sector(sector_index: &SectorIndex, world: &mut Self) -> io::Result<()> {
+        let Some(sector) = world.sector_map.get(sector_index) else {
+            return Ok(());
+        };
+
+        // A sector that could not be written stays resident rather than being lost
+        if !Sector::is_empty(sector) {
+            SectorStore::write(sector, &mut world.sector_store)?;
+        }
+
+        world.sector_map.remove(sector_index);
+
+        Ok(())
+    }
+
+    pub fn get_sector(
+        grid_position: IVec3,
+        sector_map: &BTreeMap<SectorIndex, Sector>,
+    ) -> Option<&Sector> {
         let sector_index = grid::grid_position_to_sector_index(grid_position);
 
-        let sector = Self::get_sector_by_index(&sector_index, sector_vec_slice);
+        let sector = Self::get_sector_by_index(&sector_index, sector_map);
 
         sector
     }
 
-    pub fn get_sector_mut(grid_position: IVec3, sector_vec_slice: &mut [Sector]) -> &mut Sector {
+    pub fn get_sector_mut(
+        grid_position: IVec3,
+        sector_map: &mut BTreeMap<SectorIndex, Sector>,
+    ) -> Option<&mut Sector> {
         let sector_index = grid::grid_position_to_sector_index(grid_position);
 
-        let sector = Self::get_sector_mut_by_index(&sector_index, sector_vec_slice);
+        let sector = Self::get_sector_mut_by_index(&sector_index, sector_map);
 
         sector
     }
 
     pub fn get_sector_by_index<'a>(
         sector_index: &SectorIndex,
-        sector_vec_slice: &'a [Sector],
-    ) -> &'a Sector {
-        &sector_vec_slice[SectorIndex::as_index(sector_index)]
+        sector_map: &'a BTreeMap<SectorIndex, Sector>,
+    ) -> Option<&'a Sector> {
+        sector_map.get(sector_index)
     }
 
     pub fn get_sector_mut_by_index<'a>(
         sector_index: &SectorIndex,
-        sector_vec_slice: &'a mut [Sector],
-    ) -> &'a mut Sector {
-        &mut sector_vec_slice[SectorIndex::as_index(sector_index)]
+        sector_map: &'a mut BTreeMap<SectorIndex, Sector>,
+    ) -> Option<&'a mut Sector> {
+        sector_map.get_mut(sector_index)
     }
 
-    pub fn get_block(grid_position: IVec3, sector_vec_slice: &[Sector]) -> Option<&Block> {
-        if grid::grid_position_is_valid(grid_position) {
-            let (sector_index, cell_index) = grid::grid_position_to_indices(grid_position);
-
-            let sector: &Sector = &sector_vec_slice[SectorIndex::as_index(&sector_index)];
-
-            Sector::get_block(cell_index, &sector.block_vec)
-        } else {
-            None
-        }
-    }
-
-    pub fn get_block_mut(
+    pub fn get_block(
         grid_position: IVec3,
-        sector_vec_slice: &mut [Sector],
-    ) -> Option<&mut Block> {
+        sector_map: &BTreeMap<SectorIndex, Sector>,
+    ) -> Option<Block> {
         if grid::grid_position_is_valid(grid_position) {
             let (sector_index, cell_index) = grid::grid_position_to_indices(grid_position);
 
-            let sector = &mut sector_vec_slice[SectorIndex::as_index(&sector_index)];
+            let sector = sector_map.get(&sector_index)?;
 
-            Sector::get_block_mut(cell_index, &mut sector.block_vec)
+            Sector::get_block(cell_index, sector)
         } else {
             None
         }
@@ -173,13 +286,25 @@ impl World {
                         let neighbor_grid_position = grid_position + Direction::to_ivec3(direction);
 
                         if grid::grid_position_is_valid(neighbor_grid_position) {
-                            if let Some(neighbor_block) =
-                                World::get_block_mut(neighbor_grid_position, &mut world.sector_vec)
+                            let neighbor_sector_index =
+                                grid::grid_position_to_sector_index(neighbor_grid_position);
+
+                            if let Err(error) = Self::restore_sector(&neighbor_sector_index, world)
+                            {
+                                tracing::error!(
+                                    "Sector restore failed: {:?} {}",
+                                    neighbor_sector_index,
+                                    error
+                                );
+                            }
+
+                            if let Some(mut neighbor_block) =
+                                World::get_block(neighbor_grid_position, &world.sector_map)
                             {
                                 let BlockState::Block(neighbor_block_data) =
                                     &mut neighbor_block.block_state
                                 else {
-                                    panic!("neighbor block is missing block state")
+                                    continue;
                                 };
 
                                 DirectionSet::remove(
@@ -188,6 +313,8 @@ impl World {
                                 );
 
                                 DirectionSet::remove(direction, &mut exposure_set);
+
+                                Self::store_block(neighbor_block, world);
                             }
                         }
                     }
@@ -205,9 +332,19 @@ impl World {
 
             let (sector_index, cell_index) = grid::grid_position_to_indices(grid_position);
 
-            let sector = &mut world.sector_vec[SectorIndex::as_index(&sector_index)];
-            sector.block_vec[CellIndex::as_index(&cell_index)] = Some(block);
-            sector.version += 1;
+            let sector = Self::load_sector(sector_index, world);
+
+            Sector::set_block(cell_index, Some(block), sector);
+
+            world.light_change_vec.push(grid_position);
+        }
+    }
+
+    pub fn store_block(block: Block, world: &mut Self) {
+        let (sector_index, cell_index) = grid::grid_position_to_indices(block.grid_position);
+
+        if let Some(sector) = world.sector_map.get_mut(&sector_index) {
+            Sector::set_block(cell_index, Some(block), sector);
         }
     }
 
@@ -334,27 +471,41 @@ impl World {
                 let neighbor_grid_position = grid_position + Direction::to_ivec3(direction);
 
                 if grid::grid_position_is_valid(neighbor_grid_position) {
-                    if let Some(block) =
-                        World::get_block_mut(neighbor_grid_position, &mut world.sector_vec)
+                    let neighbor_sector_index =
+                        grid::grid_position_to_sector_index(neighbor_grid_position);
+
+                    if let Err(error) = Self::restore_sector(&neighbor_sector_index, world) {
+                        tracing::error!(
+                            "Sector restore failed: {:?} {}",
+                            neighbor_sector_index,
+                            error
+                        );
+                    }
+
+                    if let Some(mut block) =
+                        World::get_block(neighbor_grid_position, &world.sector_map)
                     {
                         let BlockState::Block(block_data) = &mut block.block_state else {
-                            panic!("block should have block data")
+                            continue;
                         };
 
                         DirectionSet::add(
                             &Direction::to_opposing(direction),
                             &mut block_data.exposure_set,
                         );
+
+                        Self::store_block(block, world);
                     }
                 }
             }
 
             let (sector_index, cell_index) = grid::grid_position_to_indices(grid_position);
 
-            let sector = &mut world.sector_vec[SectorIndex::as_index(&sector_index)];
+            let sector = Self::load_sector(sector_index, world);
+
+            Sector::set_block(cell_index, None, sector);
 
-            sector.block_vec[CellIndex::as_index(&cell_index)] = None;
-            sector.version += 1;
+            world.light_change_vec.push(grid_position);
         }
     }
 
@@ -478,7 +629,7 @@ impl World {
                 hit_normal = -step.z * IVec3::unit_z();
             }
 
-            if World::get_block(cell_grid_position, &world.sector_vec).is_some() {
+            if World::get_block(cell_grid_position, &world.sector_map).is_some() {
                 return Some((cell_grid_position, hit_normal));
             }
         }
@@ -17,9 +17,10 @@ use crate::{
     },
     utils::ldmath::{ivec3_ext::rotate_by_direction, IntBox},
 };
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Area {
     pub area_id: AreaID,
     pub area_kind: AreaKind,
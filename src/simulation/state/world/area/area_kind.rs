@@ -1,4 +1,19 @@
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, VariantArray};
+
+#[derive(
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    VariantArray,
+    Serialize,
+    Deserialize,
+)]
 pub enum AreaKind {
     Center,
     CenterHall,
@@ -1,7 +1,8 @@
 use crate::simulation::state::world::{area::area_id::AreaID, grid::Line};
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Connection {
     pub area_id1: AreaID,
     pub area_id2: AreaID,
@@ -1,6 +1,7 @@
 use crate::simulation::state::population::nation::nation_kind::NationKind;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Style {
     None,
     Wireframe,
@@ -9,4 +10,5 @@ pub enum Style {
     ElevatorCap,
     TradingPlatform,
     Temple { nation_kind: NationKind },
+    Schematic { name: String },
 }
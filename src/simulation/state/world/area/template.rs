@@ -1,6 +1,7 @@
 pub mod elevator_cap_template;
 pub mod elevator_template;
 pub mod generic_room_template;
+pub mod schematic_template;
 pub mod temple_template;
 pub mod trading_platform_template;
 pub mod wireframe_template;
@@ -8,6 +9,7 @@ pub mod wireframe_template;
 pub use elevator_cap_template::ElevatorCapTemplate;
 pub use elevator_template::ElevatorTemplate;
 pub use generic_room_template::GenericRoomTemplate;
+pub use schematic_template::SchematicTemplate;
 pub use temple_template::TempleTemplate;
 pub use trading_platform_template::TradingPlatformTemplate;
 pub use wireframe_template::WireframeTemplate;
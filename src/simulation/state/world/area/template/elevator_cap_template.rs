@@ -60,13 +60,20 @@ impl Template for ElevatorCapTemplate {
             world,
         );
 
-        // let area_int_box = grid::get_grid_int_box(area.grid_position, area.size);
+        template::set_block(
+            IVec3::new(3, 2, 0),
+            &Direction::East,
+            &BlockKind::Stairs1,
+            area,
+            world,
+        );
 
-        // World::set_object(
-        //     IVec3::new(area_int_box.min.x + 2, area_int_box.min.y + 2, area_int_box.min.z),
-        //     object::Kind::Platform,
-        //     Direction::South,
-        //     world,
-        // );
+        template::set_block(
+            IVec3::new(2, 2, 0),
+            &Direction::North,
+            &BlockKind::Metal2,
+            area,
+            world,
+        );
     }
 }
@@ -69,180 +69,32 @@ impl Template for ElevatorTemplate {
 
         // Stairs
 
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 2,
-        //         area_int_box.min.y + 2,
-        //         area_int_box.min.z + 0,
-        //     ),
-        //     object::Kind::Platform,
-        //     Direction::South,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 2,
-        //         area_int_box.min.y + 3,
-        //         area_int_box.min.z + 1,
-        //     ),
-        //     object::Kind::Stairs,
-        //     Direction::South,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 2,
-        //         area_int_box.min.y + 4,
-        //         area_int_box.min.z + 1,
-        //     ),
-        //     object::Kind::Platform,
-        //     Direction::South,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 3,
-        //         area_int_box.min.y + 4,
-        //         area_int_box.min.z + 2,
-        //     ),
-        //     object::Kind::Stairs,
-        //     Direction::West,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 4,
-        //         area_int_box.min.y + 4,
-        //         area_int_box.min.z + 2,
-        //     ),
-        //     object::Kind::Platform,
-        //     Direction::West,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 4,
-        //         area_int_box.min.y + 3,
-        //         area_int_box.min.z + 3,
-        //     ),
-        //     object::Kind::Stairs,
-        //     Direction::North,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 4,
-        //         area_int_box.min.y + 2,
-        //         area_int_box.min.z + 3,
-        //     ),
-        //     object::Kind::Platform,
-        //     Direction::North,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 3,
-        //         area_int_box.min.y + 2,
-        //         area_int_box.min.z + 4,
-        //     ),
-        //     object::Kind::Stairs,
-        //     Direction::East,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 2,
-        //         area_int_box.min.y + 2,
-        //         area_int_box.min.z + 4,
-        //     ),
-        //     object::Kind::Platform,
-        //     Direction::East,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 2,
-        //         area_int_box.min.y + 3,
-        //         area_int_box.min.z + 5,
-        //     ),
-        //     object::Kind::Stairs,
-        //     Direction::South,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 2,
-        //         area_int_box.min.y + 4,
-        //         area_int_box.min.z + 5,
-        //     ),
-        //     object::Kind::Platform,
-        //     Direction::South,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 3,
-        //         area_int_box.min.y + 4,
-        //         area_int_box.min.z + 6,
-        //     ),
-        //     object::Kind::Stairs,
-        //     Direction::West,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 4,
-        //         area_int_box.min.y + 4,
-        //         area_int_box.min.z + 6,
-        //     ),
-        //     object::Kind::Platform,
-        //     Direction::West,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 4,
-        //         area_int_box.min.y + 3,
-        //         area_int_box.min.z + 7,
-        //     ),
-        //     object::Kind::Stairs,
-        //     Direction::North,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 4,
-        //         area_int_box.min.y + 2,
-        //         area_int_box.min.z + 7,
-        //     ),
-        //     object::Kind::Platform,
-        //     Direction::North,
-        //     world,
-        // );
-
-        // World::set_object(
-        //     IVec3::new(
-        //         area_int_box.min.x + 3,
-        //         area_int_box.min.y + 2,
-        //         area_int_box.min.z + 8,
-        //     ),
-        //     object::Kind::Stairs,
-        //     Direction::East,
-        //     world,
-        // );
+        let spiral_array = [
+            (IVec3::new(3, 2, 0), IVec3::new(2, 2, 0), Direction::East),
+            (IVec3::new(2, 3, 0), IVec3::new(2, 4, 0), Direction::South),
+            (IVec3::new(3, 4, 0), IVec3::new(4, 4, 0), Direction::West),
+            (IVec3::new(4, 3, 0), IVec3::new(4, 2, 0), Direction::North),
+        ];
+
+        for level in 0..area.size.z {
+            let (stairs_offset, platform_offset, direction) =
+                &spiral_array[level as usize % spiral_array.len()];
+
+            let level_offset = IVec3::new(0, 0, level);
+
+            World::set_block(
+                area_int_box.min + *stairs_offset + level_offset,
+                direction,
+                &BlockKind::Stairs1,
+                world,
+            );
+
+            World::set_block(
+                area_int_box.min + *platform_offset + level_offset,
+                &Direction::North,
+                &BlockKind::Metal2,
+                world,
+            );
+        }
     }
 }
@@ -0,0 +1,23 @@
+use crate::simulation::state::world::{
+    area::{self, template::Template},
+    schematic::Schematic,
+};
+
+pub struct SchematicTemplate {}
+
+impl Template for SchematicTemplate {
+    fn construct(
+        area: &crate::simulation::state::world::Area,
+        world: &mut crate::simulation::state::World,
+    ) {
+        if let area::Style::Schematic { name } = &area.style {
+            let Some(schematic) = world.schematic_map.get(name).cloned() else {
+                tracing::error!("Missing schematic {}", name);
+
+                return;
+            };
+
+            Schematic::construct(&schematic, area, world);
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub mod block_kind;
 pub mod block_shape;
 pub mod block_state;
 
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
 use crate::{
@@ -16,7 +17,7 @@ use crate::{
     utils::ldmath::FloatBox,
 };
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Block {
     pub block_kind: BlockKind,
     pub grid_position: IVec3,
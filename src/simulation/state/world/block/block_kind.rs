@@ -1,4 +1,5 @@
 use crate::simulation::state::world::block::block_shape::BlockShape;
+use serde::{Deserialize, Serialize};
 use strum::{EnumCount, VariantArray};
 use strum_macros::{Display, EnumCount, EnumIter, EnumString, VariantArray};
 
@@ -15,6 +16,8 @@ use strum_macros::{Display, EnumCount, EnumIter, EnumString, VariantArray};
     Eq,
     PartialOrd,
     VariantArray,
+    Deserialize,
+    Serialize,
 )]
 pub enum BlockKind {
     CardinalEast,
@@ -150,4 +153,16 @@ impl BlockKind {
             BlockKind::Stairs1 => BlockShape::Stairs,
         }
     }
+
+    pub fn is_opaque(block_kind: &Self) -> bool {
+        Self::get_block_shape(block_kind) == BlockShape::Block
+    }
+
+    pub fn get_light_emission(block_kind: &Self) -> u8 {
+        match block_kind {
+            BlockKind::Server1 | BlockKind::Server2 | BlockKind::Server3 | BlockKind::Server4 => 10,
+            BlockKind::Vent1 | BlockKind::Vent2 | BlockKind::Vent3 | BlockKind::Vent4 => 14,
+            _ => 0,
+        }
+    }
 }
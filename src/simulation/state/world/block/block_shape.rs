@@ -1,8 +1,9 @@
 use crate::{simulation::constants::*, utils::ldmath::FloatBox};
+use serde::{Deserialize, Serialize};
 use strum_macros::{Display, EnumString};
 use ultraviolet::Vec3;
 
-#[derive(Clone, Debug, EnumString, Display, Hash, PartialEq, Eq)]
+#[derive(Clone, Debug, EnumString, Display, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BlockShape {
     Block,
     DoorLower,
@@ -12,89 +13,90 @@ pub enum BlockShape {
 }
 
 impl BlockShape {
+    // Radii are half extents around the cell center, so every shape stays inside its cell
     pub const EMPTY_SHAPE_ARRAY: [FloatBox; 0] = [];
 
     pub const BLOCK_SHAPE_ARRAY: [FloatBox; 1] = [FloatBox {
         center_position: Vec3::new(0.0, 0.0, 0.0),
-        radius: Vec3::new(CELL_UNIT_01, CELL_UNIT_01, CELL_UNIT_01),
+        radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_02, CELL_UNIT_02),
     }];
 
     pub const PLATFORM_SHAPE_ARRAY: [FloatBox; 1] = [FloatBox {
         center_position: Vec3::new(0.0, 0.0, CELL_UNIT_04),
-        radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_02, CELL_UNIT_02),
+        radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_02, CELL_UNIT_04),
     }];
 
     pub const DOOR_CLOSED_X_SHAPE_ARRAY: [FloatBox; 1] = [FloatBox {
         center_position: Vec3::new(0.0, 0.0, 0.0),
-        radius: Vec3::new(CELL_UNIT_01, CELL_UNIT_08, CELL_UNIT_01),
+        radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_02, CELL_UNIT_02),
     }];
 
     pub const DOOR_OPEN_X_SHAPE_ARRAY: [FloatBox; 2] = [
         FloatBox {
-            center_position: Vec3::new(-CELL_UNIT_04, 0.0, 0.0),
-            radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_08, CELL_UNIT_01),
+            center_position: Vec3::new(0.0, -7.0 * CELL_UNIT_16, 0.0),
+            radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_16, CELL_UNIT_02),
         },
         FloatBox {
-            center_position: Vec3::new(CELL_UNIT_04, 0.0, 0.0),
-            radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_08, CELL_UNIT_01),
+            center_position: Vec3::new(0.0, 7.0 * CELL_UNIT_16, 0.0),
+            radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_16, CELL_UNIT_02),
         },
     ];
 
     pub const DOOR_CLOSED_Y_SHAPE_ARRAY: [FloatBox; 1] = [FloatBox {
         center_position: Vec3::new(0.0, 0.0, 0.0),
-        radius: Vec3::new(CELL_UNIT_01, CELL_UNIT_08, CELL_UNIT_01),
+        radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_16, CELL_UNIT_02),
     }];
 
     pub const DOOR_OPEN_Y_SHAPE_ARRAY: [FloatBox; 2] = [
         FloatBox {
-            center_position: Vec3::new(-CELL_UNIT_04, 0.0, 0.0),
-            radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_08, CELL_UNIT_01),
+            center_position: Vec3::new(-7.0 * CELL_UNIT_16, 0.0, 0.0),
+            radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_16, CELL_UNIT_02),
         },
         FloatBox {
-            center_position: Vec3::new(CELL_UNIT_04, 0.0, 0.0),
-            radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_08, CELL_UNIT_01),
+            center_position: Vec3::new(7.0 * CELL_UNIT_16, 0.0, 0.0),
+            radius: Vec3::new(CELL_UNIT_16, CELL_UNIT_16, CELL_UNIT_02),
         },
     ];
 
     pub const LADDER_NORTH_SHAPE_ARRAY: [FloatBox; 1] = [FloatBox {
         center_position: Vec3::new(0.0, 3.0 * CELL_UNIT_08, 0.0),
-        radius: Vec3::new(CELL_UNIT_01, CELL_UNIT_04, CELL_UNIT_01),
+        radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_08, CELL_UNIT_02),
     }];
 
     pub const LADDER_WEST_SHAPE_ARRAY: [FloatBox; 1] = [FloatBox {
         center_position: Vec3::new(-3.0 * CELL_UNIT_08, 0.0, 0.0),
-        radius: Vec3::new(CELL_UNIT_04, CELL_UNIT_01, CELL_UNIT_01),
+        radius: Vec3::new(CELL_UNIT_08, CELL_UNIT_02, CELL_UNIT_02),
     }];
 
     pub const LADDER_SOUTH_SHAPE_ARRAY: [FloatBox; 1] = [FloatBox {
         center_position: Vec3::new(0.0, -3.0 * CELL_UNIT_08, 0.0),
-        radius: Vec3::new(CELL_UNIT_01, CELL_UNIT_08, CELL_UNIT_01),
+        radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_08, CELL_UNIT_02),
     }];
 
     pub const LADDER_EAST_SHAPE_ARRAY: [FloatBox; 1] = [FloatBox {
         center_position: Vec3::new(3.0 * CELL_UNIT_08, 0.0, 0.0),
-        radius: Vec3::new(CELL_UNIT_04, CELL_UNIT_01, CELL_UNIT_01),
+        radius: Vec3::new(CELL_UNIT_08, CELL_UNIT_02, CELL_UNIT_02),
     }];
 
     pub const STAIRS_NORTH_SHAPE_ARRAY: [FloatBox; 2] = [
         FloatBox {
-            center_position: Vec3::new(0.0, -CELL_UNIT_04, -CELL_UNIT_04),
+            center_position: Vec3::new(0.0, CELL_UNIT_04, -CELL_UNIT_04),
             radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_04, CELL_UNIT_04),
         },
         FloatBox {
-            center_position: Vec3::new(0.0, CELL_UNIT_04, CELL_UNIT_04),
+            center_position: Vec3::new(0.0, -CELL_UNIT_04, CELL_UNIT_04),
             radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_04, CELL_UNIT_04),
         },
     ];
 
     pub const STAIRS_WEST_SHAPE_ARRAY: [FloatBox; 2] = [
         FloatBox {
-            center_position: Vec3::new(0.0, -CELL_UNIT_04, -CELL_UNIT_04),
-            radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_04, CELL_UNIT_04),
+            center_position: Vec3::new(-CELL_UNIT_04, 0.0, -CELL_UNIT_04),
+            radius: Vec3::new(CELL_UNIT_04, CELL_UNIT_02, CELL_UNIT_04),
         },
         FloatBox {
-            center_position: Vec3::new(0.0, CELL_UNIT_04, CELL_UNIT_04),
-            radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_04, CELL_UNIT_04),
+            center_position: Vec3::new(CELL_UNIT_04, 0.0, CELL_UNIT_04),
+            radius: Vec3::new(CELL_UNIT_04, CELL_UNIT_02, CELL_UNIT_04),
         },
     ];
 
@@ -111,12 +113,12 @@ impl BlockShape {
 
     pub const STAIRS_EAST_SHAPE_ARRAY: [FloatBox; 2] = [
         FloatBox {
-            center_position: Vec3::new(0.0, -CELL_UNIT_04, -CELL_UNIT_04),
-            radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_04, CELL_UNIT_04),
+            center_position: Vec3::new(CELL_UNIT_04, 0.0, -CELL_UNIT_04),
+            radius: Vec3::new(CELL_UNIT_04, CELL_UNIT_02, CELL_UNIT_04),
         },
         FloatBox {
-            center_position: Vec3::new(0.0, CELL_UNIT_04, CELL_UNIT_04),
-            radius: Vec3::new(CELL_UNIT_02, CELL_UNIT_04, CELL_UNIT_04),
+            center_position: Vec3::new(-CELL_UNIT_04, 0.0, CELL_UNIT_04),
+            radius: Vec3::new(CELL_UNIT_04, CELL_UNIT_02, CELL_UNIT_04),
         },
     ];
 }
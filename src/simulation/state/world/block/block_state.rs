@@ -4,8 +4,9 @@ pub mod door_data;
 use crate::simulation::state::world::block::block_state::{
     block_data::BlockData, door_data::DoorData,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum BlockState {
     Block(BlockData),
     Ladder,
@@ -1,6 +1,7 @@
 use crate::simulation::state::world::grid::direction_set::DirectionSet;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BlockData {
     pub exposure_set: DirectionSet,
 }
@@ -1,7 +1,11 @@
-#[derive(Clone, Hash, PartialEq, Eq)]
+use crate::simulation::state::population::nation::nation_kind::NationKind;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DoorData {
     pub is_open: bool,
     pub is_locked: bool,
+    pub key_nation_kind_option: Option<NationKind>,
 }
 
 impl DoorData {
@@ -9,6 +13,13 @@ impl DoorData {
         Self {
             is_open: false,
             is_locked: false,
+            key_nation_kind_option: None,
         }
     }
+
+    pub fn has_key(nation_kind: &NationKind, door_data: &Self) -> bool {
+        door_data
+            .key_nation_kind_option
+            .is_none_or(|key_nation_kind| key_nation_kind == *nation_kind)
+    }
 }
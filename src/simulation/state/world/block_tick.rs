@@ -0,0 +1,132 @@
+//! Scheduled and random block updates dispatched by BlockKind
+
+pub mod block_tick_queue;
+
+use crate::{
+    simulation::{
+        constants::*,
+        state::world::{
+            block::{block_kind::BlockKind, Block},
+            block_tick::block_tick_queue::BlockTickQueue,
+            cell::cell_index::CellIndex,
+            door::Door,
+            grid,
+            sector::Sector,
+            World,
+        },
+    },
+    utils::ldmath::rand_chacha_ext::gen_range_i32,
+};
+use tracing::instrument;
+use ultraviolet::IVec3;
+
+pub struct BlockTick {}
+
+impl BlockTick {
+    #[instrument(skip_all)]
+    pub fn tick(world: &mut World) {
+        Self::run_scheduled_ticks(world);
+        Self::run_random_ticks(world);
+    }
+
+    pub fn schedule(grid_position: IVec3, delay_in_ticks: u64, world: &mut World) {
+        let tick = world.time.tick + delay_in_ticks.max(1);
+
+        BlockTickQueue::push(tick, grid_position, &mut world.block_tick_queue);
+    }
+
+    fn run_scheduled_ticks(world: &mut World) {
+        let grid_position_vec =
+            BlockTickQueue::pop_due(world.time.tick, &mut world.block_tick_queue);
+
+        for grid_position in grid_position_vec {
+            let sector_index = grid::grid_position_to_sector_index(grid_position);
+
+            if let Err(error) = World::restore_sector(&sector_index, world) {
+                tracing::error!("Sector restore failed: {:?} {}", sector_index, error);
+            }
+
+            if let Some(block) = World::get_block(grid_position, &world.sector_map) {
+                Self::apply_scheduled_tick(block, world);
+            }
+        }
+    }
+
+    fn run_random_ticks(world: &mut World) {
+        let mut block_vec = Vec::new();
+
+        for sector in world.sector_map.values() {
+            if Sector::is_empty(sector) {
+                continue;
+            }
+
+            for _ in 0..BLOCK_RANDOM_TICK_COUNT_PER_SECTOR {
+                let cell_index = CellIndex::new(gen_range_i32(
+                    0,
+                    SECTOR_VOLUME_IN_CELLS as i32 - 1,
+                    &mut world.rng,
+                ) as usize);
+
+                if let Some(block) = Sector::get_block(cell_index, sector) {
+                    block_vec.push(block);
+                }
+            }
+        }
+
+        for block in block_vec {
+            Self::apply_random_tick(block, world);
+        }
+    }
+
+    fn apply_scheduled_tick(block: Block, world: &mut World) {
+        match block.block_kind {
+            BlockKind::DoorLower1 | BlockKind::DoorUpper1 => {
+                Door::set_open(block.grid_position, false, world);
+            }
+            _ => (),
+        }
+    }
+
+    fn apply_random_tick(block: Block, world: &mut World) {
+        match block.block_kind {
+            BlockKind::DoorLower1 | BlockKind::DoorUpper1 => {
+                Self::apply_door_random_tick(block.grid_position, world);
+            }
+            BlockKind::Carved1 | BlockKind::Carved2 | BlockKind::Carved3 => {
+                Self::apply_weathering_random_tick(block, world);
+            }
+            _ => (),
+        }
+    }
+
+    // Weathering only moves toward Carved4, so placed blocks settle and idle sectors stay unchanged
+    fn apply_weathering_random_tick(mut block: Block, world: &mut World) {
+        block.block_kind = match block.block_kind {
+            BlockKind::Carved1 => BlockKind::Carved2,
+            BlockKind::Carved2 => BlockKind::Carved3,
+            _ => BlockKind::Carved4,
+        };
+
+        World::store_block(block, world);
+    }
+
+    fn apply_door_random_tick(grid_position: IVec3, world: &mut World) {
+        let is_open = Door::get_door_data(grid_position, &world.sector_map)
+            .is_some_and(|door_data| door_data.is_open);
+
+        if !is_open {
+            return;
+        }
+
+        let is_scheduled = Door::get_grid_position_vec(grid_position, &world.sector_map)
+            .into_iter()
+            .any(|door_grid_position| {
+                BlockTickQueue::is_scheduled(door_grid_position, &world.block_tick_queue)
+            });
+
+        // Doors opened by pastes, schematics or undo have no close pending
+        if !is_scheduled {
+            Self::schedule(grid_position, BLOCK_DOOR_CLOSE_DELAY_IN_TICKS, world);
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use ultraviolet::IVec3;
+
+/// Pending block updates ordered by due tick, then by scheduling order
+#[derive(Serialize, Deserialize)]
+pub struct BlockTickQueue {
+    pub sequence: u64,
+    pub scheduled_tick_map: BTreeMap<(u64, u64), IVec3>,
+    pub grid_position_index: BTreeMap<[i32; 3], BTreeSet<(u64, u64)>>,
+}
+
+impl BlockTickQueue {
+    pub fn new() -> Self {
+        Self {
+            sequence: 0,
+            scheduled_tick_map: BTreeMap::new(),
+            grid_position_index: BTreeMap::new(),
+        }
+    }
+
+    pub fn push(tick: u64, grid_position: IVec3, block_tick_queue: &mut Self) {
+        let sequence = block_tick_queue.sequence;
+        block_tick_queue.sequence += 1;
+
+        block_tick_queue
+            .scheduled_tick_map
+            .insert((tick, sequence), grid_position);
+
+        block_tick_queue
+            .grid_position_index
+            .entry(grid_position.into())
+            .or_default()
+            .insert((tick, sequence));
+    }
+
+    pub fn pop_due(tick: u64, block_tick_queue: &mut Self) -> Vec<IVec3> {
+        let pending_tick_map = block_tick_queue
+            .scheduled_tick_map
+            .split_off(&(tick + 1, 0));
+
+        let due_tick_map =
+            std::mem::replace(&mut block_tick_queue.scheduled_tick_map, pending_tick_map);
+
+        for (scheduled_key, grid_position) in &due_tick_map {
+            Self::remove_from_index(*scheduled_key, *grid_position, block_tick_queue);
+        }
+
+        due_tick_map.into_values().collect()
+    }
+
+    pub fn cancel(grid_position: IVec3, block_tick_queue: &mut Self) {
+        let Some(scheduled_key_set) = block_tick_queue
+            .grid_position_index
+            .remove(&<[i32; 3]>::from(grid_position))
+        else {
+            return;
+        };
+
+        for scheduled_key in scheduled_key_set {
+            block_tick_queue.scheduled_tick_map.remove(&scheduled_key);
+        }
+    }
+
+    pub fn is_scheduled(grid_position: IVec3, block_tick_queue: &Self) -> bool {
+        block_tick_queue
+            .grid_position_index
+            .contains_key(&<[i32; 3]>::from(grid_position))
+    }
+
+    pub fn clear(block_tick_queue: &mut Self) {
+        block_tick_queue.sequence = 0;
+        block_tick_queue.scheduled_tick_map.clear();
+        block_tick_queue.grid_position_index.clear();
+    }
+
+    fn remove_from_index(
+        scheduled_key: (u64, u64),
+        grid_position: IVec3,
+        block_tick_queue: &mut Self,
+    ) {
+        let grid_position_key = <[i32; 3]>::from(grid_position);
+
+        if let Some(scheduled_key_set) = block_tick_queue
+            .grid_position_index
+            .get_mut(&grid_position_key)
+        {
+            scheduled_key_set.remove(&scheduled_key);
+
+            if scheduled_key_set.is_empty() {
+                block_tick_queue
+                    .grid_position_index
+                    .remove(&grid_position_key);
+            }
+        }
+    }
+}
+
+impl Default for BlockTickQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
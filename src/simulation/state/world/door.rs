@@ -0,0 +1,142 @@
+//! Door halves that open, close and lock as one
+
+use crate::simulation::{
+    constants::*,
+    state::{
+        population::nation::nation_kind::NationKind,
+        world::{
+            block::{
+                block_shape::BlockShape,
+                block_state::{door_data::DoorData, BlockState},
+            },
+            block_tick::{block_tick_queue::BlockTickQueue, BlockTick},
+            sector::{sector_index::SectorIndex, Sector},
+            World,
+        },
+    },
+};
+use std::collections::BTreeMap;
+use ultraviolet::IVec3;
+
+pub struct Door {}
+
+impl Door {
+    pub fn get_grid_position_vec(
+        grid_position: IVec3,
+        sector_map: &BTreeMap<SectorIndex, Sector>,
+    ) -> Vec<IVec3> {
+        let Some(block) = World::get_block(grid_position, sector_map) else {
+            return Vec::new();
+        };
+
+        let partner_grid_position = match block.block_shape {
+            BlockShape::DoorLower => grid_position + IVec3::unit_z(),
+            BlockShape::DoorUpper => grid_position - IVec3::unit_z(),
+            _ => return Vec::new(),
+        };
+
+        let partner_shape = match block.block_shape {
+            BlockShape::DoorLower => BlockShape::DoorUpper,
+            _ => BlockShape::DoorLower,
+        };
+
+        let is_paired = World::get_block(partner_grid_position, sector_map)
+            .is_some_and(|partner_block| partner_block.block_shape == partner_shape);
+
+        if is_paired {
+            vec![grid_position, partner_grid_position]
+        } else {
+            vec![grid_position]
+        }
+    }
+
+    pub fn get_door_data(
+        grid_position: IVec3,
+        sector_map: &BTreeMap<SectorIndex, Sector>,
+    ) -> Option<DoorData> {
+        let block = World::get_block(grid_position, sector_map)?;
+
+        match block.block_state {
+            BlockState::Door(door_data) => Some(door_data),
+            _ => None,
+        }
+    }
+
+    pub fn toggle_open(grid_position: IVec3, nation_kind: &NationKind, world: &mut World) -> bool {
+        let Some(door_data) = Self::get_door_data(grid_position, &world.sector_map) else {
+            return false;
+        };
+
+        if door_data.is_locked && !DoorData::has_key(nation_kind, &door_data) {
+            return false;
+        }
+
+        let is_open = !door_data.is_open;
+
+        Self::set_open(grid_position, is_open, world);
+
+        // A pending close from an earlier opening would shut a reopened door early
+        for door_grid_position in Self::get_grid_position_vec(grid_position, &world.sector_map) {
+            BlockTickQueue::cancel(door_grid_position, &mut world.block_tick_queue);
+        }
+
+        if is_open {
+            BlockTick::schedule(grid_position, BLOCK_DOOR_CLOSE_DELAY_IN_TICKS, world);
+        }
+
+        true
+    }
+
+    pub fn set_open(grid_position: IVec3, is_open: bool, world: &mut World) {
+        Self::update_door_data(grid_position, world, |door_data| {
+            door_data.is_open = is_open;
+        });
+    }
+
+    pub fn toggle_locked(
+        grid_position: IVec3,
+        nation_kind: &NationKind,
+        world: &mut World,
+    ) -> bool {
+        let Some(door_data) = Self::get_door_data(grid_position, &world.sector_map) else {
+            return false;
+        };
+
+        if door_data.is_open || !DoorData::has_key(nation_kind, &door_data) {
+            return false;
+        }
+
+        let is_locked = !door_data.is_locked;
+
+        Self::update_door_data(grid_position, world, |door_data| {
+            door_data.is_locked = is_locked;
+            door_data.key_nation_kind_option = Some(*nation_kind);
+        });
+
+        true
+    }
+
+    fn update_door_data(
+        grid_position: IVec3,
+        world: &mut World,
+        update_fn: impl Fn(&mut DoorData),
+    ) {
+        for door_grid_position in Self::get_grid_position_vec(grid_position, &world.sector_map) {
+            let Some(mut block) = World::get_block(door_grid_position, &world.sector_map) else {
+                continue;
+            };
+
+            let BlockState::Door(door_data) = &mut block.block_state else {
+                panic!("Door is missing its state");
+            };
+
+            let previous_door_data = door_data.clone();
+
+            update_fn(door_data);
+
+            if *door_data != previous_door_data {
+                World::store_block(block, world);
+            }
+        }
+    }
+}
@@ -25,23 +25,11 @@ pub fn cell_index_vec() -> Vec<CellIndex> {
         .collect()
 }
 
-#[inline]
-pub fn sector_index_vec() -> Vec<SectorIndex> {
-    (0usize..WORLD_VOLUME_IN_SECTORS)
-        .map(SectorIndex::new)
-        .collect()
-}
-
 #[inline]
 pub fn cell_index_is_valid(cell_index: CellIndex) -> bool {
     cell_index_vec().contains(&cell_index)
 }
 
-#[inline]
-pub fn sector_index_is_valid(sector_index: SectorIndex) -> bool {
-    sector_index_vec().contains(&sector_index)
-}
-
 #[inline]
 pub fn cell_coordinate_is_valid(cell_coordinate: IVec3) -> bool {
     let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
@@ -58,48 +46,22 @@ pub fn cell_coordinate_is_valid(cell_coordinate: IVec3) -> bool {
     in_x_range && in_y_range && in_z_range
 }
 
-#[inline]
-pub fn sector_coordinate_is_valid(sector_coordinate: IVec3) -> bool {
-    let world_radius_in_sectors = WORLD_RADIUS_IN_SECTORS as i32;
-
-    let in_x_range = sector_coordinate.x >= -world_radius_in_sectors
-        && sector_coordinate.x <= world_radius_in_sectors;
-
-    let in_y_range = sector_coordinate.y >= -world_radius_in_sectors
-        && sector_coordinate.y <= world_radius_in_sectors;
-
-    let in_z_range = sector_coordinate.z >= -world_radius_in_sectors
-        && sector_coordinate.z <= world_radius_in_sectors;
-
-    in_x_range && in_y_range && in_z_range
-}
-
 #[inline]
 pub fn grid_position_is_valid(grid_position: IVec3) -> bool {
-    let world_radius_in_cells = WORLD_RADIUS_IN_CELLS as i32;
-
-    let in_x_range =
-        grid_position.x >= -world_radius_in_cells && grid_position.x <= world_radius_in_cells;
-
-    let in_y_range =
-        grid_position.y >= -world_radius_in_cells && grid_position.y <= world_radius_in_cells;
-
-    let in_z_range =
-        grid_position.z >= -world_radius_in_cells && grid_position.z <= world_radius_in_cells;
+    let in_x_range = grid_position.x.abs() <= WORLD_LIMIT_IN_CELLS;
+    let in_y_range = grid_position.y.abs() <= WORLD_LIMIT_IN_CELLS;
+    let in_z_range = grid_position.z.abs() <= WORLD_LIMIT_IN_CELLS;
 
     in_x_range && in_y_range && in_z_range
 }
 
 #[inline]
 pub fn world_position_is_valid(world_position: Vec3) -> bool {
-    let in_x_range =
-        world_position.x >= -WORLD_RADIUS_IN_METERS && world_position.x <= WORLD_RADIUS_IN_METERS;
-
-    let in_y_range =
-        world_position.y >= -WORLD_RADIUS_IN_METERS && world_position.x <= WORLD_RADIUS_IN_METERS;
+    let world_limit_in_meters = WORLD_LIMIT_IN_CELLS as f32 * CELL_SIZE_IN_METERS;
 
-    let in_z_range =
-        world_position.z >= -WORLD_RADIUS_IN_METERS && world_position.x <= WORLD_RADIUS_IN_METERS;
+    let in_x_range = world_position.x.abs() <= world_limit_in_meters;
+    let in_y_range = world_position.y.abs() <= world_limit_in_meters;
+    let in_z_range = world_position.z.abs() <= world_limit_in_meters;
 
     in_x_range && in_y_range && in_z_range
 }
@@ -123,19 +85,14 @@ pub fn cell_coordinate_to_cell_index(coordinate: IVec3) -> CellIndex {
 
 #[inline]
 pub fn sector_index_to_sector_coordinate(sector_index: SectorIndex) -> IVec3 {
-    let sector_coordinate = ivec3_ext::index_to_ivec3(
-        SectorIndex::as_index(&sector_index),
-        WORLD_RADIUS_IN_SECTORS,
-    );
+    let sector_coordinate = SectorIndex::to_sector_coordinate(&sector_index);
 
     sector_coordinate
 }
 
 #[inline]
 pub fn sector_coordinate_to_sector_index(sector_coordinate: IVec3) -> SectorIndex {
-    let index = ivec3_ext::ivec3_to_index(sector_coordinate, WORLD_RADIUS_IN_SECTORS);
-
-    let sector_index = SectorIndex::new(index);
+    let sector_index = SectorIndex::new(sector_coordinate);
 
     sector_index
 }
@@ -165,33 +122,34 @@ pub fn grid_position_to_world_position(grid_position: IVec3) -> Vec3 {
 
 #[inline]
 pub fn grid_position_to_sector_coordinate(grid_position: IVec3) -> IVec3 {
-    let world_radius_in_cells = WORLD_RADIUS_IN_CELLS as i32;
+    let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
     let sector_size_in_cells = SECTOR_SIZE_IN_CELLS as i32;
-    let world_radius_in_sectors = WORLD_RADIUS_IN_SECTORS as i32;
 
-    let grid_position_indexable = grid_position + IVec3::broadcast(world_radius_in_cells);
-    let sector_coordinate_indexable = grid_position_indexable / sector_size_in_cells;
+    let grid_position_offset = grid_position + IVec3::broadcast(sector_radius_in_cells);
 
-    let sector_coordinate = sector_coordinate_indexable - IVec3::broadcast(world_radius_in_sectors);
+    let sector_coordinate = IVec3::new(
+        grid_position_offset.x.div_euclid(sector_size_in_cells),
+        grid_position_offset.y.div_euclid(sector_size_in_cells),
+        grid_position_offset.z.div_euclid(sector_size_in_cells),
+    );
 
     sector_coordinate
 }
 
 #[inline]
 pub fn grid_position_to_cell_coordinate(grid_position: IVec3) -> IVec3 {
-    let world_radius_in_cells = WORLD_RADIUS_IN_CELLS as i32;
     let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
     let sector_size_in_cells = SECTOR_SIZE_IN_CELLS as i32;
 
-    let grid_position_indexable = grid_position + IVec3::broadcast(world_radius_in_cells);
+    let grid_position_offset = grid_position + IVec3::broadcast(sector_radius_in_cells);
 
-    let cell_coordinate_indexable = IVec3::new(
-        grid_position_indexable.x % sector_size_in_cells,
-        grid_position_indexable.y % sector_size_in_cells,
-        grid_position_indexable.z % sector_size_in_cells,
+    let cell_coordinate_offset = IVec3::new(
+        grid_position_offset.x.rem_euclid(sector_size_in_cells),
+        grid_position_offset.y.rem_euclid(sector_size_in_cells),
+        grid_position_offset.z.rem_euclid(sector_size_in_cells),
     );
 
-    let cell_coordinate = cell_coordinate_indexable - IVec3::broadcast(sector_radius_in_cells);
+    let cell_coordinate = cell_coordinate_offset - IVec3::broadcast(sector_radius_in_cells);
 
     cell_coordinate
 }
@@ -396,12 +354,3 @@ pub fn on_sector_boundary(grid_position: IVec3) -> bool {
         false
     }
 }
-
-#[inline]
-pub fn on_world_boundary(grid_position: IVec3) -> bool {
-    let world_radius_in_cells = WORLD_RADIUS_IN_CELLS as i32;
-
-    grid_position.x.abs() == world_radius_in_cells
-        || grid_position.y.abs() == world_radius_in_cells
-        || grid_position.z.abs() == world_radius_in_cells
-}
@@ -1,9 +1,10 @@
+use serde::{Deserialize, Serialize};
 use ultraviolet::Vec3;
 
 use crate::simulation::state::world::grid::Direction;
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Axis {
     X,
     Y,
@@ -1,8 +1,10 @@
+use crate::utils::ldmath::ivec3_ext::rotate_by_direction;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use ultraviolet::{IVec3, Vec3};
 
 #[repr(u8)]
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Direction {
     North = 1 << 0,
     West = 1 << 1,
@@ -103,6 +105,16 @@ impl Direction {
         }
     }
 
+    pub fn rotate(direction: &Self, rotation: &Self) -> Self {
+        let rotated = rotate_by_direction(Self::to_ivec3(direction), *rotation);
+
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|candidate| Self::to_ivec3(candidate) == rotated)
+            .expect("Rotation should preserve axis directions")
+    }
+
     pub const fn as_str(&self) -> &'static str {
         match self {
             Direction::North => "north",
@@ -1,7 +1,8 @@
 use crate::simulation::state::world::grid::Direction;
+use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DirectionSet(u8);
 
 impl DirectionSet {
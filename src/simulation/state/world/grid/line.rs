@@ -1,7 +1,8 @@
 use crate::simulation::state::world::grid::Axis;
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Line {
     pub axis: Axis,
     pub grid_position1: IVec3,
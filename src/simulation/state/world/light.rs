@@ -0,0 +1,564 @@
+//! Sky and block light propagated by flood fill across resident sectors
+
+pub mod light_channel;
+pub mod light_workspace;
+
+use crate::simulation::{
+    constants::*,
+    state::world::{
+        grid::{self, Direction},
+        light::{light_channel::LightChannel, light_workspace::LightWorkspace},
+        sector::{light_field::LightField, sector_index::SectorIndex, Sector},
+        World,
+    },
+};
+use std::collections::{BTreeMap, VecDeque};
+use tracing::instrument;
+use ultraviolet::IVec3;
+
+pub struct Light {}
+
+impl Light {
+    pub fn get_level(
+        grid_position: IVec3,
+        light_channel: &LightChannel,
+        sector_map: &BTreeMap<SectorIndex, Sector>,
+    ) -> Option<u8> {
+        if !grid::grid_position_is_valid(grid_position) {
+            return None;
+        }
+
+        let (sector_index, cell_index) = grid::grid_position_to_indices(grid_position);
+
+        let sector = sector_map.get(&sector_index)?;
+
+        Some(LightField::get_level(
+            cell_index,
+            light_channel,
+            &sector.light_field,
+        ))
+    }
+
+    #[instrument(skip_all)]
+    pub fn update(world: &mut World) {
+        if world.light_change_vec.is_empty() && world.light_sector_set.is_empty() {
+            return;
+        }
+
+        let light_change_vec = std::mem::take(&mut world.light_change_vec);
+        let light_sector_set = std::mem::take(&mut world.light_sector_set);
+
+        let mut light_sector_index_vec: Vec<SectorIndex> = light_sector_set
+            .iter()
+            .filter(|sector_index| world.sector_map.contains_key(sector_index))
+            .copied()
+            .collect();
+
+        light_sector_index_vec
+            .sort_by_key(|sector_index| -SectorIndex::to_sector_coordinate(sector_index).z);
+
+        let mut light_workspace = LightWorkspace::new();
+
+        let light_slot_index_vec: Vec<usize> = light_sector_index_vec
+            .iter()
+            .filter_map(|sector_index| {
+                LightWorkspace::get_slot_index(sector_index, world, &mut light_workspace)
+            })
+            .collect();
+
+        let mut light_change_slot_vec = Vec::new();
+
+        for grid_position in light_change_vec {
+            let sector_index = grid::grid_position_to_sector_index(grid_position);
+
+            if light_sector_set.contains(&sector_index) {
+                continue;
+            }
+
+            if let Some(slot_index) =
+                LightWorkspace::get_slot_index(&sector_index, world, &mut light_workspace)
+            {
+                let cell_coordinate = grid::grid_position_to_cell_coordinate(grid_position);
+
+                light_change_slot_vec.push((slot_index, cell_coordinate));
+            }
+        }
+
+        for light_channel in LightChannel::ALL {
+            let mut removal_deque = VecDeque::new();
+            let mut addition_deque = VecDeque::new();
+
+            for slot_index in &light_slot_index_vec {
+                LightWorkspace::fill_level(*slot_index, &light_channel, 0, &mut light_workspace);
+            }
+
+            for (slot_index, cell_coordinate) in &light_change_slot_vec {
+                Self::seed_change(
+                    *slot_index,
+                    *cell_coordinate,
+                    &light_channel,
+                    world,
+                    &mut light_workspace,
+                    &mut removal_deque,
+                    &mut addition_deque,
+                );
+            }
+
+            Self::propagate_removal(
+                &light_channel,
+                world,
+                &mut light_workspace,
+                &mut removal_deque,
+                &mut addition_deque,
+            );
+
+            for slot_index in &light_slot_index_vec {
+                Self::seed_sector(
+                    *slot_index,
+                    &light_channel,
+                    world,
+                    &mut light_workspace,
+                    &mut addition_deque,
+                );
+            }
+
+            Self::propagate_addition(
+                &light_channel,
+                world,
+                &mut light_workspace,
+                &mut addition_deque,
+            );
+
+            if light_channel == LightChannel::Sky {
+                for slot_index in &light_slot_index_vec {
+                    Self::seed_sky_shadow(
+                        *slot_index,
+                        world,
+                        &mut light_workspace,
+                        &mut removal_deque,
+                    );
+                }
+
+                Self::propagate_removal(
+                    &light_channel,
+                    world,
+                    &mut light_workspace,
+                    &mut removal_deque,
+                    &mut addition_deque,
+                );
+
+                Self::propagate_addition(
+                    &light_channel,
+                    world,
+                    &mut light_workspace,
+                    &mut addition_deque,
+                );
+            }
+        }
+
+        LightWorkspace::write_back(world, light_workspace);
+    }
+
+    fn seed_change(
+        slot_index: usize,
+        cell_coordinate: IVec3,
+        light_channel: &LightChannel,
+        world: &World,
+        light_workspace: &mut LightWorkspace,
+        removal_deque: &mut VecDeque<(usize, IVec3, u8)>,
+        addition_deque: &mut VecDeque<(usize, IVec3)>,
+    ) {
+        let level =
+            LightWorkspace::get_level(slot_index, cell_coordinate, light_channel, light_workspace);
+
+        if level > 0 {
+            LightWorkspace::set_level(
+                slot_index,
+                cell_coordinate,
+                light_channel,
+                0,
+                light_workspace,
+            );
+
+            removal_deque.push_back((slot_index, cell_coordinate, level));
+        }
+
+        let emission = LightWorkspace::get_emission(
+            slot_index,
+            cell_coordinate,
+            light_channel,
+            light_workspace,
+        );
+
+        if emission > 0 {
+            LightWorkspace::set_level(
+                slot_index,
+                cell_coordinate,
+                light_channel,
+                emission,
+                light_workspace,
+            );
+
+            addition_deque.push_back((slot_index, cell_coordinate));
+        }
+
+        if !LightWorkspace::is_opaque(slot_index, cell_coordinate, light_workspace) {
+            for direction in Direction::ALL {
+                let Some((neighbor_slot_index, neighbor_cell_coordinate)) =
+                    LightWorkspace::get_neighbor(
+                        slot_index,
+                        cell_coordinate,
+                        direction,
+                        world,
+                        light_workspace,
+                    )
+                else {
+                    continue;
+                };
+
+                let neighbor_level = LightWorkspace::get_level(
+                    neighbor_slot_index,
+                    neighbor_cell_coordinate,
+                    light_channel,
+                    light_workspace,
+                );
+
+                if neighbor_level > 0 {
+                    addition_deque.push_back((neighbor_slot_index, neighbor_cell_coordinate));
+                }
+            }
+        }
+    }
+
+    fn seed_sector(
+        slot_index: usize,
+        light_channel: &LightChannel,
+        world: &World,
+        light_workspace: &mut LightWorkspace,
+        addition_deque: &mut VecDeque<(usize, IVec3)>,
+    ) {
+        let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
+
+        let is_sky_filled = *light_channel == LightChannel::Sky
+            && light_workspace.slot_vec[slot_index].is_empty
+            && Self::is_sky_column_open(slot_index, world, light_workspace);
+
+        if is_sky_filled {
+            LightWorkspace::fill_level(slot_index, light_channel, LIGHT_LEVEL_MAX, light_workspace);
+        }
+
+        for z in -sector_radius_in_cells..=sector_radius_in_cells {
+            for y in -sector_radius_in_cells..=sector_radius_in_cells {
+                for x in -sector_radius_in_cells..=sector_radius_in_cells {
+                    let cell_coordinate = IVec3::new(x, y, z);
+
+                    let is_boundary =
+                        cell_coordinate.abs().component_max() == sector_radius_in_cells;
+
+                    if is_sky_filled {
+                        if is_boundary {
+                            addition_deque.push_back((slot_index, cell_coordinate));
+                        }
+
+                        continue;
+                    }
+
+                    let emission = LightWorkspace::get_emission(
+                        slot_index,
+                        cell_coordinate,
+                        light_channel,
+                        light_workspace,
+                    );
+
+                    if emission > 0 {
+                        LightWorkspace::set_level(
+                            slot_index,
+                            cell_coordinate,
+                            light_channel,
+                            emission,
+                            light_workspace,
+                        );
+
+                        addition_deque.push_back((slot_index, cell_coordinate));
+                    }
+
+                    if !is_boundary {
+                        continue;
+                    }
+
+                    for direction in Direction::ALL {
+                        let Some((neighbor_slot_index, neighbor_cell_coordinate)) =
+                            LightWorkspace::get_neighbor(
+                                slot_index,
+                                cell_coordinate,
+                                direction,
+                                world,
+                                light_workspace,
+                            )
+                        else {
+                            continue;
+                        };
+
+                        if neighbor_slot_index == slot_index {
+                            continue;
+                        }
+
+                        let neighbor_level = LightWorkspace::get_level(
+                            neighbor_slot_index,
+                            neighbor_cell_coordinate,
+                            light_channel,
+                            light_workspace,
+                        );
+
+                        if neighbor_level > 0 {
+                            addition_deque
+                                .push_back((neighbor_slot_index, neighbor_cell_coordinate));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn is_sky_column_open(
+        slot_index: usize,
+        world: &World,
+        light_workspace: &mut LightWorkspace,
+    ) -> bool {
+        if light_workspace.slot_vec[slot_index].is_sky_open {
+            return true;
+        }
+
+        let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
+
+        for y in -sector_radius_in_cells..=sector_radius_in_cells {
+            for x in -sector_radius_in_cells..=sector_radius_in_cells {
+                let cell_coordinate = IVec3::new(x, y, sector_radius_in_cells);
+
+                let Some((above_slot_index, above_cell_coordinate)) = LightWorkspace::get_neighbor(
+                    slot_index,
+                    cell_coordinate,
+                    &Direction::Up,
+                    world,
+                    light_workspace,
+                ) else {
+                    return false;
+                };
+
+                let above_level = LightWorkspace::get_level(
+                    above_slot_index,
+                    above_cell_coordinate,
+                    &LightChannel::Sky,
+                    light_workspace,
+                );
+
+                if above_level < LIGHT_LEVEL_MAX {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    fn seed_sky_shadow(
+        slot_index: usize,
+        world: &World,
+        light_workspace: &mut LightWorkspace,
+        removal_deque: &mut VecDeque<(usize, IVec3, u8)>,
+    ) {
+        let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
+
+        for y in -sector_radius_in_cells..=sector_radius_in_cells {
+            for x in -sector_radius_in_cells..=sector_radius_in_cells {
+                let cell_coordinate = IVec3::new(x, y, -sector_radius_in_cells);
+
+                let Some((below_slot_index, below_cell_coordinate)) = LightWorkspace::get_neighbor(
+                    slot_index,
+                    cell_coordinate,
+                    &Direction::Down,
+                    world,
+                    light_workspace,
+                ) else {
+                    continue;
+                };
+
+                let level = LightWorkspace::get_level(
+                    slot_index,
+                    cell_coordinate,
+                    &LightChannel::Sky,
+                    light_workspace,
+                );
+
+                let below_level = LightWorkspace::get_level(
+                    below_slot_index,
+                    below_cell_coordinate,
+                    &LightChannel::Sky,
+                    light_workspace,
+                );
+
+                if below_level == LIGHT_LEVEL_MAX && level < LIGHT_LEVEL_MAX {
+                    LightWorkspace::set_level(
+                        below_slot_index,
+                        below_cell_coordinate,
+                        &LightChannel::Sky,
+                        0,
+                        light_workspace,
+                    );
+
+                    removal_deque.push_back((below_slot_index, below_cell_coordinate, below_level));
+                }
+            }
+        }
+    }
+
+    fn propagate_removal(
+        light_channel: &LightChannel,
+        world: &World,
+        light_workspace: &mut LightWorkspace,
+        removal_deque: &mut VecDeque<(usize, IVec3, u8)>,
+        addition_deque: &mut VecDeque<(usize, IVec3)>,
+    ) {
+        while let Some((slot_index, cell_coordinate, level)) = removal_deque.pop_front() {
+            for direction in Direction::ALL {
+                let Some((neighbor_slot_index, neighbor_cell_coordinate)) =
+                    LightWorkspace::get_neighbor(
+                        slot_index,
+                        cell_coordinate,
+                        direction,
+                        world,
+                        light_workspace,
+                    )
+                else {
+                    continue;
+                };
+
+                let neighbor_level = LightWorkspace::get_level(
+                    neighbor_slot_index,
+                    neighbor_cell_coordinate,
+                    light_channel,
+                    light_workspace,
+                );
+
+                if neighbor_level == 0 {
+                    continue;
+                }
+
+                let is_dependent =
+                    Self::is_sky_fall(light_channel, direction, level) || neighbor_level < level;
+
+                if !is_dependent {
+                    addition_deque.push_back((neighbor_slot_index, neighbor_cell_coordinate));
+
+                    continue;
+                }
+
+                LightWorkspace::set_level(
+                    neighbor_slot_index,
+                    neighbor_cell_coordinate,
+                    light_channel,
+                    0,
+                    light_workspace,
+                );
+
+                removal_deque.push_back((
+                    neighbor_slot_index,
+                    neighbor_cell_coordinate,
+                    neighbor_level,
+                ));
+
+                let emission = LightWorkspace::get_emission(
+                    neighbor_slot_index,
+                    neighbor_cell_coordinate,
+                    light_channel,
+                    light_workspace,
+                );
+
+                if emission > 0 {
+                    LightWorkspace::set_level(
+                        neighbor_slot_index,
+                        neighbor_cell_coordinate,
+                        light_channel,
+                        emission,
+                        light_workspace,
+                    );
+
+                    addition_deque.push_back((neighbor_slot_index, neighbor_cell_coordinate));
+                }
+            }
+        }
+    }
+
+    fn propagate_addition(
+        light_channel: &LightChannel,
+        world: &World,
+        light_workspace: &mut LightWorkspace,
+        addition_deque: &mut VecDeque<(usize, IVec3)>,
+    ) {
+        while let Some((slot_index, cell_coordinate)) = addition_deque.pop_front() {
+            let level = LightWorkspace::get_level(
+                slot_index,
+                cell_coordinate,
+                light_channel,
+                light_workspace,
+            );
+
+            if level <= 1 {
+                continue;
+            }
+
+            for direction in Direction::ALL {
+                let Some((neighbor_slot_index, neighbor_cell_coordinate)) =
+                    LightWorkspace::get_neighbor(
+                        slot_index,
+                        cell_coordinate,
+                        direction,
+                        world,
+                        light_workspace,
+                    )
+                else {
+                    continue;
+                };
+
+                if LightWorkspace::is_opaque(
+                    neighbor_slot_index,
+                    neighbor_cell_coordinate,
+                    light_workspace,
+                ) {
+                    continue;
+                }
+
+                let spread_level = if Self::is_sky_fall(light_channel, direction, level) {
+                    LIGHT_LEVEL_MAX
+                } else {
+                    level - 1
+                };
+
+                let neighbor_level = LightWorkspace::get_level(
+                    neighbor_slot_index,
+                    neighbor_cell_coordinate,
+                    light_channel,
+                    light_workspace,
+                );
+
+                if neighbor_level < spread_level {
+                    LightWorkspace::set_level(
+                        neighbor_slot_index,
+                        neighbor_cell_coordinate,
+                        light_channel,
+                        spread_level,
+                        light_workspace,
+                    );
+
+                    addition_deque.push_back((neighbor_slot_index, neighbor_cell_coordinate));
+                }
+            }
+        }
+    }
+
+    fn is_sky_fall(light_channel: &LightChannel, direction: &Direction, level: u8) -> bool {
+        *light_channel == LightChannel::Sky
+            && *direction == Direction::Down
+            && level == LIGHT_LEVEL_MAX
+    }
+}
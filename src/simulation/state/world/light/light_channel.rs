@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LightChannel {
+    Sky,
+    Block,
+}
+
+impl LightChannel {
+    pub const ALL: [Self; 2] = [Self::Sky, Self::Block];
+
+    pub fn get_shift(light_channel: &Self) -> u8 {
+        match light_channel {
+            Self::Sky => 4,
+            Self::Block => 0,
+        }
+    }
+}
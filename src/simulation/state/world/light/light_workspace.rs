@@ -0,0 +1,286 @@
+use crate::{
+    simulation::{
+        constants::*,
+        state::world::{
+            block::block_kind::BlockKind,
+            grid::{self, Direction},
+            light::light_channel::LightChannel,
+            sector::{
+                block_storage::BlockStorage, light_field::LightField, sector_index::SectorIndex,
+            },
+            World,
+        },
+    },
+    utils::ldmath::ivec3_ext,
+};
+use std::collections::BTreeMap;
+use ultraviolet::IVec3;
+
+/// Unpacked copy of one resident sector for the duration of a light update
+pub struct LightSlot {
+    pub sector_index: SectorIndex,
+    pub opaque_vec: Vec<bool>,
+    pub emission_vec: Vec<u8>,
+    pub light_vec: Vec<u8>,
+    pub is_empty: bool,
+    pub is_sky_open: bool,
+    pub is_changed: bool,
+    pub is_boundary_changed: bool,
+    pub neighbor_slot_array: [Option<Option<usize>>; 6],
+}
+
+/// Sectors unpacked on first touch so flood fill steps are plain array reads
+pub struct LightWorkspace {
+    pub slot_index_map: BTreeMap<SectorIndex, usize>,
+    pub slot_vec: Vec<LightSlot>,
+}
+
+impl LightWorkspace {
+    pub fn new() -> Self {
+        Self {
+            slot_index_map: BTreeMap::new(),
+            slot_vec: Vec::new(),
+        }
+    }
+
+    pub fn get_slot_index(
+        sector_index: &SectorIndex,
+        world: &World,
+        light_workspace: &mut Self,
+    ) -> Option<usize> {
+        if let Some(slot_index) = light_workspace.slot_index_map.get(sector_index) {
+            return Some(*slot_index);
+        }
+
+        let sector = world.sector_map.get(sector_index)?;
+
+        let is_empty = BlockStorage::is_empty(&sector.block_storage);
+
+        let mut opaque_vec = vec![false; SECTOR_VOLUME_IN_CELLS];
+        let mut emission_vec = vec![0; SECTOR_VOLUME_IN_CELLS];
+
+        if !is_empty {
+            for cell_index in grid::cell_index_vec() {
+                if let Some(palette_entry) =
+                    BlockStorage::get_entry(cell_index, &sector.block_storage)
+                {
+                    let index = usize::from(cell_index);
+
+                    opaque_vec[index] = BlockKind::is_opaque(&palette_entry.block_kind);
+                    emission_vec[index] = BlockKind::get_light_emission(&palette_entry.block_kind);
+                }
+            }
+        }
+
+        let light_vec = if sector.light_field.light_vec.is_empty() {
+            vec![sector.light_field.uniform_light; SECTOR_VOLUME_IN_CELLS]
+        } else {
+            sector.light_field.light_vec.clone()
+        };
+
+        let sector_coordinate = SectorIndex::to_sector_coordinate(sector_index);
+        let above_sector_index = SectorIndex::new(sector_coordinate + IVec3::unit_z());
+
+        let top_grid_position_z = sector.grid_position.z + SECTOR_RADIUS_IN_CELLS as i32;
+
+        let is_sky_open = !world.sector_map.contains_key(&above_sector_index)
+            && top_grid_position_z + 1 >= LIGHT_SKY_HEIGHT_IN_CELLS;
+
+        let slot_index = light_workspace.slot_vec.len();
+
+        light_workspace.slot_vec.push(LightSlot {
+            sector_index: *sector_index,
+            opaque_vec,
+            emission_vec,
+            light_vec,
+            is_empty,
+            is_sky_open,
+            is_changed: false,
+            is_boundary_changed: false,
+            neighbor_slot_array: [None; 6],
+        });
+
+        light_workspace
+            .slot_index_map
+            .insert(*sector_index, slot_index);
+
+        Some(slot_index)
+    }
+
+    pub fn get_neighbor(
+        slot_index: usize,
+        cell_coordinate: IVec3,
+        direction: &Direction,
+        world: &World,
+        light_workspace: &mut Self,
+    ) -> Option<(usize, IVec3)> {
+        let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
+
+        let offset = Direction::to_ivec3(direction);
+        let neighbor_cell_coordinate = cell_coordinate + offset;
+
+        if neighbor_cell_coordinate.abs().component_max() <= sector_radius_in_cells {
+            return Some((slot_index, neighbor_cell_coordinate));
+        }
+
+        let direction_index = Direction::to_index(direction);
+
+        let neighbor_slot_index_option =
+            match light_workspace.slot_vec[slot_index].neighbor_slot_array[direction_index] {
+                Some(neighbor_slot_index_option) => neighbor_slot_index_option,
+                None => {
+                    let sector_index = light_workspace.slot_vec[slot_index].sector_index;
+                    let neighbor_sector_index =
+                        SectorIndex::new(SectorIndex::to_sector_coordinate(&sector_index) + offset);
+
+                    let neighbor_slot_index_option =
+                        Self::get_slot_index(&neighbor_sector_index, world, light_workspace);
+
+                    light_workspace.slot_vec[slot_index].neighbor_slot_array[direction_index] =
+                        Some(neighbor_slot_index_option);
+
+                    neighbor_slot_index_option
+                }
+            };
+
+        let neighbor_slot_index = neighbor_slot_index_option?;
+
+        Some((
+            neighbor_slot_index,
+            neighbor_cell_coordinate - offset * SECTOR_SIZE_IN_CELLS as i32,
+        ))
+    }
+
+    pub fn get_level(
+        slot_index: usize,
+        cell_coordinate: IVec3,
+        light_channel: &LightChannel,
+        light_workspace: &Self,
+    ) -> u8 {
+        let index = Self::get_index(cell_coordinate);
+        let light = light_workspace.slot_vec[slot_index].light_vec[index];
+
+        (light >> LightChannel::get_shift(light_channel)) & 0x0F
+    }
+
+    pub fn set_level(
+        slot_index: usize,
+        cell_coordinate: IVec3,
+        light_channel: &LightChannel,
+        level: u8,
+        light_workspace: &mut Self,
+    ) {
+        let index = Self::get_index(cell_coordinate);
+        let shift = LightChannel::get_shift(light_channel);
+
+        let light_slot = &mut light_workspace.slot_vec[slot_index];
+        let light = &mut light_slot.light_vec[index];
+
+        let updated_light = (*light & !(0x0F << shift)) | ((level & 0x0F) << shift);
+
+        if *light == updated_light {
+            return;
+        }
+
+        *light = updated_light;
+
+        light_slot.is_changed = true;
+
+        if cell_coordinate.abs().component_max() == SECTOR_RADIUS_IN_CELLS as i32 {
+            light_slot.is_boundary_changed = true;
+        }
+    }
+
+    pub fn fill_level(
+        slot_index: usize,
+        light_channel: &LightChannel,
+        level: u8,
+        light_workspace: &mut Self,
+    ) {
+        let shift = LightChannel::get_shift(light_channel);
+
+        let light_slot = &mut light_workspace.slot_vec[slot_index];
+
+        for light in &mut light_slot.light_vec {
+            *light = (*light & !(0x0F << shift)) | ((level & 0x0F) << shift);
+        }
+
+        light_slot.is_changed = true;
+        light_slot.is_boundary_changed = true;
+    }
+
+    pub fn is_opaque(slot_index: usize, cell_coordinate: IVec3, light_workspace: &Self) -> bool {
+        light_workspace.slot_vec[slot_index].opaque_vec[Self::get_index(cell_coordinate)]
+    }
+
+    pub fn get_emission(
+        slot_index: usize,
+        cell_coordinate: IVec3,
+        light_channel: &LightChannel,
+        light_workspace: &Self,
+    ) -> u8 {
+        let light_slot = &light_workspace.slot_vec[slot_index];
+        let index = Self::get_index(cell_coordinate);
+
+        match light_channel {
+            LightChannel::Sky => {
+                if light_slot.is_sky_open
+                    && cell_coordinate.z == SECTOR_RADIUS_IN_CELLS as i32
+                    && !light_slot.opaque_vec[index]
+                {
+                    LIGHT_LEVEL_MAX
+                } else {
+                    0
+                }
+            }
+            LightChannel::Block => light_slot.emission_vec[index],
+        }
+    }
+
+    pub fn write_back(world: &mut World, light_workspace: Self) {
+        let mut boundary_changed_sector_index_vec = Vec::new();
+
+        for light_slot in light_workspace.slot_vec {
+            if !light_slot.is_changed {
+                continue;
+            }
+
+            let Some(sector) = world.sector_map.get_mut(&light_slot.sector_index) else {
+                continue;
+            };
+
+            sector.light_field.light_vec = light_slot.light_vec;
+
+            LightField::compact(&mut sector.light_field);
+
+            sector.version += 1;
+
+            if light_slot.is_boundary_changed {
+                boundary_changed_sector_index_vec.push(light_slot.sector_index);
+            }
+        }
+
+        for sector_index in boundary_changed_sector_index_vec {
+            let sector_coordinate = SectorIndex::to_sector_coordinate(&sector_index);
+
+            for direction in Direction::ALL {
+                let neighbor_sector_index =
+                    SectorIndex::new(sector_coordinate + Direction::to_ivec3(direction));
+
+                if let Some(neighbor_sector) = world.sector_map.get_mut(&neighbor_sector_index) {
+                    neighbor_sector.version += 1;
+                }
+            }
+        }
+    }
+
+    fn get_index(cell_coordinate: IVec3) -> usize {
+        ivec3_ext::ivec3_to_index(cell_coordinate, SECTOR_RADIUS_IN_CELLS)
+    }
+}
+
+impl Default for LightWorkspace {
+    fn default() -> Self {
+        Self::new()
+    }
+}
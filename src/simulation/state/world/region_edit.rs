@@ -0,0 +1,239 @@
+pub mod block_change;
+pub mod edit_journal;
+pub mod region;
+
+use crate::{
+    simulation::state::world::{
+        block::{block_kind::BlockKind, block_state::BlockState, Block},
+        grid::{self, Direction},
+        region_edit::{block_change::BlockChange, edit_journal::EditJournal, region::Region},
+        sector::palette_entry::PaletteEntry,
+        World,
+    },
+    utils::ldmath::{ivec3_ext::rotate_by_direction, IntBox},
+};
+use ultraviolet::IVec3;
+
+pub struct RegionEdit {}
+
+impl RegionEdit {
+    pub fn copy(int_box: &IntBox, world: &mut World) -> Region {
+        let mut region = Region::new(IntBox::get_size(int_box.clone()));
+
+        for offset in Region::get_offset_iter(&region).collect::<Vec<_>>() {
+            let grid_position = int_box.min + offset;
+            let index = Region::get_index(offset, &region);
+
+            region.entry_vec[index] =
+                Self::read_block(grid_position, world).map(PaletteEntry::from_block);
+        }
+
+        region
+    }
+
+    pub fn paste(
+        grid_position: IVec3,
+        direction: &Direction,
+        region: &Region,
+        world: &mut World,
+    ) -> Vec<BlockChange> {
+        let mut block_change_vec = Vec::new();
+
+        for offset in Region::get_offset_iter(region) {
+            let target_grid_position = grid_position + rotate_by_direction(offset, *direction);
+
+            let after_option = region.entry_vec[Region::get_index(offset, region)]
+                .as_ref()
+                .map(|palette_entry| PaletteEntry {
+                    block_kind: palette_entry.block_kind.clone(),
+                    direction: Direction::rotate(&palette_entry.direction, direction),
+                    block_state: palette_entry.block_state.clone(),
+                });
+
+            if let Some(block_change) = Self::apply(target_grid_position, after_option, world) {
+                block_change_vec.push(block_change);
+            }
+        }
+
+        block_change_vec
+    }
+
+    pub fn fill(
+        int_box: &IntBox,
+        direction: &Direction,
+        block_kind_option: &Option<BlockKind>,
+        world: &mut World,
+    ) -> Vec<BlockChange> {
+        let mut block_change_vec = Vec::new();
+
+        for z in int_box.min.z..=int_box.max.z {
+            for y in int_box.min.y..=int_box.max.y {
+                for x in int_box.min.x..=int_box.max.x {
+                    let grid_position = IVec3::new(x, y, z);
+
+                    let after_option = block_kind_option.as_ref().map(|block_kind| {
+                        PaletteEntry::from_block(Block::new(block_kind, grid_position, direction))
+                    });
+
+                    if let Some(block_change) = Self::apply(grid_position, after_option, world) {
+                        block_change_vec.push(block_change);
+                    }
+                }
+            }
+        }
+
+        block_change_vec
+    }
+
+    pub fn replace(
+        int_box: &IntBox,
+        mask_block_kind: &BlockKind,
+        block_kind_option: &Option<BlockKind>,
+        world: &mut World,
+    ) -> Vec<BlockChange> {
+        let mut block_change_vec = Vec::new();
+
+        for z in int_box.min.z..=int_box.max.z {
+            for y in int_box.min.y..=int_box.max.y {
+                for x in int_box.min.x..=int_box.max.x {
+                    let grid_position = IVec3::new(x, y, z);
+
+                    let Some(block) = Self::read_block(grid_position, world) else {
+                        continue;
+                    };
+
+                    if block.block_kind != *mask_block_kind {
+                        continue;
+                    }
+
+                    let after_option = block_kind_option.as_ref().map(|block_kind| {
+                        PaletteEntry::from_block(Block::new(
+                            block_kind,
+                            grid_position,
+                            &block.direction,
+                        ))
+                    });
+
+                    if let Some(block_change) = Self::apply(grid_position, after_option, world) {
+                        block_change_vec.push(block_change);
+                    }
+                }
+            }
+        }
+
+        block_change_vec
+    }
+
+    pub fn undo(edit_journal: &mut EditJournal, world: &mut World) -> bool {
+        let Some(block_change_vec) = edit_journal.undo_vec.pop() else {
+            return false;
+        };
+
+        for block_change in block_change_vec.iter().rev() {
+            Self::write_block(
+                block_change.grid_position,
+                &block_change.before_option,
+                world,
+            );
+        }
+
+        edit_journal.redo_vec.push(block_change_vec);
+
+        true
+    }
+
+    pub fn redo(edit_journal: &mut EditJournal, world: &mut World) -> bool {
+        let Some(block_change_vec) = edit_journal.redo_vec.pop() else {
+            return false;
+        };
+
+        for block_change in &block_change_vec {
+            Self::write_block(
+                block_change.grid_position,
+                &block_change.after_option,
+                world,
+            );
+        }
+
+        edit_journal.undo_vec.push(block_change_vec);
+
+        true
+    }
+
+    fn apply(
+        grid_position: IVec3,
+        after_option: Option<PaletteEntry>,
+        world: &mut World,
+    ) -> Option<BlockChange> {
+        if !grid::grid_position_is_valid(grid_position) {
+            return None;
+        }
+
+        let before_option = Self::read_block(grid_position, world).map(PaletteEntry::from_block);
+
+        let unchanged = match (&before_option, &after_option) {
+            (Some(before), Some(after)) => {
+                // Exposure is derived from neighbors, every other state is part of the edit
+                let state_unchanged = match (&before.block_state, &after.block_state) {
+                    (BlockState::Block(_), BlockState::Block(_)) => true,
+                    _ => before.block_state == after.block_state,
+                };
+
+                before.block_kind == after.block_kind
+                    && before.direction == after.direction
+                    && state_unchanged
+            }
+            (None, None) => true,
+            _ => false,
+        };
+
+        if unchanged {
+            return None;
+        }
+
+        Self::write_block(grid_position, &after_option, world);
+
+        let block_change = BlockChange {
+            grid_position,
+            before_option,
+            after_option,
+        };
+
+        Some(block_change)
+    }
+
+    fn read_block(grid_position: IVec3, world: &mut World) -> Option<Block> {
+        let sector_index = grid::grid_position_to_sector_index(grid_position);
+
+        if let Err(error) = World::restore_sector(&sector_index, world) {
+            tracing::error!("Sector restore failed: {:?} {}", sector_index, error);
+        }
+
+        World::get_block(grid_position, &world.sector_map)
+    }
+
+    fn write_block(
+        grid_position: IVec3,
+        palette_entry_option: &Option<PaletteEntry>,
+        world: &mut World,
+    ) {
+        match palette_entry_option {
+            Some(palette_entry) => {
+                World::set_block(
+                    grid_position,
+                    &palette_entry.direction,
+                    &palette_entry.block_kind,
+                    world,
+                );
+
+                // Exposure is recomputed on placement, other states are restored as copied
+                if !matches!(palette_entry.block_state, BlockState::Block(_)) {
+                    let block = PaletteEntry::to_block(grid_position, palette_entry);
+
+                    World::store_block(block, world);
+                }
+            }
+            None => World::remove_block(grid_position, world),
+        }
+    }
+}
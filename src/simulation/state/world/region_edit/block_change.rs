@@ -0,0 +1,10 @@
+use crate::simulation::state::world::sector::palette_entry::PaletteEntry;
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BlockChange {
+    pub grid_position: IVec3,
+    pub before_option: Option<PaletteEntry>,
+    pub after_option: Option<PaletteEntry>,
+}
@@ -0,0 +1,31 @@
+use crate::simulation::{
+    constants::EDIT_JOURNAL_LIMIT,
+    state::world::region_edit::{block_change::BlockChange, region::Region},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct EditJournal {
+    pub clipboard_option: Option<Region>,
+    pub undo_vec: Vec<Vec<BlockChange>>,
+    pub redo_vec: Vec<Vec<BlockChange>>,
+}
+
+impl EditJournal {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(block_change_vec: Vec<BlockChange>, edit_journal: &mut Self) {
+        if block_change_vec.is_empty() {
+            return;
+        }
+
+        edit_journal.undo_vec.push(block_change_vec);
+        edit_journal.redo_vec.clear();
+
+        if edit_journal.undo_vec.len() > EDIT_JOURNAL_LIMIT {
+            edit_journal.undo_vec.remove(0);
+        }
+    }
+}
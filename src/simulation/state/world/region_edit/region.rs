@@ -0,0 +1,32 @@
+use crate::simulation::state::world::sector::palette_entry::PaletteEntry;
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Region {
+    pub size: IVec3,
+    pub entry_vec: Vec<Option<PaletteEntry>>,
+}
+
+impl Region {
+    pub fn new(size: IVec3) -> Self {
+        let volume = (size.x * size.y * size.z) as usize;
+
+        Self {
+            size,
+            entry_vec: vec![None; volume],
+        }
+    }
+
+    pub fn get_index(offset: IVec3, region: &Self) -> usize {
+        (offset.x + offset.y * region.size.x + offset.z * region.size.x * region.size.y) as usize
+    }
+
+    pub fn get_offset_iter(region: &Self) -> impl Iterator<Item = IVec3> {
+        let size = region.size;
+
+        (0..size.z).flat_map(move |z| {
+            (0..size.y).flat_map(move |y| (0..size.x).map(move |x| IVec3::new(x, y, z)))
+        })
+    }
+}
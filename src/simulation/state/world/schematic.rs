@@ -0,0 +1,164 @@
+//! Structures stored as editable files and placed like area templates
+
+pub mod schematic_block;
+
+use crate::{
+    simulation::{
+        constants::SCHEMATIC_EXTENSION,
+        state::world::{
+            area::{area_kind::AreaKind, Area},
+            block::block_state::BlockState,
+            grid::{self, Direction},
+            schematic::schematic_block::SchematicBlock,
+            World,
+        },
+    },
+    utils::ldmath::IntBox,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Schematic {
+    pub name: String,
+    pub size: IVec3,
+    #[serde(default)]
+    pub area_kind_option: Option<AreaKind>,
+    pub schematic_block_vec: Vec<SchematicBlock>,
+}
+
+impl Schematic {
+    pub fn from_region(name: &str, int_box: &IntBox, world: &mut World) -> Self {
+        let mut schematic_block_vec = Vec::new();
+
+        for z in int_box.min.z..=int_box.max.z {
+            for y in int_box.min.y..=int_box.max.y {
+                for x in int_box.min.x..=int_box.max.x {
+                    let grid_position = IVec3::new(x, y, z);
+                    let sector_index = grid::grid_position_to_sector_index(grid_position);
+
+                    if let Err(error) = World::restore_sector(&sector_index, world) {
+                        tracing::error!("Sector restore failed: {:?} {}", sector_index, error);
+                    }
+
+                    let Some(block) = World::get_block(grid_position, &world.sector_map) else {
+                        continue;
+                    };
+
+                    let door_data_option = match block.block_state {
+                        BlockState::Door(door_data) => Some(door_data),
+                        _ => None,
+                    };
+
+                    schematic_block_vec.push(SchematicBlock {
+                        offset: grid_position - int_box.min,
+                        block_kind: block.block_kind,
+                        direction: block.direction,
+                        door_data_option,
+                    });
+                }
+            }
+        }
+
+        Self {
+            name: String::from(name),
+            size: IntBox::get_size(int_box.clone()),
+            area_kind_option: None,
+            schematic_block_vec,
+        }
+    }
+
+    pub fn construct(schematic: &Self, area: &Area, world: &mut World) {
+        for schematic_block in &schematic.schematic_block_vec {
+            let local_int_box = Area::set_local(schematic_block.offset, IVec3::one(), area);
+            let direction = Direction::rotate(&schematic_block.direction, &area.direction);
+
+            World::set_block(
+                local_int_box.min,
+                &direction,
+                &schematic_block.block_kind,
+                world,
+            );
+
+            if let Some(door_data) = &schematic_block.door_data_option {
+                if let Some(mut block) = World::get_block(local_int_box.min, &world.sector_map) {
+                    block.block_state = BlockState::Door(door_data.clone());
+
+                    World::store_block(block, world);
+                }
+            }
+        }
+    }
+
+    pub fn name_is_valid(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|character| character.is_ascii_alphanumeric() || "_-".contains(character))
+    }
+
+    pub fn fits(area: &Area, schematic: &Self) -> bool {
+        schematic.size.x <= area.size.x
+            && schematic.size.y <= area.size.y
+            && schematic.size.z <= area.size.z
+    }
+
+    pub fn write(path: &Path, schematic: &Self) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        serde_json::to_writer_pretty(&mut writer, schematic)?;
+
+        writer.flush()
+    }
+
+    pub fn read(path: &Path) -> io::Result<Self> {
+        let reader = BufReader::new(File::open(path)?);
+
+        let schematic = serde_json::from_reader(reader)?;
+
+        Ok(schematic)
+    }
+
+    pub fn load_directory(directory: &Path) -> BTreeMap<String, Self> {
+        let mut schematic_map = BTreeMap::new();
+
+        let Ok(read_dir) = fs::read_dir(directory) else {
+            return schematic_map;
+        };
+
+        let mut path_vec: Vec<_> = read_dir
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == SCHEMATIC_EXTENSION)
+            })
+            .collect();
+
+        path_vec.sort();
+
+        for path in path_vec {
+            match Self::read(&path) {
+                Ok(schematic) => {
+                    tracing::info!("Loaded schematic {}", schematic.name);
+
+                    schematic_map.insert(schematic.name.clone(), schematic);
+                }
+                Err(error) => {
+                    tracing::error!("Failed to load schematic {}: {}", path.display(), error)
+                }
+            }
+        }
+
+        schematic_map
+    }
+}
@@ -0,0 +1,15 @@
+use crate::simulation::state::world::{
+    block::{block_kind::BlockKind, block_state::door_data::DoorData},
+    grid::Direction,
+};
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SchematicBlock {
+    pub offset: IVec3,
+    pub block_kind: BlockKind,
+    pub direction: Direction,
+    #[serde(default)]
+    pub door_data_option: Option<DoorData>,
+}
@@ -1,28 +1,58 @@
+pub mod block_storage;
+pub mod light_field;
+pub mod palette_entry;
 pub mod sector_index;
 
 use crate::simulation::state::world::{
-    block::Block, cell::cell_index::CellIndex, sector::sector_index::SectorIndex,
+    block::Block,
+    cell::cell_index::CellIndex,
+    grid,
+    sector::{
+        block_storage::BlockStorage, light_field::LightField, palette_entry::PaletteEntry,
+        sector_index::SectorIndex,
+    },
 };
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Sector {
     pub version: u64,
     pub sector_index: SectorIndex,
     pub grid_position: IVec3,
-    pub block_vec: Vec<Option<Block>>,
+    pub block_storage: BlockStorage,
+    pub light_field: LightField,
 }
 
 impl Sector {
-    pub fn get_block(cell_index: CellIndex, block_slice: &[Option<Block>]) -> Option<&Block> {
-        block_slice.get(CellIndex::as_index(&cell_index))?.as_ref()
+    pub fn new(sector_index: SectorIndex) -> Self {
+        let version = 0;
+        let grid_position = grid::sector_index_to_grid_position(sector_index);
+        let block_storage = BlockStorage::new();
+        let light_field = LightField::new();
+
+        Self {
+            version,
+            sector_index,
+            grid_position,
+            block_storage,
+            light_field,
+        }
+    }
+
+    pub fn get_block(cell_index: CellIndex, sector: &Self) -> Option<Block> {
+        BlockStorage::get_block(sector.sector_index, cell_index, &sector.block_storage)
+    }
+
+    pub fn set_block(cell_index: CellIndex, block_option: Option<Block>, sector: &mut Self) {
+        let palette_entry_option = block_option.map(PaletteEntry::from_block);
+
+        BlockStorage::set_entry(cell_index, palette_entry_option, &mut sector.block_storage);
+
+        sector.version += 1;
     }
 
-    pub fn get_block_mut(
-        cell_index: CellIndex,
-        block_slice: &mut [Option<Block>],
-    ) -> Option<&mut Block> {
-        block_slice
-            .get_mut(CellIndex::as_index(&cell_index))?
-            .as_mut()
+    pub fn is_empty(sector: &Self) -> bool {
+        BlockStorage::is_empty(&sector.block_storage)
     }
 }
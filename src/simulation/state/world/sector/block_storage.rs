@@ -0,0 +1,211 @@
+use crate::simulation::{
+    constants::SECTOR_VOLUME_IN_CELLS,
+    state::world::{
+        block::Block,
+        cell::cell_index::CellIndex,
+        grid,
+        sector::{palette_entry::PaletteEntry, sector_index::SectorIndex},
+    },
+};
+use serde::{Deserialize, Serialize};
+
+/// Palette of distinct cell contents with bit-packed per-cell palette indices.
+/// A width of zero bits marks a uniform sector holding only the first entry.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct BlockStorage {
+    pub palette_vec: Vec<Option<PaletteEntry>>,
+    pub count_vec: Vec<u32>,
+    pub bits_per_index: u32,
+    pub word_vec: Vec<u64>,
+}
+
+impl BlockStorage {
+    pub fn new() -> Self {
+        Self::new_uniform(None)
+    }
+
+    pub fn new_uniform(palette_entry_option: Option<PaletteEntry>) -> Self {
+        Self {
+            palette_vec: vec![palette_entry_option],
+            count_vec: vec![SECTOR_VOLUME_IN_CELLS as u32],
+            bits_per_index: 0,
+            word_vec: Vec::new(),
+        }
+    }
+
+    pub fn is_uniform(block_storage: &Self) -> bool {
+        block_storage.bits_per_index == 0
+    }
+
+    pub fn is_empty(block_storage: &Self) -> bool {
+        Self::is_uniform(block_storage) && block_storage.palette_vec[0].is_none()
+    }
+
+    pub fn get_entry(cell_index: CellIndex, block_storage: &Self) -> Option<&PaletteEntry> {
+        let palette_index =
+            Self::get_palette_index(CellIndex::as_index(&cell_index), block_storage);
+
+        block_storage.palette_vec[palette_index].as_ref()
+    }
+
+    pub fn get_block(
+        sector_index: SectorIndex,
+        cell_index: CellIndex,
+        block_storage: &Self,
+    ) -> Option<Block> {
+        let palette_entry = Self::get_entry(cell_index, block_storage)?;
+        let grid_position = grid::indices_to_grid_position(sector_index, cell_index);
+
+        Some(PaletteEntry::to_block(grid_position, palette_entry))
+    }
+
+    pub fn get_block_vec(sector_index: SectorIndex, block_storage: &Self) -> Vec<Block> {
+        if Self::is_empty(block_storage) {
+            return Vec::new();
+        }
+
+        grid::cell_index_vec()
+            .into_iter()
+            .filter_map(|cell_index| Self::get_block(sector_index, cell_index, block_storage))
+            .collect()
+    }
+
+    pub fn get_entry_count_iter(
+        block_storage: &Self,
+    ) -> impl Iterator<Item = (&PaletteEntry, usize)> {
+        block_storage
+            .palette_vec
+            .iter()
+            .zip(&block_storage.count_vec)
+            .filter_map(|(palette_entry_option, count)| {
+                palette_entry_option
+                    .as_ref()
+                    .map(|palette_entry| (palette_entry, *count as usize))
+            })
+    }
+
+    pub fn set_entry(
+        cell_index: CellIndex,
+        palette_entry_option: Option<PaletteEntry>,
+        block_storage: &mut Self,
+    ) {
+        let index = CellIndex::as_index(&cell_index);
+        let current_palette_index = Self::get_palette_index(index, block_storage);
+
+        if block_storage.palette_vec[current_palette_index] == palette_entry_option {
+            return;
+        }
+
+        let palette_index = Self::acquire_palette_index(palette_entry_option, block_storage);
+
+        block_storage.count_vec[current_palette_index] -= 1;
+        block_storage.count_vec[palette_index] += 1;
+
+        if block_storage.count_vec[palette_index] == SECTOR_VOLUME_IN_CELLS as u32 {
+            let palette_entry_option = block_storage.palette_vec.swap_remove(palette_index);
+
+            *block_storage = Self::new_uniform(palette_entry_option);
+        } else {
+            Self::set_palette_index(index, palette_index, block_storage);
+        }
+    }
+
+    pub fn get_memory_size(block_storage: &Self) -> usize {
+        std::mem::size_of::<Self>()
+            + block_storage.palette_vec.capacity() * std::mem::size_of::<Option<PaletteEntry>>()
+            + block_storage.count_vec.capacity() * std::mem::size_of::<u32>()
+            + block_storage.word_vec.capacity() * std::mem::size_of::<u64>()
+    }
+
+    fn acquire_palette_index(
+        palette_entry_option: Option<PaletteEntry>,
+        block_storage: &mut Self,
+    ) -> usize {
+        if let Some(palette_index) = block_storage
+            .palette_vec
+            .iter()
+            .position(|entry_option| *entry_option == palette_entry_option)
+        {
+            return palette_index;
+        }
+
+        if let Some(palette_index) = block_storage.count_vec.iter().position(|count| *count == 0) {
+            block_storage.palette_vec[palette_index] = palette_entry_option;
+
+            return palette_index;
+        }
+
+        block_storage.palette_vec.push(palette_entry_option);
+        block_storage.count_vec.push(0);
+
+        let palette_len = block_storage.palette_vec.len();
+
+        if palette_len > 1 << block_storage.bits_per_index {
+            Self::widen(palette_len, block_storage);
+        }
+
+        palette_len - 1
+    }
+
+    fn widen(palette_len: usize, block_storage: &mut Self) {
+        let mut bits_per_index = block_storage.bits_per_index.max(1);
+
+        while palette_len > 1 << bits_per_index {
+            bits_per_index *= 2;
+        }
+
+        let mut widened_storage = Self {
+            palette_vec: Vec::new(),
+            count_vec: Vec::new(),
+            bits_per_index,
+            word_vec: vec![0; Self::get_word_count(bits_per_index)],
+        };
+
+        for index in 0..SECTOR_VOLUME_IN_CELLS {
+            let palette_index = Self::get_palette_index(index, block_storage);
+
+            Self::set_palette_index(index, palette_index, &mut widened_storage);
+        }
+
+        block_storage.bits_per_index = widened_storage.bits_per_index;
+        block_storage.word_vec = widened_storage.word_vec;
+    }
+
+    fn get_word_count(bits_per_index: u32) -> usize {
+        let index_per_word = (u64::BITS / bits_per_index) as usize;
+
+        SECTOR_VOLUME_IN_CELLS.div_ceil(index_per_word)
+    }
+
+    fn get_palette_index(index: usize, block_storage: &Self) -> usize {
+        if Self::is_uniform(block_storage) {
+            return 0;
+        }
+
+        let bits_per_index = block_storage.bits_per_index;
+        let index_per_word = (u64::BITS / bits_per_index) as usize;
+
+        let word = block_storage.word_vec[index / index_per_word];
+        let shift = (index % index_per_word) as u32 * bits_per_index;
+        let mask = (1u64 << bits_per_index) - 1;
+
+        ((word >> shift) & mask) as usize
+    }
+
+    fn set_palette_index(index: usize, palette_index: usize, block_storage: &mut Self) {
+        let bits_per_index = block_storage.bits_per_index;
+        let index_per_word = (u64::BITS / bits_per_index) as usize;
+
+        let word = &mut block_storage.word_vec[index / index_per_word];
+        let shift = (index % index_per_word) as u32 * bits_per_index;
+        let mask = (1u64 << bits_per_index) - 1;
+
+        *word = (*word & !(mask << shift)) | ((palette_index as u64 & mask) << shift);
+    }
+}
+
+impl Default for BlockStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::simulation::state::world::{
+    cell::cell_index::CellIndex, light::light_channel::LightChannel,
+};
+use serde::{Deserialize, Serialize};
+
+/// Sky and block light levels per cell, packed as the high and low nibble.
+/// An empty light vec marks a sector lit uniformly by the uniform light.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct LightField {
+    pub uniform_light: u8,
+    pub light_vec: Vec<u8>,
+}
+
+impl LightField {
+    pub fn new() -> Self {
+        Self {
+            uniform_light: 0,
+            light_vec: Vec::new(),
+        }
+    }
+
+    pub fn get_level(
+        cell_index: CellIndex,
+        light_channel: &LightChannel,
+        light_field: &Self,
+    ) -> u8 {
+        let light = if light_field.light_vec.is_empty() {
+            light_field.uniform_light
+        } else {
+            light_field.light_vec[CellIndex::as_index(&cell_index)]
+        };
+
+        (light >> LightChannel::get_shift(light_channel)) & 0x0F
+    }
+
+    pub fn compact(light_field: &mut Self) {
+        let Some(first_light) = light_field.light_vec.first().copied() else {
+            return;
+        };
+
+        if light_field
+            .light_vec
+            .iter()
+            .all(|light| *light == first_light)
+        {
+            light_field.uniform_light = first_light;
+            light_field.light_vec = Vec::new();
+        }
+    }
+}
+
+impl Default for LightField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
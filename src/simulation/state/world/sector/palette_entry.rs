@@ -0,0 +1,35 @@
+use crate::simulation::state::world::{
+    block::{block_kind::BlockKind, block_state::BlockState, Block},
+    grid::Direction,
+};
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PaletteEntry {
+    pub block_kind: BlockKind,
+    pub direction: Direction,
+    pub block_state: BlockState,
+}
+
+impl PaletteEntry {
+    pub fn from_block(block: Block) -> Self {
+        Self {
+            block_kind: block.block_kind,
+            direction: block.direction,
+            block_state: block.block_state,
+        }
+    }
+
+    pub fn to_block(grid_position: IVec3, palette_entry: &Self) -> Block {
+        let block_shape = BlockKind::get_block_shape(&palette_entry.block_kind);
+
+        Block {
+            block_kind: palette_entry.block_kind.clone(),
+            grid_position,
+            direction: palette_entry.direction,
+            block_shape,
+            block_state: palette_entry.block_state.clone(),
+        }
+    }
+}
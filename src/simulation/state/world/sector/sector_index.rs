@@ -1,24 +1,23 @@
-#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct SectorIndex(usize);
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
 
-impl SectorIndex {
-    pub fn new(sector_index: usize) -> Self {
-        Self(sector_index)
-    }
-
-    pub fn as_index(sector_index: &Self) -> usize {
-        sector_index.0
-    }
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SectorIndex {
+    x: i32,
+    y: i32,
+    z: i32,
 }
 
-impl From<SectorIndex> for usize {
-    fn from(sector_index: SectorIndex) -> Self {
-        sector_index.0
+impl SectorIndex {
+    pub fn new(sector_coordinate: IVec3) -> Self {
+        Self {
+            x: sector_coordinate.x,
+            y: sector_coordinate.y,
+            z: sector_coordinate.z,
+        }
     }
-}
 
-impl From<SectorIndex> for u32 {
-    fn from(sector_index: SectorIndex) -> Self {
-        sector_index.0 as u32
+    pub fn to_sector_coordinate(sector_index: &Self) -> IVec3 {
+        IVec3::new(sector_index.x, sector_index.y, sector_index.z)
     }
 }
@@ -0,0 +1,134 @@
+use crate::simulation::{
+    constants::{SECTOR_STORE_DIRECTORY, SECTOR_STORE_EXTENSION},
+    state::{
+        world::sector::{sector_index::SectorIndex, Sector},
+        Checksum,
+    },
+};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+static SECTOR_STORE_COUNT: AtomicU64 = AtomicU64::new(0);
+
+pub struct SectorStore {
+    pub directory: PathBuf,
+    pub stored_index_set: BTreeSet<SectorIndex>,
+    pub checksum_map: BTreeMap<SectorIndex, u64>,
+}
+
+impl SectorStore {
+    pub fn new() -> Self {
+        let store_id = SECTOR_STORE_COUNT.fetch_add(1, Ordering::Relaxed);
+
+        let directory = std::env::temp_dir()
+            .join(SECTOR_STORE_DIRECTORY)
+            .join(format!("{}-{}", std::process::id(), store_id));
+
+        let stored_index_set = BTreeSet::new();
+        let checksum_map = BTreeMap::new();
+
+        Self {
+            directory,
+            stored_index_set,
+            checksum_map,
+        }
+    }
+
+    pub fn contains(sector_index: &SectorIndex, sector_store: &Self) -> bool {
+        sector_store.stored_index_set.contains(sector_index)
+    }
+
+    pub fn write(sector: &Sector, sector_store: &mut Self) -> io::Result<()> {
+        fs::create_dir_all(&sector_store.directory)?;
+
+        let path = Self::get_path(&sector.sector_index, sector_store);
+
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        bincode::serialize_into(&mut writer, sector)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        writer.flush()?;
+
+        sector_store.stored_index_set.insert(sector.sector_index);
+
+        // Kept so the State checksum covers evicted sectors without reading them back
+        sector_store
+            .checksum_map
+            .insert(sector.sector_index, Checksum::compute_sector(sector));
+
+        Ok(())
+    }
+
+    pub fn take(sector_index: &SectorIndex, sector_store: &mut Self) -> io::Result<Sector> {
+        let sector = Self::read(sector_index, sector_store)?;
+
+        fs::remove_file(Self::get_path(sector_index, sector_store))?;
+
+        sector_store.stored_index_set.remove(sector_index);
+        sector_store.checksum_map.remove(sector_index);
+
+        Ok(sector)
+    }
+
+    pub fn read(sector_index: &SectorIndex, sector_store: &Self) -> io::Result<Sector> {
+        let path = Self::get_path(sector_index, sector_store);
+
+        let reader = BufReader::new(File::open(path)?);
+
+        let sector = bincode::deserialize_from(reader)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        Ok(sector)
+    }
+
+    pub fn read_all(sector_store: &Self) -> io::Result<Vec<Sector>> {
+        sector_store
+            .stored_index_set
+            .iter()
+            .map(|sector_index| Self::read(sector_index, sector_store))
+            .collect()
+    }
+
+    pub fn clear(sector_store: &mut Self) {
+        if sector_store.directory.exists() {
+            if let Err(error) = fs::remove_dir_all(&sector_store.directory) {
+                tracing::error!("Failed to clear sector store: {}", error);
+            }
+        }
+
+        sector_store.stored_index_set.clear();
+        sector_store.checksum_map.clear();
+    }
+
+    fn get_path(sector_index: &SectorIndex, sector_store: &Self) -> PathBuf {
+        let sector_coordinate = SectorIndex::to_sector_coordinate(sector_index);
+
+        let path = sector_store
+            .directory
+            .join(format!(
+                "{}_{}_{}",
+                sector_coordinate.x, sector_coordinate.y, sector_coordinate.z
+            ))
+            .with_extension(SECTOR_STORE_EXTENSION);
+
+        path
+    }
+}
+
+impl Default for SectorStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SectorStore {
+    fn drop(&mut self) {
+        Self::clear(self);
+    }
+}
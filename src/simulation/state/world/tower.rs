@@ -10,18 +10,20 @@ use crate::simulation::{
         Area,
     },
 };
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use ultraviolet::IVec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Tower {
-    pub area_map: HashMap<AreaID, Area>,
-    pub floor_map: HashMap<i32, Floor>,
+    pub area_map: BTreeMap<AreaID, Area>,
+    pub floor_map: BTreeMap<i32, Floor>,
 }
 
 impl Tower {
     pub fn new() -> Self {
-        let area_map = HashMap::new();
-        let floor_map = HashMap::new();
+        let area_map = BTreeMap::new();
+        let floor_map = BTreeMap::new();
 
         Self {
             area_map,
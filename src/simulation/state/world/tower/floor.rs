@@ -7,14 +7,16 @@ use crate::{
     },
     utils::id_generator::IDGenerator,
 };
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use ultraviolet::IVec3;
 
+#[derive(Serialize, Deserialize)]
 pub struct Floor {
     pub floor_number: i32,
     pub grid_position: IVec3,
-    pub id_area_map: HashMap<AreaID, Area>,
-    pub kind_id_map: HashMap<AreaKind, AreaID>,
+    pub id_area_map: BTreeMap<AreaID, Area>,
+    pub kind_id_map: BTreeMap<AreaKind, AreaID>,
 }
 
 impl Floor {
@@ -206,7 +208,7 @@ impl Floor {
             connection_vec: Vec::new(),
         };
 
-        let kind_id_map = HashMap::from([
+        let kind_id_map = BTreeMap::from([
             (AreaKind::Center, center_area.area_id),
             (AreaKind::CenterHall, center_hall_north_area.area_id),
             (AreaKind::CenterHall, center_hall_west_area.area_id),
@@ -222,7 +224,7 @@ impl Floor {
             (AreaKind::CornerHall, corner_hall_quadrant_ne_area.area_id),
         ]);
 
-        let id_area_map = HashMap::from([
+        let id_area_map = BTreeMap::from([
             (center_area.area_id, center_area),
             (center_hall_north_area.area_id, center_hall_north_area),
             (center_hall_west_area.area_id, center_hall_west_area),
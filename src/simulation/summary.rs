@@ -0,0 +1,108 @@
+//! Aggregate statistics of the simulation State
+
+pub mod statistics;
+
+use crate::simulation::{
+    state::{Checksum, State},
+    summary::statistics::Statistics,
+    supervisor::Supervisor,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Summary {
+    pub supervisor_tick: u32,
+    pub world_tick: u64,
+    pub person_count: usize,
+    pub sector_count: usize,
+    pub checksum_tick: u32,
+    pub checksum: u64,
+    pub statistics_option: Option<Statistics>,
+}
+
+impl Summary {
+    pub fn new(state: &State, supervisor: &Supervisor) -> Self {
+        let mut summary = Self::new_tick(state, supervisor);
+
+        summary.checksum_tick = supervisor.tick;
+        summary.checksum = Checksum::compute(state);
+        summary.statistics_option = Some(Statistics::new(state));
+
+        summary
+    }
+
+    // Per-tick summaries reuse the interval checksum and skip the sector scan
+    pub fn new_tick(state: &State, supervisor: &Supervisor) -> Self {
+        let supervisor_tick = supervisor.tick;
+        let world_tick = state.world.time.tick;
+        let person_count = state.population.person_map.len();
+        let sector_count = state.world.sector_map.len();
+        let checksum_tick = supervisor.checksum_tick;
+        let checksum = supervisor.checksum;
+        let statistics_option = None;
+
+        Self {
+            supervisor_tick,
+            world_tick,
+            person_count,
+            sector_count,
+            checksum_tick,
+            checksum,
+            statistics_option,
+        }
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Supervisor Ticks: {}", self.supervisor_tick)?;
+        writeln!(f, "World Ticks: {}", self.world_tick)?;
+        writeln!(f, "Persons: {}", self.person_count)?;
+
+        if let Some(statistics) = &self.statistics_option {
+            let block_total: usize = statistics
+                .block_count_vec
+                .iter()
+                .map(|(_, count)| count)
+                .sum();
+
+            writeln!(f, "Blocks: {}", block_total)?;
+
+            for (block_kind, count) in &statistics.block_count_vec {
+                if *count > 0 {
+                    writeln!(f, "    {}: {}", block_kind, count)?;
+                }
+            }
+
+            let area_total: usize = statistics
+                .area_count_vec
+                .iter()
+                .map(|(_, count)| count)
+                .sum();
+
+            writeln!(f, "Areas: {}", area_total)?;
+
+            for (area_kind, count) in &statistics.area_count_vec {
+                if *count > 0 {
+                    writeln!(f, "    {}: {}", area_kind, count)?;
+                }
+            }
+        }
+
+        writeln!(f, "Sectors: {}", self.sector_count)?;
+
+        if let Some(statistics) = &self.statistics_option {
+            writeln!(
+                f,
+                "    Memory: {} KiB (unpacked estimate {} KiB)",
+                statistics.sector_memory_in_bytes / 1024,
+                statistics.sector_memory_unpacked_estimate_in_bytes / 1024
+            )?;
+        }
+
+        writeln!(f, "Checksum {}: {:016x}", self.checksum_tick, self.checksum)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use crate::simulation::{
+    constants::SECTOR_VOLUME_IN_CELLS,
+    state::{
+        world::{
+            area::area_kind::AreaKind,
+            block::{block_kind::BlockKind, Block},
+            sector::{block_storage::BlockStorage, Sector},
+        },
+        State,
+    },
+};
+use serde::{Deserialize, Serialize};
+use strum::VariantArray;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Statistics {
+    pub block_count_vec: Vec<(BlockKind, usize)>,
+    pub area_count_vec: Vec<(AreaKind, usize)>,
+    pub sector_memory_in_bytes: usize,
+    pub sector_memory_unpacked_estimate_in_bytes: usize,
+}
+
+impl Statistics {
+    pub fn new(state: &State) -> Self {
+        let mut block_count_vec: Vec<(BlockKind, usize)> = BlockKind::VARIANTS
+            .iter()
+            .map(|block_kind| (block_kind.clone(), 0))
+            .collect();
+
+        let mut sector_memory_in_bytes = 0;
+        let mut sector_memory_unpacked_estimate_in_bytes = 0;
+
+        for sector in state.world.sector_map.values() {
+            for (palette_entry, count) in BlockStorage::get_entry_count_iter(&sector.block_storage)
+            {
+                block_count_vec[palette_entry.block_kind.clone() as usize].1 += count;
+            }
+
+            sector_memory_in_bytes += BlockStorage::get_memory_size(&sector.block_storage);
+
+            // Estimated from the old layout of one Option<Block> per cell, not measured
+            if !Sector::is_empty(sector) {
+                sector_memory_unpacked_estimate_in_bytes +=
+                    SECTOR_VOLUME_IN_CELLS * std::mem::size_of::<Option<Block>>();
+            }
+        }
+
+        let mut area_count_vec: Vec<(AreaKind, usize)> = AreaKind::VARIANTS
+            .iter()
+            .map(|area_kind| (area_kind.clone(), 0))
+            .collect();
+
+        for area in state.world.tower.area_map.values() {
+            area_count_vec[area.area_kind.clone() as usize].1 += 1;
+        }
+
+        Self {
+            block_count_vec,
+            area_count_vec,
+            sector_memory_in_bytes,
+            sector_memory_unpacked_estimate_in_bytes,
+        }
+    }
+}
@@ -1,9 +1,15 @@
+pub mod control;
 pub mod message;
+pub mod recorder;
 pub mod scheduler;
+pub mod server;
 pub mod supervisor_status;
 pub mod viewer;
 
+pub use control::Control;
 pub use message::Message;
+pub use recorder::Recorder;
+pub use server::Server;
 pub use viewer::Viewer;
 
 use crate::{
@@ -11,26 +17,40 @@ use crate::{
     simulation::{
         constants::*,
         state::{
-            action::act::{self, Act, JumpData, PlaceBlockData, RemoveBlockData},
+            action::act::{
+                self, Act, CopyRegionData, DoorData, JumpData, LockDoorData, PasteRegionData,
+                PlaceBlockData, RedoData, RemoveBlockData, ReplaceRegionData, UndoData,
+            },
             population::{
                 motion::{self},
-                person::person_id::PersonID,
+                person::{person_id::PersonID, Person},
             },
             work::{
                 construct_task::{generate_data::GenerateData, ConstructTask},
                 construct_worker::ConstructWorker,
             },
-            world::block::block_kind::BlockKind,
-            State,
+            world::{
+                block::block_kind::BlockKind,
+                light::Light,
+                region_edit::{edit_journal::EditJournal, RegionEdit},
+                schematic::Schematic,
+            },
+            Checksum, Population, SaveFile, State,
         },
+        summary::Summary,
         supervisor::{
-            scheduler::Scheduler, supervisor_status::SupervisorStatus, viewer::view::View,
+            recorder::RecorderMode, scheduler::Scheduler, supervisor_status::SupervisorStatus,
+            viewer::ViewInput,
         },
     },
+    utils::ldmath::IntBox,
+};
+use std::{
+    path::Path,
+    time::{Duration, Instant},
 };
-use std::time::{Duration, Instant};
 use tracing::instrument;
-use ultraviolet::Vec3;
+use ultraviolet::{IVec3, Vec3};
 
 pub struct Supervisor {
     pub supervisor_status: SupervisorStatus,
@@ -38,28 +58,58 @@ pub struct Supervisor {
     pub frame_ticks: u32,
     pub instant_start: Instant,
     pub instant_next: Instant,
+    pub timestep_base_tick: u32,
+    pub paused: bool,
+    pub step_count: u32,
+    pub time_scale: f32,
+    pub unbounded: bool,
+    pub autosave: bool,
+    pub tick_rate: f32,
+    pub tick_rate_count: u32,
+    pub tick_rate_instant: Instant,
     pub message_limit: usize,
     pub message_rx: crossbeam::channel::Receiver<Message>,
     pub viewer: Viewer,
     pub scheduler: Scheduler,
+    pub recorder: Recorder,
+    pub control: Control,
+    pub server: Server,
+    pub checksum_interval: u32,
+    pub checksum_tick: u32,
+    pub checksum: u64,
 }
 
 impl Supervisor {
-    pub fn new(
-        message_rx: crossbeam::channel::Receiver<Message>,
-        view_input: triple_buffer::Input<View>,
-    ) -> Self {
+    pub fn new(message_rx: crossbeam::channel::Receiver<Message>, view_input: ViewInput) -> Self {
         let supervisor_status = SupervisorStatus::Start;
 
         let tick = 0;
         let frame_ticks = 0;
         let instant_start = Instant::now();
         let instant_next = Instant::now();
+        let timestep_base_tick = 0;
+
+        let paused = false;
+        let step_count = 0;
+        let time_scale = 1.0;
+        let unbounded = false;
+        let autosave = false;
+
+        let tick_rate = 0.0;
+        let tick_rate_count = 0;
+        let tick_rate_instant = Instant::now();
 
         let message_limit = OVERSEER_MESSAGE_LIMIT;
 
         let viewer = Viewer::new(view_input);
         let scheduler = Scheduler::new();
+        let recorder = Recorder::new(RecorderMode::Off).expect("Failed to create Recorder");
+        let control = Control::new();
+        let server = Server::new();
+
+        let checksum_interval = SIMULATION_CHECKSUM_INTERVAL;
+        let checksum_tick = 0;
+        let checksum = 0;
 
         Self {
             supervisor_status,
@@ -67,10 +117,25 @@ impl Supervisor {
             frame_ticks,
             instant_start,
             instant_next,
+            timestep_base_tick,
+            paused,
+            step_count,
+            time_scale,
+            unbounded,
+            autosave,
+            tick_rate,
+            tick_rate_count,
+            tick_rate_instant,
             message_limit,
             message_rx,
             viewer,
             scheduler,
+            recorder,
+            control,
+            server,
+            checksum_interval,
+            checksum_tick,
+            checksum,
         }
     }
 
@@ -79,19 +144,32 @@ impl Supervisor {
     }
 
     pub fn has_work(supervisor: &Self) -> bool {
-        Instant::now() >= supervisor.instant_next
-            && supervisor.frame_ticks < SIMULATION_MAX_TICKS_PER_FRAME
+        if supervisor.frame_ticks >= SIMULATION_MAX_TICKS_PER_FRAME {
+            return false;
+        }
+
+        supervisor.unbounded || Instant::now() >= supervisor.instant_next
     }
 
     pub fn update_timestep(supervisor: &mut Self) {
         supervisor.tick += 1;
         supervisor.frame_ticks += 1;
 
-        supervisor.instant_next =
-            supervisor.instant_start + supervisor.tick * SIMULATION_TICK_DURATION;
+        if supervisor.unbounded {
+            supervisor.instant_next = Instant::now();
+        } else {
+            let timestep_ticks = supervisor.tick - supervisor.timestep_base_tick;
+
+            supervisor.instant_next =
+                supervisor.instant_start + timestep_ticks * Self::get_tick_duration(supervisor);
+        }
     }
 
     pub fn fix_timestep(supervisor: &mut Self) {
+        if supervisor.unbounded {
+            return;
+        }
+
         let current_instant = Instant::now();
 
         if current_instant < supervisor.instant_next {
@@ -107,36 +185,143 @@ impl Supervisor {
         }
     }
 
+    pub fn get_tick_duration(supervisor: &Self) -> Duration {
+        SIMULATION_TICK_DURATION.div_f32(supervisor.time_scale)
+    }
+
+    fn reset_timestep(supervisor: &mut Self) {
+        supervisor.instant_start = Instant::now();
+        supervisor.instant_next = supervisor.instant_start;
+        supervisor.timestep_base_tick = supervisor.tick;
+    }
+
+    fn should_advance(supervisor: &mut Self) -> bool {
+        if !supervisor.paused {
+            true
+        } else if supervisor.step_count > 0 {
+            supervisor.step_count -= 1;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    fn update_tick_rate(advanced: bool, supervisor: &mut Self) {
+        if advanced {
+            supervisor.tick_rate_count += 1;
+        }
+
+        let elapsed = supervisor.tick_rate_instant.elapsed();
+
+        if elapsed >= Duration::from_secs(1) {
+            supervisor.tick_rate = supervisor.tick_rate_count as f32 / elapsed.as_secs_f32();
+            supervisor.tick_rate_count = 0;
+            supervisor.tick_rate_instant = Instant::now();
+        }
+    }
+
     #[instrument(skip_all)]
     pub fn tick(state: &mut State, supervisor: &mut Self) -> bool {
         Self::update_timestep(supervisor);
+
+        Control::tick(state, &mut supervisor.control);
+        Server::receive(state, &supervisor.viewer, &mut supervisor.server);
+
         Self::receive_messages(state, supervisor);
 
+        // Relights message edits and the previous State tick before any view reads the world
+        Light::update(&mut state.world);
+
         Viewer::tick(state, supervisor);
 
-        match supervisor.supervisor_status {
+        if Server::is_active(&supervisor.server) {
+            let supervisor_view = Viewer::update_supervisor_view(supervisor);
+
+            Server::publish(state, &supervisor_view, &mut supervisor.server);
+        }
+
+        let running = match supervisor.supervisor_status {
             SupervisorStatus::Start => true,
-            SupervisorStatus::Run => State::tick(state),
+            SupervisorStatus::Run => {
+                let advanced = Self::should_advance(supervisor);
+
+                Self::update_tick_rate(advanced, supervisor);
+
+                if advanced {
+                    if state.active {
+                        Scheduler::tick(state, &mut supervisor.scheduler);
+                    }
+
+                    let running = State::tick(state);
+
+                    Self::publish_summary(state, supervisor);
+
+                    running
+                } else {
+                    true
+                }
+            }
             SupervisorStatus::Done => false,
+        };
+
+        Self::update_checksum(state, supervisor);
+
+        running
+    }
+
+    fn publish_summary(state: &State, supervisor: &mut Self) {
+        if Control::has_subscriber(&supervisor.control) {
+            let summary = Summary::new_tick(state, supervisor);
+
+            Control::publish_summary(&summary, &mut supervisor.control);
         }
     }
 
+    fn update_checksum(state: &State, supervisor: &mut Self) {
+        if supervisor.supervisor_status != SupervisorStatus::Run
+            || supervisor.checksum_interval == 0
+            || !supervisor.tick.is_multiple_of(supervisor.checksum_interval)
+        {
+            return;
+        }
+
+        supervisor.checksum_tick = supervisor.tick;
+        supervisor.checksum = Checksum::compute(state);
+    }
+
     fn receive_messages(state: &mut State, supervisor: &mut Self) {
-        let mut message_limit = supervisor.message_limit;
+        let mut message_vec = Vec::new();
 
         while let Ok(message) = supervisor.message_rx.try_recv() {
-            message_limit -= 1;
+            if message_vec.len() < supervisor.message_limit {
+                message_vec.push(message);
+            }
+        }
 
-            if message_limit > 0 {
-                match supervisor.supervisor_status {
-                    SupervisorStatus::Start => {
-                        Self::handle_start_message(&message, state, supervisor)
-                    }
-                    SupervisorStatus::Run => Self::handle_run_message(&message, state, supervisor),
-                    SupervisorStatus::Done => {
-                        Self::handle_done_message(&message, state, supervisor)
-                    }
-                }
+        message_vec.extend(Control::take_message_vec(&mut supervisor.control));
+        message_vec.extend(Server::take_message_vec(&mut supervisor.server));
+
+        if Recorder::is_replaying(&supervisor.recorder) {
+            let quit_requested = message_vec
+                .iter()
+                .any(|message| matches!(message, Message::Quit));
+
+            message_vec =
+                Recorder::get_replay_message_vec(supervisor.tick, &mut supervisor.recorder);
+
+            if quit_requested {
+                message_vec.push(Message::Quit);
+            }
+        }
+
+        for message in message_vec {
+            Recorder::record(supervisor.tick, &message, &mut supervisor.recorder);
+
+            match supervisor.supervisor_status {
+                SupervisorStatus::Start => Self::handle_start_message(&message, state, supervisor),
+                SupervisorStatus::Run => Self::handle_run_message(&message, state, supervisor),
+                SupervisorStatus::Done => Self::handle_done_message(&message, state, supervisor),
             }
         }
     }
@@ -144,7 +329,12 @@ impl Supervisor {
     fn handle_start_message(message: &Message, state: &mut State, supervisor: &mut Self) {
         match message {
             Message::SetSeed(seed_data) => Self::handle_set_seed_message(seed_data, state),
-            Message::Generate => Self::handle_generate_message(state),
+            Message::SetSchematics(schematic_set_data) => {
+                Self::handle_set_schematics_message(schematic_set_data, state)
+            }
+            Message::Generate => Self::handle_generate_message(state, supervisor),
+            Message::Save(save_data) => Self::handle_save_message(save_data, state, supervisor),
+            Message::Load(load_data) => Self::handle_load_message(load_data, state, supervisor),
             Message::Quit => Self::handle_quit_message(state, supervisor),
             _ => (),
         }
@@ -152,29 +342,89 @@ impl Supervisor {
 
     fn handle_run_message(message: &Message, state: &mut State, supervisor: &mut Self) {
         match message {
-            Message::Interact1 => Self::handle_interact1(state),
-            Message::Interact2 => Self::handle_interact2(state),
+            Message::Interact1(person_input_data) => {
+                Self::handle_interact1_message(person_input_data, state)
+            }
+            Message::Interact2(person_input_data) => {
+                Self::handle_interact2_message(person_input_data, state)
+            }
             Message::RotateInput(rotate_data) => {
                 Self::handle_rotate_input_message(rotate_data, state)
             }
             Message::MoveInput(move_data) => Self::handle_move_input_message(move_data, state),
-            Message::JumpInput => Self::handle_jump_input_message(state),
+            Message::JumpInput(person_input_data) => {
+                Self::handle_jump_input_message(person_input_data, state)
+            }
             Message::SetSeed(seed_data) => Self::handle_set_seed_message(seed_data, state),
-            Message::Generate => Self::handle_generate_message(state),
+            Message::SetSchematics(schematic_set_data) => {
+                Self::handle_set_schematics_message(schematic_set_data, state)
+            }
+            Message::Generate => Self::handle_generate_message(state, supervisor),
+            Message::Save(save_data) => Self::handle_save_message(save_data, state, supervisor),
+            Message::Load(load_data) => Self::handle_load_message(load_data, state, supervisor),
             Message::Quit => Self::handle_quit_message(state, supervisor),
-            Message::Debug => Self::handle_debug_message(state),
-            Message::Option1 => Self::handle_option1_message(state),
-            Message::Option2 => Self::handle_option2_message(state),
-            Message::Option3 => Self::handle_option3_message(state),
-            Message::Option4 => Self::handle_option4_message(state),
+            Message::TogglePause => Self::handle_toggle_pause_message(supervisor),
+            Message::Step => Self::handle_step_message(supervisor),
+            Message::SlowDown => {
+                Self::handle_set_time_scale(supervisor.time_scale * 0.5, supervisor)
+            }
+            Message::SpeedUp => {
+                Self::handle_set_time_scale(supervisor.time_scale * 2.0, supervisor)
+            }
+            Message::SetTimeScale(time_scale_data) => {
+                Self::handle_set_time_scale(time_scale_data.time_scale, supervisor)
+            }
+            Message::ToggleUnbounded => Self::handle_toggle_unbounded_message(supervisor),
+            Message::Teleport(teleport_data) => Self::handle_teleport_message(teleport_data, state),
+            Message::SetBlock(set_block_data) => {
+                Self::handle_set_block_message(set_block_data, state)
+            }
+            Message::Fill(fill_data) => Self::handle_fill_message(fill_data, state),
+            Message::Spawn(spawn_data) => Self::handle_spawn_message(spawn_data, state),
+            Message::AddJudge(add_judge_data) => {
+                Self::handle_add_judge_message(add_judge_data, state)
+            }
+            Message::Debug(person_input_data) => {
+                Self::handle_debug_message(person_input_data, state)
+            }
+            Message::Option1(person_input_data) => {
+                Self::handle_option1_message(person_input_data, state)
+            }
+            Message::Option2(person_input_data) => {
+                Self::handle_option2_message(person_input_data, state)
+            }
+            Message::Option3(person_input_data) => {
+                Self::handle_option3_message(person_input_data, state)
+            }
+            Message::Option4(person_input_data) => {
+                Self::handle_option4_message(person_input_data, state)
+            }
+            Message::CopyRegion(region_data) => {
+                Self::handle_copy_region_message(region_data, state)
+            }
+            Message::PasteRegion(paste_data) => {
+                Self::handle_paste_region_message(paste_data, state)
+            }
+            Message::ReplaceRegion(replace_data) => {
+                Self::handle_replace_region_message(replace_data, state)
+            }
+            Message::Undo(person_input_data) => Self::handle_undo_message(person_input_data, state),
+            Message::Redo(person_input_data) => Self::handle_redo_message(person_input_data, state),
+            Message::SaveSchematic(schematic_data) => {
+                Self::handle_save_schematic_message(schematic_data, state)
+            }
+            Message::Door(person_input_data) => Self::handle_door_message(person_input_data, state),
+            Message::LockDoor(person_input_data) => {
+                Self::handle_lock_door_message(person_input_data, state)
+            }
         }
     }
 
     fn handle_done_message(_message: &Message, _state: &mut State, _supervisor: &mut Self) {}
 
-    fn handle_interact1(state: &mut State) {
+    fn handle_interact1_message(person_input_data: &message::PersonInputData, state: &mut State) {
         let place_block_data = PlaceBlockData {
-            person_id: PersonID::JUDGE_ID_1,
+            person_id: person_input_data.person_id,
         };
 
         state
@@ -183,9 +433,9 @@ impl Supervisor {
             .push_back(Act::PlaceBlock(place_block_data));
     }
 
-    fn handle_interact2(state: &mut State) {
+    fn handle_interact2_message(person_input_data: &message::PersonInputData, state: &mut State) {
         let remove_block_data = RemoveBlockData {
-            person_id: PersonID::JUDGE_ID_1,
+            person_id: person_input_data.person_id,
         };
 
         state
@@ -194,12 +444,31 @@ impl Supervisor {
             .push_back(Act::RemoveBlock(remove_block_data));
     }
 
+    fn handle_door_message(person_input_data: &message::PersonInputData, state: &mut State) {
+        let door_data = DoorData {
+            person_id: person_input_data.person_id,
+        };
+
+        state.action.act_deque.push_back(Act::Door(door_data));
+    }
+
+    fn handle_lock_door_message(person_input_data: &message::PersonInputData, state: &mut State) {
+        let lock_door_data = LockDoorData {
+            person_id: person_input_data.person_id,
+        };
+
+        state
+            .action
+            .act_deque
+            .push_back(Act::LockDoor(lock_door_data));
+    }
+
     fn handle_rotate_input_message(
         rotate_input_data: &message::RotateInputData,
         state: &mut State,
     ) {
         let rotate_data = act::RotateData {
-            person_id: PersonID::JUDGE_ID_1,
+            person_id: rotate_input_data.person_id,
             rotation_angles: Vec3::new(
                 rotate_input_data.input_x,
                 rotate_input_data.input_y,
@@ -218,17 +487,26 @@ impl Supervisor {
         )
         .normalized();
 
+        let stance = if move_input_data.crouch {
+            motion::Stance::Crouch
+        } else if move_input_data.sprint {
+            motion::Stance::Sprint
+        } else {
+            motion::Stance::Walk
+        };
+
         let move_data = act::MoveData {
-            person_id: PersonID::JUDGE_ID_1,
+            person_id: move_input_data.person_id,
             move_direction,
+            stance,
         };
 
         state.action.act_deque.push_back(Act::Move(move_data));
     }
 
-    fn handle_jump_input_message(state: &mut State) {
+    fn handle_jump_input_message(person_input_data: &message::PersonInputData, state: &mut State) {
         let jump_data = JumpData {
-            person_id: PersonID::JUDGE_ID_1,
+            person_id: person_input_data.person_id,
         };
 
         state.action.act_deque.push_back(Act::Jump(jump_data));
@@ -238,23 +516,305 @@ impl Supervisor {
         State::seed(seed_data.seed, state);
     }
 
-    fn handle_generate_message(state: &mut State) {
+    fn handle_set_schematics_message(
+        schematic_set_data: &message::SchematicSetData,
+        state: &mut State,
+    ) {
+        state.world.schematic_map = schematic_set_data.schematic_map.clone();
+    }
+
+    fn handle_generate_message(state: &mut State, supervisor: &mut Self) {
         let generate_data = GenerateData::new();
         let construct_task = ConstructTask::Generate(generate_data);
 
         ConstructWorker::enqueue(construct_task, &mut state.work.construct_worker.task_deque);
 
         state.active = true;
+
+        Viewer::invalidate_world_views(&mut supervisor.viewer);
+        Server::invalidate_world_views(&mut supervisor.server);
+
+        supervisor.supervisor_status = SupervisorStatus::Run;
+    }
+
+    fn handle_save_message(save_data: &message::SaveData, state: &State, supervisor: &Self) {
+        if !state.active {
+            tracing::info!("No active State to save");
+
+            return;
+        }
+
+        if !SaveFile::name_is_valid(&save_data.save_name) {
+            tracing::error!("Invalid save name: {}", save_data.save_name);
+
+            return;
+        }
+
+        let path = SaveFile::get_path(&save_data.save_name);
+
+        match SaveFile::write(&path, state, &supervisor.scheduler) {
+            Ok(()) => tracing::info!("Saved State: {:?}", path),
+            Err(error) => tracing::error!("Save Failed: {:?} {}", path, error),
+        }
     }
 
-    fn handle_quit_message(_state: &mut State, supervisor: &mut Self) {
-        // TODO: Save Simulation State!
+    fn handle_load_message(
+        load_data: &message::LoadData,
+        state: &mut State,
+        supervisor: &mut Self,
+    ) {
+        if !SaveFile::name_is_valid(&load_data.save_name) {
+            tracing::error!("Invalid save name: {}", load_data.save_name);
+
+            return;
+        }
+
+        let path = SaveFile::get_path(&load_data.save_name);
+
+        match SaveFile::read(&path) {
+            Ok((loaded_state, loaded_scheduler)) => {
+                *state = loaded_state;
+                supervisor.scheduler = loaded_scheduler;
+
+                Viewer::invalidate_world_views(&mut supervisor.viewer);
+                Server::invalidate_world_views(&mut supervisor.server);
+
+                supervisor.supervisor_status = SupervisorStatus::Run;
+
+                tracing::info!("Loaded State: {:?}", path);
+            }
+            Err(error) => tracing::error!("Load Failed: {:?} {}", path, error),
+        }
+    }
+
+    fn handle_quit_message(state: &mut State, supervisor: &mut Self) {
+        if supervisor.autosave {
+            let save_data = message::SaveData {
+                save_name: String::from(SAVE_AUTOSAVE_NAME),
+            };
+
+            Self::handle_save_message(&save_data, state, supervisor);
+        }
+
+        Recorder::finish(&supervisor.recorder);
 
         supervisor.supervisor_status = SupervisorStatus::Done;
     }
 
-    fn handle_debug_message(state: &mut State) {
-        if let Some(judge) = state.population.person_map.get_mut(&PersonID::JUDGE_ID_1) {
+    fn handle_toggle_pause_message(supervisor: &mut Self) {
+        supervisor.paused = !supervisor.paused;
+        supervisor.step_count = 0;
+
+        Self::reset_timestep(supervisor);
+    }
+
+    fn handle_step_message(supervisor: &mut Self) {
+        if supervisor.paused {
+            supervisor.step_count += 1;
+        }
+    }
+
+    fn handle_set_time_scale(time_scale: f32, supervisor: &mut Self) {
+        supervisor.time_scale =
+            time_scale.clamp(SIMULATION_TIME_SCALE_MIN, SIMULATION_TIME_SCALE_MAX);
+
+        Self::reset_timestep(supervisor);
+    }
+
+    fn handle_toggle_unbounded_message(supervisor: &mut Self) {
+        supervisor.unbounded = !supervisor.unbounded;
+
+        Self::reset_timestep(supervisor);
+    }
+
+    fn handle_teleport_message(teleport_data: &message::TeleportData, state: &mut State) {
+        if let Some(judge) = state
+            .population
+            .person_map
+            .get_mut(&teleport_data.person_id)
+        {
+            Person::set_world_position(teleport_data.world_position, judge);
+            Person::set_velocity(Vec3::zero(), judge);
+        }
+    }
+
+    fn handle_set_block_message(set_block_data: &message::SetBlockData, state: &mut State) {
+        let int_box = IntBox::new(set_block_data.grid_position, set_block_data.grid_position);
+
+        let block_change_vec = RegionEdit::fill(
+            &int_box,
+            &set_block_data.direction,
+            &set_block_data.block_kind_option,
+            &mut state.world,
+        );
+
+        let edit_journal = state
+            .world
+            .edit_journal_map
+            .entry(set_block_data.person_id)
+            .or_default();
+
+        EditJournal::record(block_change_vec, edit_journal);
+    }
+
+    fn handle_fill_message(fill_data: &message::FillData, state: &mut State) {
+        let int_box = Self::get_int_box(fill_data.min, fill_data.max);
+
+        let block_change_vec = RegionEdit::fill(
+            &int_box,
+            &fill_data.direction,
+            &fill_data.block_kind_option,
+            &mut state.world,
+        );
+
+        let edit_journal = state
+            .world
+            .edit_journal_map
+            .entry(fill_data.person_id)
+            .or_default();
+
+        EditJournal::record(block_change_vec, edit_journal);
+    }
+
+    fn handle_copy_region_message(region_data: &message::RegionData, state: &mut State) {
+        let copy_region_data = CopyRegionData {
+            person_id: region_data.person_id,
+            int_box: Self::get_int_box(region_data.min, region_data.max),
+        };
+
+        state
+            .action
+            .act_deque
+            .push_back(Act::CopyRegion(copy_region_data));
+    }
+
+    fn handle_paste_region_message(paste_data: &message::PasteData, state: &mut State) {
+        let paste_region_data = PasteRegionData {
+            person_id: paste_data.person_id,
+            grid_position: paste_data.grid_position,
+            direction: paste_data.direction,
+        };
+
+        state
+            .action
+            .act_deque
+            .push_back(Act::PasteRegion(paste_region_data));
+    }
+
+    fn handle_replace_region_message(replace_data: &message::ReplaceData, state: &mut State) {
+        let replace_region_data = ReplaceRegionData {
+            person_id: replace_data.person_id,
+            int_box: Self::get_int_box(replace_data.min, replace_data.max),
+            mask_block_kind: replace_data.mask_block_kind.clone(),
+            block_kind_option: replace_data.block_kind_option.clone(),
+        };
+
+        state
+            .action
+            .act_deque
+            .push_back(Act::ReplaceRegion(replace_region_data));
+    }
+
+    fn handle_undo_message(person_input_data: &message::PersonInputData, state: &mut State) {
+        let undo_data = UndoData {
+            person_id: person_input_data.person_id,
+        };
+
+        state.action.act_deque.push_back(Act::Undo(undo_data));
+    }
+
+    fn handle_redo_message(person_input_data: &message::PersonInputData, state: &mut State) {
+        let redo_data = RedoData {
+            person_id: person_input_data.person_id,
+        };
+
+        state.action.act_deque.push_back(Act::Redo(redo_data));
+    }
+
+    fn handle_save_schematic_message(schematic_data: &message::SchematicData, state: &mut State) {
+        if !Schematic::name_is_valid(&schematic_data.name) {
+            tracing::error!("Invalid schematic name: {}", schematic_data.name);
+
+            return;
+        }
+
+        let int_box = Self::get_int_box(schematic_data.min, schematic_data.max);
+
+        let schematic = Schematic::from_region(&schematic_data.name, &int_box, &mut state.world);
+
+        let path = Path::new(SCHEMATIC_DIRECTORY)
+            .join(&schematic.name)
+            .with_extension(SCHEMATIC_EXTENSION);
+
+        match Schematic::write(&path, &schematic) {
+            Ok(()) => {
+                tracing::info!("Saved schematic to {}", path.display());
+
+                state
+                    .world
+                    .schematic_map
+                    .insert(schematic.name.clone(), schematic);
+            }
+            Err(error) => tracing::error!("Failed to save schematic: {}", error),
+        }
+    }
+
+    fn get_int_box(corner1: IVec3, corner2: IVec3) -> IntBox {
+        IntBox::new(
+            IVec3::new(
+                corner1.x.min(corner2.x),
+                corner1.y.min(corner2.y),
+                corner1.z.min(corner2.z),
+            ),
+            IVec3::new(
+                corner1.x.max(corner2.x),
+                corner1.y.max(corner2.y),
+                corner1.z.max(corner2.z),
+            ),
+        )
+    }
+
+    fn handle_spawn_message(spawn_data: &message::SpawnData, state: &mut State) {
+        for _ in 0..spawn_data.count {
+            if state.population.person_map.len() >= PERSON_MAX_COUNT {
+                tracing::info!("Person limit reached");
+
+                break;
+            }
+
+            let person =
+                Population::generate_citizen(&spawn_data.nation_kind, &mut state.population);
+
+            state.population.person_map.insert(person.person_id, person);
+        }
+    }
+
+    fn handle_add_judge_message(add_judge_data: &message::AddJudgeData, state: &mut State) {
+        if !PersonID::JUDGE_ID_ARRAY.contains(&add_judge_data.person_id) {
+            tracing::error!("{:?} is not a Judge ID", add_judge_data.person_id);
+
+            return;
+        }
+
+        if state
+            .population
+            .person_map
+            .contains_key(&add_judge_data.person_id)
+        {
+            tracing::info!("{:?} already exists", add_judge_data.person_id);
+
+            return;
+        }
+
+        Population::generate_judge(add_judge_data.person_id, &mut state.population);
+    }
+
+    fn handle_debug_message(person_input_data: &message::PersonInputData, state: &mut State) {
+        if let Some(judge) = state
+            .population
+            .person_map
+            .get_mut(&person_input_data.person_id)
+        {
             match judge.motion.mode {
                 motion::Mode::Ground | motion::Mode::Climb => {
                     judge.motion.mode = motion::Mode::Air;
@@ -266,24 +826,32 @@ impl Supervisor {
         }
     }
 
-    fn handle_option1_message(state: &mut State) {
-        if let Some(judge) = state.population.person_map.get_mut(&PersonID::JUDGE_ID_1) {
+    fn handle_option1_message(person_input_data: &message::PersonInputData, state: &mut State) {
+        if let Some(judge) = state
+            .population
+            .person_map
+            .get_mut(&person_input_data.person_id)
+        {
             judge.selected_block_kind =
                 BlockKind::get_previous_block_kind(&judge.selected_block_kind);
         }
     }
 
-    fn handle_option2_message(state: &mut State) {
-        if let Some(judge) = state.population.person_map.get_mut(&PersonID::JUDGE_ID_1) {
+    fn handle_option2_message(person_input_data: &message::PersonInputData, state: &mut State) {
+        if let Some(judge) = state
+            .population
+            .person_map
+            .get_mut(&person_input_data.person_id)
+        {
             judge.selected_block_kind = BlockKind::get_next_block_kind(&judge.selected_block_kind);
         }
     }
 
-    fn handle_option3_message(_state: &mut State) {
-        tracing::info!("Option 3 Message");
+    fn handle_option3_message(person_input_data: &message::PersonInputData, _state: &mut State) {
+        tracing::info!("Option 3 Message: {:?}", person_input_data.person_id);
     }
 
-    fn handle_option4_message(_state: &mut State) {
-        tracing::info!("Option 4 Message");
+    fn handle_option4_message(person_input_data: &message::PersonInputData, _state: &mut State) {
+        tracing::info!("Option 4 Message: {:?}", person_input_data.person_id);
     }
 }
@@ -0,0 +1,220 @@
+//! Line-delimited JSON control protocol for external scripts
+
+pub mod control_client;
+pub mod control_request;
+pub mod control_response;
+pub mod person_data;
+
+pub use control_client::ControlClient;
+pub use control_request::ControlRequest;
+pub use control_response::ControlResponse;
+pub use person_data::PersonData;
+
+use crate::simulation::{
+    constants::CONTROL_CLIENT_LIMIT,
+    state::{population::person::person_id::PersonID, State, World},
+    summary::Summary,
+    supervisor::Message,
+};
+use std::{
+    io,
+    net::{SocketAddr, TcpListener},
+};
+
+pub struct Control {
+    pub listener_option: Option<TcpListener>,
+    pub control_client_vec: Vec<ControlClient>,
+    pub message_vec: Vec<Message>,
+}
+
+impl Control {
+    pub fn new() -> Self {
+        let listener_option = None;
+        let control_client_vec = Vec::new();
+        let message_vec = Vec::new();
+
+        Self {
+            listener_option,
+            control_client_vec,
+            message_vec,
+        }
+    }
+
+    pub fn bind(address: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+
+        tracing::info!("Control listening on {}", listener.local_addr()?);
+
+        let mut control = Self::new();
+        control.listener_option = Some(listener);
+
+        Ok(control)
+    }
+
+    pub fn get_local_addr(control: &Self) -> Option<SocketAddr> {
+        control
+            .listener_option
+            .as_ref()
+            .and_then(|listener| listener.local_addr().ok())
+    }
+
+    pub fn has_subscriber(control: &Self) -> bool {
+        control
+            .control_client_vec
+            .iter()
+            .any(|control_client| control_client.subscribed)
+    }
+
+    pub fn tick(state: &State, control: &mut Self) {
+        if control.listener_option.is_none() {
+            return;
+        }
+
+        Self::accept_clients(control);
+
+        for control_client in &mut control.control_client_vec {
+            for line in ControlClient::read_line_vec(control_client) {
+                let control_response = Self::handle_request_line(
+                    &line,
+                    state,
+                    control_client,
+                    &mut control.message_vec,
+                );
+
+                ControlClient::send(&control_response, control_client);
+            }
+
+            ControlClient::flush(control_client);
+        }
+
+        control
+            .control_client_vec
+            .retain(|control_client| control_client.connected);
+    }
+
+    pub fn take_message_vec(control: &mut Self) -> Vec<Message> {
+        std::mem::take(&mut control.message_vec)
+    }
+
+    pub fn publish_summary(summary: &Summary, control: &mut Self) {
+        let control_response = ControlResponse::Summary {
+            summary: summary.clone(),
+        };
+
+        for control_client in &mut control.control_client_vec {
+            if control_client.subscribed {
+                ControlClient::send(&control_response, control_client);
+            }
+        }
+    }
+
+    fn accept_clients(control: &mut Self) {
+        let Some(listener) = control.listener_option.as_ref() else {
+            return;
+        };
+
+        loop {
+            match listener.accept() {
+                Ok((stream, socket_addr)) => {
+                    if control.control_client_vec.len() >= CONTROL_CLIENT_LIMIT {
+                        tracing::error!("Control client limit reached, rejecting {}", socket_addr);
+
+                        continue;
+                    }
+
+                    match ControlClient::new(stream) {
+                        Ok(control_client) => {
+                            tracing::info!("Control client connected: {}", socket_addr);
+
+                            control.control_client_vec.push(control_client);
+                        }
+                        Err(error) => tracing::error!("Control client setup failed: {}", error),
+                    }
+                }
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) => {
+                    tracing::error!("Control accept failed: {}", error);
+
+                    break;
+                }
+            }
+        }
+    }
+
+    fn handle_request_line(
+        line: &str,
+        state: &State,
+        control_client: &mut ControlClient,
+        message_vec: &mut Vec<Message>,
+    ) -> ControlResponse {
+        let control_request = match serde_json::from_str::<ControlRequest>(line) {
+            Ok(control_request) => control_request,
+            Err(error) => {
+                return ControlResponse::Error {
+                    error: format!("invalid request: {error}"),
+                }
+            }
+        };
+
+        match control_request {
+            ControlRequest::Message {
+                message: Message::Save(_) | Message::Load(_),
+            } => ControlResponse::Error {
+                error: String::from("save and load are local only"),
+            },
+            ControlRequest::Message { message } => {
+                message_vec.push(message);
+
+                ControlResponse::Ok
+            }
+            ControlRequest::GetJudge { person_id } => {
+                let person_id = person_id.unwrap_or(PersonID::JUDGE_ID_1);
+
+                match state.population.person_map.get(&person_id) {
+                    Some(judge) => ControlResponse::Judge {
+                        person_data: PersonData::new(judge),
+                    },
+                    None => ControlResponse::Error {
+                        error: String::from("judge not found"),
+                    },
+                }
+            }
+            ControlRequest::GetPersons => {
+                let person_data_vec = state
+                    .population
+                    .person_map
+                    .values()
+                    .map(PersonData::new)
+                    .collect();
+
+                ControlResponse::Persons { person_data_vec }
+            }
+            ControlRequest::GetBlock { grid_position } => {
+                let block_kind_option = World::get_block(grid_position, &state.world.sector_map)
+                    .map(|block| block.block_kind.clone());
+
+                ControlResponse::Block {
+                    grid_position,
+                    block_kind_option,
+                }
+            }
+            ControlRequest::Subscribe => {
+                control_client.subscribed = true;
+
+                ControlResponse::Ok
+            }
+            ControlRequest::Unsubscribe => {
+                control_client.subscribed = false;
+
+                ControlResponse::Ok
+            }
+        }
+    }
+}
+
+impl Default for Control {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,119 @@
+use crate::simulation::{
+    constants::{CONTROL_READ_BUFFER_LIMIT, CONTROL_WRITE_BUFFER_LIMIT},
+    supervisor::control::ControlResponse,
+};
+use std::{
+    io::{self, Read, Write},
+    net::TcpStream,
+};
+
+pub struct ControlClient {
+    pub stream: TcpStream,
+    pub connected: bool,
+    pub subscribed: bool,
+    pub read_buffer: Vec<u8>,
+    pub write_buffer: Vec<u8>,
+}
+
+impl ControlClient {
+    pub fn new(stream: TcpStream) -> io::Result<Self> {
+        stream.set_nonblocking(true)?;
+        stream.set_nodelay(true)?;
+
+        let control_client = Self {
+            stream,
+            connected: true,
+            subscribed: false,
+            read_buffer: Vec::new(),
+            write_buffer: Vec::new(),
+        };
+
+        Ok(control_client)
+    }
+
+    pub fn read_line_vec(control_client: &mut Self) -> Vec<String> {
+        let mut chunk = [0u8; 4096];
+
+        loop {
+            match control_client.stream.read(&mut chunk) {
+                Ok(0) => {
+                    control_client.connected = false;
+
+                    break;
+                }
+                Ok(byte_count) => control_client
+                    .read_buffer
+                    .extend_from_slice(&chunk[..byte_count]),
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => {
+                    control_client.connected = false;
+
+                    break;
+                }
+            }
+        }
+
+        let mut line_vec = Vec::new();
+
+        while let Some(newline_index) = control_client
+            .read_buffer
+            .iter()
+            .position(|byte| *byte == b'\n')
+        {
+            let line_bytes: Vec<u8> = control_client.read_buffer.drain(..=newline_index).collect();
+            let line = String::from_utf8_lossy(&line_bytes).trim().to_string();
+
+            if !line.is_empty() {
+                line_vec.push(line);
+            }
+        }
+
+        if control_client.read_buffer.len() > CONTROL_READ_BUFFER_LIMIT {
+            tracing::error!("Control request exceeds read buffer limit");
+
+            control_client.connected = false;
+        }
+
+        line_vec
+    }
+
+    pub fn send(control_response: &ControlResponse, control_client: &mut Self) {
+        let line = serde_json::to_string(control_response).expect("Failed to serialize response");
+
+        control_client
+            .write_buffer
+            .extend_from_slice(line.as_bytes());
+        control_client.write_buffer.push(b'\n');
+
+        Self::flush(control_client);
+
+        if control_client.write_buffer.len() > CONTROL_WRITE_BUFFER_LIMIT {
+            tracing::error!("Control client is not reading responses");
+
+            control_client.connected = false;
+        }
+    }
+
+    pub fn flush(control_client: &mut Self) {
+        while !control_client.write_buffer.is_empty() {
+            match control_client.stream.write(&control_client.write_buffer) {
+                Ok(0) => {
+                    control_client.connected = false;
+
+                    break;
+                }
+                Ok(byte_count) => {
+                    control_client.write_buffer.drain(..byte_count);
+                }
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(_) => {
+                    control_client.connected = false;
+
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use crate::simulation::{state::population::person::person_id::PersonID, supervisor::Message};
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ControlRequest {
+    Message { message: Message },
+    GetJudge { person_id: Option<PersonID> },
+    GetPersons,
+    GetBlock { grid_position: IVec3 },
+    Subscribe,
+    Unsubscribe,
+}
@@ -0,0 +1,27 @@
+use crate::simulation::{
+    state::world::block::block_kind::BlockKind, summary::Summary, supervisor::control::PersonData,
+};
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ControlResponse {
+    Ok,
+    Error {
+        error: String,
+    },
+    Judge {
+        person_data: PersonData,
+    },
+    Persons {
+        person_data_vec: Vec<PersonData>,
+    },
+    Block {
+        grid_position: IVec3,
+        block_kind_option: Option<BlockKind>,
+    },
+    Summary {
+        summary: Summary,
+    },
+}
@@ -0,0 +1,27 @@
+use crate::simulation::state::population::{
+    nation::nation_kind::NationKind,
+    person::{person_id::PersonID, Person},
+};
+use serde::{Deserialize, Serialize};
+use ultraviolet::Vec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PersonData {
+    pub person_id: PersonID,
+    pub nation_kind: NationKind,
+    pub world_position: Vec3,
+    pub velocity: Vec3,
+    pub rotation_xy: f32,
+}
+
+impl PersonData {
+    pub fn new(person: &Person) -> Self {
+        Self {
+            person_id: person.person_id,
+            nation_kind: person.identity.nation_kind,
+            world_position: person.transform.world_position,
+            velocity: person.motion.velocity,
+            rotation_xy: person.transform.rotation_xy,
+        }
+    }
+}
@@ -1,24 +1,76 @@
+pub mod add_judge_data;
+pub mod fill_data;
+pub mod load_data;
 pub mod move_input_data;
+pub mod paste_data;
+pub mod person_input_data;
+pub mod region_data;
+pub mod replace_data;
 pub mod rotate_input_data;
+pub mod save_data;
+pub mod schematic_data;
+pub mod schematic_set_data;
 pub mod seed_data;
+pub mod set_block_data;
+pub mod spawn_data;
+pub mod teleport_data;
+pub mod time_scale_data;
 
+pub use add_judge_data::AddJudgeData;
+pub use fill_data::FillData;
+pub use load_data::LoadData;
 pub use move_input_data::MoveInputData;
+pub use paste_data::PasteData;
+pub use person_input_data::PersonInputData;
+pub use region_data::RegionData;
+pub use replace_data::ReplaceData;
 pub use rotate_input_data::RotateInputData;
+pub use save_data::SaveData;
+pub use schematic_data::SchematicData;
+pub use schematic_set_data::SchematicSetData;
 pub use seed_data::SeedData;
+pub use set_block_data::SetBlockData;
+pub use spawn_data::SpawnData;
+pub use teleport_data::TeleportData;
+pub use time_scale_data::TimeScaleData;
 
-#[derive(Debug)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Message {
-    Interact1,
-    Interact2,
+    Interact1(PersonInputData),
+    Interact2(PersonInputData),
     MoveInput(MoveInputData),
     RotateInput(RotateInputData),
-    JumpInput,
+    JumpInput(PersonInputData),
     SetSeed(SeedData),
+    SetSchematics(SchematicSetData),
     Generate,
+    Save(SaveData),
+    Load(LoadData),
     Quit,
-    Debug,
-    Option1,
-    Option2,
-    Option3,
-    Option4,
+    TogglePause,
+    Step,
+    SlowDown,
+    SpeedUp,
+    SetTimeScale(TimeScaleData),
+    ToggleUnbounded,
+    Teleport(TeleportData),
+    SetBlock(SetBlockData),
+    Fill(FillData),
+    Spawn(SpawnData),
+    AddJudge(AddJudgeData),
+    Debug(PersonInputData),
+    Option1(PersonInputData),
+    Option2(PersonInputData),
+    Option3(PersonInputData),
+    Option4(PersonInputData),
+    CopyRegion(RegionData),
+    PasteRegion(PasteData),
+    ReplaceRegion(ReplaceData),
+    Undo(PersonInputData),
+    Redo(PersonInputData),
+    SaveSchematic(SchematicData),
+    Door(PersonInputData),
+    LockDoor(PersonInputData),
 }
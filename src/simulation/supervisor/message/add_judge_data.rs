@@ -0,0 +1,7 @@
+use crate::simulation::state::population::person::person_id::PersonID;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AddJudgeData {
+    pub person_id: PersonID,
+}
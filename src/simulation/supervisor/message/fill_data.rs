@@ -0,0 +1,15 @@
+use crate::simulation::state::{
+    population::person::person_id::PersonID,
+    world::{block::block_kind::BlockKind, grid::Direction},
+};
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FillData {
+    pub person_id: PersonID,
+    pub min: IVec3,
+    pub max: IVec3,
+    pub block_kind_option: Option<BlockKind>,
+    pub direction: Direction,
+}
@@ -1,6 +1,12 @@
-#[derive(Debug)]
+use crate::simulation::state::population::person::person_id::PersonID;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MoveInputData {
+    pub person_id: PersonID,
     pub input_x: f32,
     pub input_y: f32,
     pub input_z: f32,
+    pub sprint: bool,
+    pub crouch: bool,
 }
@@ -0,0 +1,10 @@
+use crate::simulation::state::population::person::person_id::PersonID;
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RegionData {
+    pub person_id: PersonID,
+    pub min: IVec3,
+    pub max: IVec3,
+}
@@ -0,0 +1,14 @@
+use crate::simulation::state::{
+    population::person::person_id::PersonID, world::block::block_kind::BlockKind,
+};
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReplaceData {
+    pub person_id: PersonID,
+    pub min: IVec3,
+    pub max: IVec3,
+    pub mask_block_kind: BlockKind,
+    pub block_kind_option: Option<BlockKind>,
+}
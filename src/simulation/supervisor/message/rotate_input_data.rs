@@ -1,5 +1,9 @@
-#[derive(Debug)]
+use crate::simulation::state::population::person::person_id::PersonID;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RotateInputData {
+    pub person_id: PersonID,
     pub input_x: f32,
     pub input_y: f32,
     pub input_z: f32,
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use ultraviolet::IVec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SchematicData {
+    pub name: String,
+    pub min: IVec3,
+    pub max: IVec3,
+}
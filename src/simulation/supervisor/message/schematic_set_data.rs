@@ -0,0 +1,8 @@
+use crate::simulation::state::world::schematic::Schematic;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SchematicSetData {
+    pub schematic_map: BTreeMap<String, Schematic>,
+}
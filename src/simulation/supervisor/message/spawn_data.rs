@@ -0,0 +1,8 @@
+use crate::simulation::state::population::nation::nation_kind::NationKind;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SpawnData {
+    pub nation_kind: NationKind,
+    pub count: u32,
+}
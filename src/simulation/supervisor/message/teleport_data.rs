@@ -0,0 +1,9 @@
+use crate::simulation::state::population::person::person_id::PersonID;
+use serde::{Deserialize, Serialize};
+use ultraviolet::Vec3;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TeleportData {
+    pub person_id: PersonID,
+    pub world_position: Vec3,
+}
@@ -0,0 +1,134 @@
+//! Message recording and replay
+
+pub mod recorder_mode;
+pub mod replay_entry;
+
+pub use recorder_mode::RecorderMode;
+pub use replay_entry::ReplayEntry;
+
+use crate::simulation::{
+    constants::{REPLAY_FORMAT_MAGIC, REPLAY_FORMAT_VERSION},
+    supervisor::Message,
+};
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+pub struct Recorder {
+    pub recorder_mode: RecorderMode,
+    pub replay_entry_vec: Vec<ReplayEntry>,
+    pub replay_index: usize,
+}
+
+impl Recorder {
+    pub fn new(recorder_mode: RecorderMode) -> io::Result<Self> {
+        let replay_entry_vec = match &recorder_mode {
+            RecorderMode::Replay(path) => Self::read(path)?,
+            RecorderMode::Off | RecorderMode::Record(_) => Vec::new(),
+        };
+
+        let replay_index = 0;
+
+        let recorder = Self {
+            recorder_mode,
+            replay_entry_vec,
+            replay_index,
+        };
+
+        Ok(recorder)
+    }
+
+    pub fn record(tick: u32, message: &Message, recorder: &mut Self) {
+        if let RecorderMode::Record(_) = recorder.recorder_mode {
+            let replay_entry = ReplayEntry {
+                tick,
+                message: message.clone(),
+            };
+
+            recorder.replay_entry_vec.push(replay_entry);
+        }
+    }
+
+    pub fn is_replaying(recorder: &Self) -> bool {
+        matches!(recorder.recorder_mode, RecorderMode::Replay(_))
+    }
+
+    pub fn get_replay_message_vec(tick: u32, recorder: &mut Self) -> Vec<Message> {
+        let mut message_vec = Vec::new();
+
+        while let Some(replay_entry) = recorder.replay_entry_vec.get(recorder.replay_index) {
+            if replay_entry.tick > tick {
+                break;
+            }
+
+            message_vec.push(replay_entry.message.clone());
+            recorder.replay_index += 1;
+        }
+
+        if recorder.replay_index >= recorder.replay_entry_vec.len() {
+            tracing::info!("Replay Complete");
+
+            recorder.recorder_mode = RecorderMode::Off;
+        }
+
+        message_vec
+    }
+
+    pub fn finish(recorder: &Self) {
+        if let RecorderMode::Record(path) = &recorder.recorder_mode {
+            match Self::write(path, &recorder.replay_entry_vec) {
+                Ok(()) => tracing::info!("Saved Replay: {:?}", path),
+                Err(error) => tracing::error!("Replay Save Failed: {:?} {}", path, error),
+            }
+        }
+    }
+
+    pub fn write(path: &Path, replay_entry_vec: &[ReplayEntry]) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        writer.write_all(&REPLAY_FORMAT_MAGIC)?;
+        writer.write_all(&REPLAY_FORMAT_VERSION.to_le_bytes())?;
+
+        bincode::serialize_into(&mut writer, replay_entry_vec)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        writer.flush()
+    }
+
+    pub fn read(path: &Path) -> io::Result<Vec<ReplayEntry>> {
+        let mut reader = BufReader::new(File::open(path)?);
+
+        let mut magic = [0u8; 4];
+        reader.read_exact(&mut magic)?;
+
+        if magic != REPLAY_FORMAT_MAGIC {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "not a replay file",
+            ));
+        }
+
+        let mut version_bytes = [0u8; 4];
+        reader.read_exact(&mut version_bytes)?;
+
+        let version = u32::from_le_bytes(version_bytes);
+
+        if version != REPLAY_FORMAT_VERSION {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("unsupported replay version {version} (expected {REPLAY_FORMAT_VERSION})"),
+            ));
+        }
+
+        let replay_entry_vec = bincode::deserialize_from(&mut reader)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        Ok(replay_entry_vec)
+    }
+}
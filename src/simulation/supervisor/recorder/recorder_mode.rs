@@ -0,0 +1,9 @@
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum RecorderMode {
+    #[default]
+    Off,
+    Record(PathBuf),
+    Replay(PathBuf),
+}
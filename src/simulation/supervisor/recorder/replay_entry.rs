@@ -0,0 +1,8 @@
+use crate::simulation::supervisor::Message;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReplayEntry {
+    pub tick: u32,
+    pub message: Message,
+}
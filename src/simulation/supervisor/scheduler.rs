@@ -1,7 +1,101 @@
-pub struct Scheduler {}
+//! Timed execution of Commands
+
+pub mod command;
+pub mod job;
+
+pub use command::Command;
+pub use job::Job;
+
+use crate::simulation::state::State;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::instrument;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Scheduler {
+    pub next_job_id: u64,
+    pub job_map: BTreeMap<(u64, u64), Job>,
+}
 
 impl Scheduler {
     pub fn new() -> Self {
-        Self {}
+        let next_job_id = 0;
+        let job_map = BTreeMap::new();
+
+        Self {
+            next_job_id,
+            job_map,
+        }
+    }
+
+    pub fn schedule(tick: u64, command: Command, scheduler: &mut Self) -> u64 {
+        Self::insert_job(tick, None, command, scheduler)
+    }
+
+    pub fn schedule_repeating(
+        tick: u64,
+        interval: u64,
+        command: Command,
+        scheduler: &mut Self,
+    ) -> u64 {
+        Self::insert_job(tick, Some(interval.max(1)), command, scheduler)
+    }
+
+    pub fn cancel(job_id: u64, scheduler: &mut Self) -> bool {
+        let key_option = scheduler
+            .job_map
+            .iter()
+            .find(|(_, job)| job.job_id == job_id)
+            .map(|(key, _)| *key);
+
+        if let Some(key) = key_option {
+            scheduler.job_map.remove(&key);
+
+            true
+        } else {
+            false
+        }
+    }
+
+    #[instrument(skip_all)]
+    pub fn tick(state: &mut State, scheduler: &mut Self) {
+        let current_tick = state.world.time.tick;
+
+        while let Some(entry) = scheduler.job_map.first_entry() {
+            if entry.key().0 > current_tick {
+                break;
+            }
+
+            let mut job = entry.remove();
+
+            Command::execute(&job.command, state);
+
+            if let Some(interval) = job.interval_option {
+                job.tick = (job.tick + interval).max(current_tick + 1);
+
+                scheduler.job_map.insert((job.tick, job.job_id), job);
+            }
+        }
+    }
+
+    fn insert_job(
+        tick: u64,
+        interval_option: Option<u64>,
+        command: Command,
+        scheduler: &mut Self,
+    ) -> u64 {
+        let job_id = scheduler.next_job_id;
+        scheduler.next_job_id += 1;
+
+        let job = Job {
+            job_id,
+            tick,
+            interval_option,
+            command,
+        };
+
+        scheduler.job_map.insert((tick, job_id), job);
+
+        job_id
     }
 }
@@ -1,3 +1,24 @@
-pub struct Command {
-    
-}
\ No newline at end of file
+use crate::simulation::state::{
+    action::act::Act,
+    work::{construct_task::ConstructTask, construct_worker::ConstructWorker},
+    State,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub enum Command {
+    EnqueueAct(Act),
+    EnqueueConstructTask(ConstructTask),
+}
+
+impl Command {
+    pub fn execute(command: &Self, state: &mut State) {
+        match command {
+            Command::EnqueueAct(act) => state.action.act_deque.push_back(act.clone()),
+            Command::EnqueueConstructTask(construct_task) => ConstructWorker::enqueue(
+                construct_task.clone(),
+                &mut state.work.construct_worker.task_deque,
+            ),
+        }
+    }
+}
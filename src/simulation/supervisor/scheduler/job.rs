@@ -0,0 +1,10 @@
+use crate::simulation::supervisor::scheduler::command::Command;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Job {
+    pub job_id: u64,
+    pub tick: u64,
+    pub interval_option: Option<u64>,
+    pub command: Command,
+}
@@ -0,0 +1,239 @@
+//! Authoritative host for remote clients
+
+pub mod server_client;
+
+pub use server_client::ServerClient;
+
+use crate::simulation::{
+    constants::NETWORK_CLIENT_LIMIT,
+    network::{ClientPacket, FrameStream, ServerPacket, ViewPacket},
+    state::{population::person::person_id::PersonID, State},
+    supervisor::{message::AddJudgeData, viewer::view::SupervisorView, Message, Viewer},
+};
+use std::{
+    io,
+    net::{SocketAddr, TcpListener},
+};
+
+pub struct Server {
+    pub listener_option: Option<TcpListener>,
+    pub server_client_vec: Vec<ServerClient>,
+    pub message_vec: Vec<Message>,
+}
+
+impl Server {
+    pub fn new() -> Self {
+        let listener_option = None;
+        let server_client_vec = Vec::new();
+        let message_vec = Vec::new();
+
+        Self {
+            listener_option,
+            server_client_vec,
+            message_vec,
+        }
+    }
+
+    pub fn bind(address: &str) -> io::Result<Self> {
+        let listener = TcpListener::bind(address)?;
+        listener.set_nonblocking(true)?;
+
+        tracing::info!("Server listening on {}", listener.local_addr()?);
+
+        let mut server = Self::new();
+        server.listener_option = Some(listener);
+
+        Ok(server)
+    }
+
+    pub fn is_active(server: &Self) -> bool {
+        server.listener_option.is_some()
+    }
+
+    pub fn get_local_addr(server: &Self) -> Option<SocketAddr> {
+        server
+            .listener_option
+            .as_ref()
+            .and_then(|listener| listener.local_addr().ok())
+    }
+
+    pub fn take_message_vec(server: &mut Self) -> Vec<Message> {
+        std::mem::take(&mut server.message_vec)
+    }
+
+    pub fn invalidate_world_views(server: &mut Self) {
+        for server_client in &mut server.server_client_vec {
+            Viewer::invalidate_sector_versions(&mut server_client.sector_version_map);
+        }
+    }
+
+    pub fn receive(state: &State, viewer: &Viewer, server: &mut Self) {
+        if !Self::is_active(server) {
+            return;
+        }
+
+        Self::accept_clients(viewer, server);
+
+        for server_client in &mut server.server_client_vec {
+            for client_packet in
+                FrameStream::receive::<ClientPacket>(&mut server_client.frame_stream)
+            {
+                match client_packet {
+                    ClientPacket::Message(message) => {
+                        if let Some(message) =
+                            Self::authorize_message(message, server_client.person_id)
+                        {
+                            server.message_vec.push(message);
+                        }
+                    }
+                }
+            }
+
+            let judge_required = state
+                .population
+                .person_map
+                .contains_key(&PersonID::JUDGE_ID_1)
+                && !state
+                    .population
+                    .person_map
+                    .contains_key(&server_client.person_id);
+
+            if judge_required {
+                let add_judge_data = AddJudgeData {
+                    person_id: server_client.person_id,
+                };
+
+                server.message_vec.push(Message::AddJudge(add_judge_data));
+            }
+        }
+
+        server.server_client_vec.retain(|server_client| {
+            if !server_client.frame_stream.connected {
+                tracing::info!("Client disconnected: {:?}", server_client.person_id);
+            }
+
+            server_client.frame_stream.connected
+        });
+    }
+
+    pub fn publish(state: &State, supervisor_view: &SupervisorView, server: &mut Self) {
+        for server_client in &mut server.server_client_vec {
+            let population_view = Viewer::update_population_view(&server_client.person_id, state);
+
+            let world_view_delta = Viewer::update_world_view_delta(
+                &server_client.person_id,
+                state,
+                &mut server_client.sector_version_map,
+            );
+
+            server_client.sent_sector_count += world_view_delta.sector_view_vec.len();
+
+            let view_packet = ViewPacket {
+                supervisor_view: supervisor_view.clone(),
+                population_view,
+                world_view_delta,
+            };
+
+            FrameStream::send(
+                &ServerPacket::View(view_packet),
+                &mut server_client.frame_stream,
+            );
+        }
+    }
+
+    fn accept_clients(viewer: &Viewer, server: &mut Self) {
+        let Some(listener) = server.listener_option.as_ref() else {
+            return;
+        };
+
+        loop {
+            match listener.accept() {
+                Ok((stream, socket_addr)) => {
+                    let mut frame_stream = match FrameStream::new(stream) {
+                        Ok(frame_stream) => frame_stream,
+                        Err(error) => {
+                            tracing::error!("Client setup failed: {}", error);
+
+                            continue;
+                        }
+                    };
+
+                    let person_id_option = PersonID::JUDGE_ID_ARRAY.into_iter().find(|person_id| {
+                        !viewer.view_input_map.contains_key(person_id)
+                            && !server
+                                .server_client_vec
+                                .iter()
+                                .any(|server_client| server_client.person_id == *person_id)
+                    });
+
+                    match person_id_option {
+                        Some(person_id)
+                            if server.server_client_vec.len() < NETWORK_CLIENT_LIMIT =>
+                        {
+                            tracing::info!("Client connected: {} as {:?}", socket_addr, person_id);
+
+                            FrameStream::send(&ServerPacket::Welcome(person_id), &mut frame_stream);
+
+                            server
+                                .server_client_vec
+                                .push(ServerClient::new(person_id, frame_stream));
+                        }
+                        _ => {
+                            tracing::info!("Client rejected: {}", socket_addr);
+
+                            FrameStream::send(
+                                &ServerPacket::Reject(String::from("No Judge available")),
+                                &mut frame_stream,
+                            );
+                        }
+                    }
+                }
+                Err(error) if error.kind() == io::ErrorKind::WouldBlock => break,
+                Err(error) => {
+                    tracing::error!("Server accept failed: {}", error);
+
+                    break;
+                }
+            }
+        }
+    }
+
+    fn authorize_message(mut message: Message, person_id: PersonID) -> Option<Message> {
+        let host = person_id == PersonID::JUDGE_ID_1;
+
+        match &mut message {
+            Message::Interact1(person_input_data)
+            | Message::Interact2(person_input_data)
+            | Message::JumpInput(person_input_data)
+            | Message::Debug(person_input_data)
+            | Message::Option1(person_input_data)
+            | Message::Option2(person_input_data)
+            | Message::Option3(person_input_data)
+            | Message::Option4(person_input_data)
+            | Message::Door(person_input_data)
+            | Message::LockDoor(person_input_data) => person_input_data.person_id = person_id,
+            Message::MoveInput(move_input_data) => move_input_data.person_id = person_id,
+            Message::RotateInput(rotate_input_data) => rotate_input_data.person_id = person_id,
+            Message::Teleport(teleport_data) if host => teleport_data.person_id = person_id,
+            Message::SetBlock(set_block_data) if host => set_block_data.person_id = person_id,
+            Message::Fill(fill_data) if host => fill_data.person_id = person_id,
+            Message::CopyRegion(region_data) if host => region_data.person_id = person_id,
+            Message::PasteRegion(paste_data) if host => paste_data.person_id = person_id,
+            Message::ReplaceRegion(replace_data) if host => replace_data.person_id = person_id,
+            Message::Undo(person_input_data) | Message::Redo(person_input_data) if host => {
+                person_input_data.person_id = person_id
+            }
+            Message::Quit | Message::Save(_) | Message::Load(_) => return None,
+            _ if host => (),
+            _ => return None,
+        }
+
+        Some(message)
+    }
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self::new()
+    }
+}
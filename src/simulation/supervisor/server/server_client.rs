@@ -0,0 +1,23 @@
+use crate::simulation::{
+    network::FrameStream,
+    state::{population::person::person_id::PersonID, world::sector::sector_index::SectorIndex},
+};
+use std::collections::HashMap;
+
+pub struct ServerClient {
+    pub person_id: PersonID,
+    pub frame_stream: FrameStream,
+    pub sector_version_map: HashMap<SectorIndex, u64>,
+    pub sent_sector_count: usize,
+}
+
+impl ServerClient {
+    pub fn new(person_id: PersonID, frame_stream: FrameStream) -> Self {
+        Self {
+            person_id,
+            frame_stream,
+            sector_version_map: HashMap::new(),
+            sent_sector_count: 0,
+        }
+    }
+}
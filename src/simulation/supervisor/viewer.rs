@@ -1,38 +1,78 @@
-//! Simulation View producer
-
 pub mod face_mask;
 pub mod view;
+pub mod view_input;
+pub mod view_output;
+
+pub use view_input::ViewInput;
+pub use view_output::ViewOutput;
 
 use crate::simulation::{
     state::{
         population::person::person_id::PersonID,
-        world::{
-            grid::{self},
-            sector::{sector_index::SectorIndex, Sector},
-        },
+        world::{grid, sector::sector_index::SectorIndex},
         State, World,
     },
     supervisor::{
-        viewer::view::{PersonView, PopulationView, SectorView, SupervisorView, View, WorldView},
+        viewer::view::{
+            PersonView, PopulationView, SectorView, SupervisorView, View, WorldView, WorldViewDelta,
+        },
         Supervisor,
     },
 };
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use tracing::instrument;
 use ultraviolet::IVec3;
 
 pub struct Viewer {
-    pub view_input: triple_buffer::Input<View>,
-    pub sector_version_map: HashMap<SectorIndex, u64>,
-    pub sector_view_cache: HashMap<SectorIndex, SectorView>,
+    pub view_input_map: BTreeMap<PersonID, ViewInput>,
 }
 
 impl Viewer {
-    pub fn new(view_input: triple_buffer::Input<View>) -> Self {
-        Self {
-            view_input,
+    pub fn new(view_input: ViewInput) -> Self {
+        let view_input_map = BTreeMap::from([(PersonID::JUDGE_ID_1, view_input)]);
+
+        Self { view_input_map }
+    }
+
+    pub fn create_view_channel() -> (ViewInput, ViewOutput) {
+        let (view_buffer_input, view_buffer_output) =
+            triple_buffer::triple_buffer(&Default::default());
+
+        let (world_view_delta_tx, world_view_delta_rx) =
+            crossbeam::channel::unbounded::<WorldViewDelta>();
+
+        let view_input = ViewInput {
+            view_buffer: view_buffer_input,
+            world_view_delta_tx,
             sector_version_map: HashMap::new(),
-            sector_view_cache: HashMap::new(),
+        };
+
+        let view_output = ViewOutput {
+            view_buffer: view_buffer_output,
+            world_view_delta_rx,
+            world_view: WorldView::default(),
+        };
+
+        (view_input, view_output)
+    }
+
+    pub fn attach(person_id: PersonID, view_input: ViewInput, viewer: &mut Self) {
+        viewer.view_input_map.insert(person_id, view_input);
+    }
+
+    pub fn detach(person_id: &PersonID, viewer: &mut Self) {
+        viewer.view_input_map.remove(person_id);
+    }
+
+    pub fn invalidate_world_views(viewer: &mut Self) {
+        for view_input in viewer.view_input_map.values_mut() {
+            Self::invalidate_sector_versions(&mut view_input.sector_version_map);
+        }
+    }
+
+    pub fn invalidate_sector_versions(sector_version_map: &mut HashMap<SectorIndex, u64>) {
+        for version in sector_version_map.values_mut() {
+            *version = u64::MAX;
         }
     }
 
@@ -40,46 +80,62 @@ impl Viewer {
     pub fn tick(state: &State, supervisor: &mut Supervisor) {
         let supervisor_view = Self::update_supervisor_view(supervisor);
 
-        let population_view = Self::update_population_view(state);
+        let viewer = &mut supervisor.viewer;
+
+        for (person_id, view_input) in &mut viewer.view_input_map {
+            let population_view = Self::update_population_view(person_id, state);
+
+            let world_view_delta =
+                Self::update_world_view_delta(person_id, state, &mut view_input.sector_version_map);
 
-        let world_view = Self::update_world_view(
-            state,
-            &mut supervisor.viewer.sector_version_map,
-            &mut supervisor.viewer.sector_view_cache,
-        );
+            let view = view_input.view_buffer.input_buffer_mut();
 
-        let view = supervisor.viewer.view_input.input_buffer_mut();
+            view.supervisor_view = supervisor_view.clone();
+            view.population_view = population_view;
 
-        view.supervisor_view = supervisor_view;
-        view.population_view = population_view;
-        view.world_view = world_view;
+            view_input.view_buffer.publish();
 
-        supervisor.viewer.view_input.publish();
+            if !WorldViewDelta::is_empty(&world_view_delta) {
+                let _ = view_input.world_view_delta_tx.send(world_view_delta);
+            }
+        }
     }
 
     #[instrument(skip_all)]
-    pub fn get_view(view_output: &mut triple_buffer::Output<View>) -> &View {
-        view_output.update();
+    pub fn get_view(view_output: &mut ViewOutput) -> (&View, &WorldView) {
+        view_output.view_buffer.update();
+
+        while let Ok(world_view_delta) = view_output.world_view_delta_rx.try_recv() {
+            WorldView::apply_delta(world_view_delta, &mut view_output.world_view);
+        }
 
-        let view = view_output.peek_output_buffer();
+        let view = view_output.view_buffer.peek_output_buffer();
 
-        &view
+        (view, &view_output.world_view)
     }
 
     #[instrument(skip_all)]
-    fn update_supervisor_view(supervisor: &Supervisor) -> SupervisorView {
+    pub fn update_supervisor_view(supervisor: &Supervisor) -> SupervisorView {
         let supervisor_view = SupervisorView {
             supervisor_status: supervisor.supervisor_status,
+            checksum_tick: supervisor.checksum_tick,
+            checksum: supervisor.checksum,
+            paused: supervisor.paused,
+            time_scale: supervisor.time_scale,
+            unbounded: supervisor.unbounded,
+            tick_rate: supervisor.tick_rate,
         };
 
         supervisor_view
     }
 
     #[instrument(skip_all)]
-    fn update_population_view(state: &State) -> PopulationView {
+    pub fn update_population_view(person_id: &PersonID, state: &State) -> PopulationView {
         let mut population_view = PopulationView::default();
 
-        if let Some(judge) = state.population.person_map.get(&PersonID::JUDGE_ID_1) {
+        population_view.leadership_view.judge_id = *person_id;
+
+        if let Some(judge) = state.population.person_map.get(person_id) {
             let judge_sight_range_squared = judge.sight.range_in_meters.powi(2);
 
             for person in state.population.person_map.values() {
@@ -107,15 +163,10 @@ impl Viewer {
         population_view
     }
 
-    #[instrument(skip_all)]
-    fn update_world_view(
-        state: &State,
-        sector_version_map: &mut HashMap<SectorIndex, u64>,
-        sector_view_cache: &mut HashMap<SectorIndex, SectorView>,
-    ) -> WorldView {
-        let mut world_view = WorldView::default();
+    pub fn get_visible_sector_index_vec(person_id: &PersonID, state: &State) -> Vec<SectorIndex> {
+        let mut sector_index_vec = Vec::new();
 
-        if let Some(judge) = state.population.person_map.get(&PersonID::JUDGE_ID_1) {
+        if let Some(judge) = state.population.person_map.get(person_id) {
             let judge_sector_coordinate =
                 grid::world_position_to_sector_coordinate(judge.transform.world_position);
 
@@ -126,48 +177,65 @@ impl Viewer {
                     for dx in -sight_range..=sight_range {
                         let sector_coordinate = judge_sector_coordinate + IVec3::new(dx, dy, dz);
 
-                        if grid::sector_coordinate_is_valid(sector_coordinate) {
-                            let sector_index =
-                                grid::sector_coordinate_to_sector_index(sector_coordinate);
-
-                            let sector =
-                                World::get_sector_by_index(&sector_index, &state.world.sector_vec);
+                        let sector_index =
+                            grid::sector_coordinate_to_sector_index(sector_coordinate);
 
-                            let sector_view = Self::get_sector_view(
-                                sector,
-                                sector_version_map,
-                                sector_view_cache,
-                            );
-
-                            world_view
-                                .sector_view_map
-                                .insert(sector.sector_index, sector_view);
+                        if state.world.sector_map.contains_key(&sector_index) {
+                            sector_index_vec.push(sector_index);
                         }
                     }
                 }
             }
         }
 
-        world_view
+        sector_index_vec
     }
 
     #[instrument(skip_all)]
-    fn get_sector_view(
-        sector: &Sector,
+    pub fn update_world_view_delta(
+        person_id: &PersonID,
+        state: &State,
         sector_version_map: &mut HashMap<SectorIndex, u64>,
-        sector_view_cache: &mut HashMap<SectorIndex, SectorView>,
-    ) -> SectorView {
-        let needs_rebuild = match sector_version_map.get(&sector.sector_index) {
-            Some(current_version) => *current_version != sector.version,
-            None => true,
-        };
+    ) -> WorldViewDelta {
+        let mut world_view_delta = WorldViewDelta::default();
 
-        let sector_view = if needs_rebuild {
-            SectorView::new_from_sector(sector)
-        } else {
-            sector_view_cache[&sector.sector_index].clone()
-        };
+        let sector_index_vec = Self::get_visible_sector_index_vec(person_id, state);
+        let sector_index_set: HashSet<SectorIndex> = sector_index_vec.iter().copied().collect();
+
+        sector_version_map.retain(|sector_index, _| {
+            let visible = sector_index_set.contains(sector_index);
+
+            if !visible {
+                world_view_delta.exited_sector_index_vec.push(*sector_index);
+            }
+
+            visible
+        });
+
+        world_view_delta.exited_sector_index_vec.sort_unstable();
+
+        for sector_index in sector_index_vec {
+            let Some(sector) = World::get_sector_by_index(&sector_index, &state.world.sector_map)
+            else {
+                continue;
+            };
+
+            match sector_version_map.insert(sector_index, sector.version) {
+                None => {
+                    world_view_delta.entered_sector_index_vec.push(sector_index);
+                    world_view_delta
+                        .sector_view_vec
+                        .push(SectorView::new_from_sector(sector));
+                }
+                Some(version) if version != sector.version => {
+                    world_view_delta
+                        .sector_view_vec
+                        .push(SectorView::new_from_sector(sector));
+                }
+                Some(_) => (),
+            }
+        }
 
-        sector_view
+        world_view_delta
     }
 }
@@ -5,6 +5,7 @@ pub mod sector_view;
 pub mod supervisor_view;
 pub mod time_view;
 pub mod world_view;
+pub mod world_view_delta;
 
 pub use leadership_view::LeadershipView;
 pub use person_view::PersonView;
@@ -13,10 +14,10 @@ pub use sector_view::SectorView;
 pub use supervisor_view::SupervisorView;
 pub use time_view::TimeView;
 pub use world_view::WorldView;
+pub use world_view_delta::WorldViewDelta;
 
 #[derive(Clone, Default)]
 pub struct View {
     pub supervisor_view: SupervisorView,
     pub population_view: PopulationView,
-    pub world_view: WorldView,
 }
@@ -8,8 +8,9 @@ use crate::simulation::state::{
     },
     world::block::block_kind::BlockKind,
 };
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PersonView {
     pub appearance: Appearance,
     pub identity: Identity,
@@ -2,9 +2,10 @@ use crate::simulation::{
     state::population::person::person_id::PersonID,
     supervisor::viewer::{view::LeadershipView, PersonView},
 };
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct PopulationView {
     pub active: bool,
     pub leadership_view: LeadershipView,
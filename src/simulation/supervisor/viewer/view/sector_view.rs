@@ -1,16 +1,22 @@
 use crate::simulation::state::world::{
-    block::Block,
+    block::{block_kind::BlockKind, Block},
+    cell::cell_index::CellIndex,
     grid,
-    sector::{sector_index::SectorIndex, Sector},
+    light::light_channel::LightChannel,
+    sector::{
+        block_storage::BlockStorage, light_field::LightField, sector_index::SectorIndex, Sector,
+    },
 };
+use serde::{Deserialize, Serialize};
 use ultraviolet::Vec3;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SectorView {
     pub sector_index: SectorIndex,
     pub version: u64,
     pub world_position: Vec3,
-    pub block_vec: Vec<Option<Block>>,
+    pub block_storage: BlockStorage,
+    pub light_field: LightField,
 }
 
 impl SectorView {
@@ -19,7 +25,29 @@ impl SectorView {
             sector_index: sector.sector_index,
             version: sector.version,
             world_position: grid::grid_position_to_world_position(sector.grid_position),
-            block_vec: sector.block_vec.clone(),
+            block_storage: sector.block_storage.clone(),
+            light_field: sector.light_field.clone(),
         }
     }
+
+    pub fn get_block(cell_index: CellIndex, sector_view: &Self) -> Option<Block> {
+        BlockStorage::get_block(
+            sector_view.sector_index,
+            cell_index,
+            &sector_view.block_storage,
+        )
+    }
+
+    pub fn get_light_level(
+        cell_index: CellIndex,
+        light_channel: &LightChannel,
+        sector_view: &Self,
+    ) -> u8 {
+        LightField::get_level(cell_index, light_channel, &sector_view.light_field)
+    }
+
+    pub fn is_opaque(cell_index: CellIndex, sector_view: &Self) -> bool {
+        BlockStorage::get_entry(cell_index, &sector_view.block_storage)
+            .is_some_and(|palette_entry| BlockKind::is_opaque(&palette_entry.block_kind))
+    }
 }
@@ -1,14 +1,27 @@
 use crate::simulation::supervisor::supervisor_status::SupervisorStatus;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SupervisorView {
     pub supervisor_status: SupervisorStatus,
+    pub checksum_tick: u32,
+    pub checksum: u64,
+    pub paused: bool,
+    pub time_scale: f32,
+    pub unbounded: bool,
+    pub tick_rate: f32,
 }
 
 impl SupervisorView {
     pub fn new() -> Self {
         Self {
             supervisor_status: SupervisorStatus::Start,
+            checksum_tick: 0,
+            checksum: 0,
+            paused: false,
+            time_scale: 1.0,
+            unbounded: false,
+            tick_rate: 0.0,
         }
     }
 }
@@ -1,10 +1,26 @@
 use crate::simulation::{
-    state::world::sector::sector_index::SectorIndex, supervisor::viewer::SectorView,
+    state::world::sector::sector_index::SectorIndex,
+    supervisor::viewer::view::{SectorView, WorldViewDelta},
 };
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Clone, Default)]
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct WorldView {
     pub active: bool,
     pub sector_view_map: HashMap<SectorIndex, SectorView>,
 }
+
+impl WorldView {
+    pub fn apply_delta(world_view_delta: WorldViewDelta, world_view: &mut Self) {
+        for sector_index in &world_view_delta.exited_sector_index_vec {
+            world_view.sector_view_map.remove(sector_index);
+        }
+
+        for sector_view in world_view_delta.sector_view_vec {
+            world_view
+                .sector_view_map
+                .insert(sector_view.sector_index, sector_view);
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::simulation::{
+    state::world::sector::sector_index::SectorIndex, supervisor::viewer::view::SectorView,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct WorldViewDelta {
+    pub entered_sector_index_vec: Vec<SectorIndex>,
+    pub exited_sector_index_vec: Vec<SectorIndex>,
+    pub sector_view_vec: Vec<SectorView>,
+}
+
+impl WorldViewDelta {
+    pub fn is_empty(world_view_delta: &Self) -> bool {
+        world_view_delta.entered_sector_index_vec.is_empty()
+            && world_view_delta.exited_sector_index_vec.is_empty()
+            && world_view_delta.sector_view_vec.is_empty()
+    }
+}
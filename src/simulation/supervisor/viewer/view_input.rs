@@ -0,0 +1,11 @@
+use crate::simulation::{
+    state::world::sector::sector_index::SectorIndex,
+    supervisor::viewer::view::{View, WorldViewDelta},
+};
+use std::collections::HashMap;
+
+pub struct ViewInput {
+    pub view_buffer: triple_buffer::Input<View>,
+    pub world_view_delta_tx: crossbeam::channel::Sender<WorldViewDelta>,
+    pub sector_version_map: HashMap<SectorIndex, u64>,
+}
@@ -0,0 +1,7 @@
+use crate::simulation::supervisor::viewer::view::{View, WorldView, WorldViewDelta};
+
+pub struct ViewOutput {
+    pub view_buffer: triple_buffer::Output<View>,
+    pub world_view_delta_rx: crossbeam::channel::Receiver<WorldViewDelta>,
+    pub world_view: WorldView,
+}
@@ -1 +1,22 @@
-
+mod block_shape;
+mod block_tick;
+mod checksum;
+mod collision;
+mod common;
+mod console;
+mod control;
+mod door;
+mod judge;
+mod light;
+mod motion;
+mod network;
+mod physics;
+mod recorder;
+mod region_edit;
+mod save_file;
+mod scheduler;
+mod schematic;
+mod sector_model;
+mod supervisor;
+mod viewer;
+mod world;
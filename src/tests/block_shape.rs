@@ -0,0 +1,181 @@
+use crate::{
+    simulation::{
+        constants::*,
+        state::{
+            world::{
+                block::{block_kind::BlockKind, block_state::BlockState, Block},
+                grid::{axis::Axis, Direction},
+            },
+            Physics, World,
+        },
+    },
+    utils::ldmath::FloatBox,
+};
+use strum::VariantArray;
+use ultraviolet::{IVec3, Vec3};
+
+const HORIZONTAL_DIRECTION_ARRAY: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+fn create_block(block_kind: &BlockKind, direction: &Direction, is_open: bool) -> Block {
+    let mut block = Block::new(block_kind, IVec3::zero(), direction);
+
+    if let BlockState::Door(door_data) = &mut block.block_state {
+        door_data.is_open = is_open;
+    }
+
+    block
+}
+
+fn get_extent(block: &Block) -> (Vec3, Vec3) {
+    let float_box_array = Block::get_float_box_array(block);
+
+    let min = float_box_array
+        .iter()
+        .map(FloatBox::get_min)
+        .fold(Vec3::broadcast(f32::MAX), |min, float_box_min| {
+            min.min_by_component(float_box_min)
+        });
+
+    let max = float_box_array
+        .iter()
+        .map(FloatBox::get_max)
+        .fold(Vec3::broadcast(f32::MIN), |max, float_box_max| {
+            max.max_by_component(float_box_max)
+        });
+
+    (min, max)
+}
+
+fn assert_extent(block: &Block, min: Vec3, max: Vec3) {
+    let (extent_min, extent_max) = get_extent(block);
+
+    assert!((extent_min - min).mag() < 1e-6, "{extent_min:?} != {min:?}");
+    assert!((extent_max - max).mag() < 1e-6, "{extent_max:?} != {max:?}");
+}
+
+#[test]
+fn block_float_boxes_stay_inside_their_cell() {
+    for block_kind in BlockKind::VARIANTS {
+        for direction in &HORIZONTAL_DIRECTION_ARRAY {
+            for is_open in [false, true] {
+                let block = create_block(block_kind, direction, is_open);
+
+                for float_box in Block::get_float_box_array(&block) {
+                    let min = FloatBox::get_min(float_box);
+                    let max = FloatBox::get_max(float_box);
+
+                    assert!(min.component_min() >= -CELL_RADIUS_IN_METERS - 1e-6);
+                    assert!(max.component_max() <= CELL_RADIUS_IN_METERS + 1e-6);
+                    assert!((max - min).component_min() > 0.0);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn shapes_cover_the_expected_part_of_the_cell() {
+    let half = CELL_RADIUS_IN_METERS;
+
+    assert_extent(
+        &create_block(&BlockKind::Metal1, &Direction::North, false),
+        Vec3::broadcast(-half),
+        Vec3::broadcast(half),
+    );
+
+    // Closed doors are an eighth of a cell thick across their frame
+    assert_extent(
+        &create_block(&BlockKind::DoorLower1, &Direction::East, false),
+        Vec3::new(-CELL_UNIT_16, -half, -half),
+        Vec3::new(CELL_UNIT_16, half, half),
+    );
+
+    assert_extent(
+        &create_block(&BlockKind::DoorUpper1, &Direction::North, false),
+        Vec3::new(-half, -CELL_UNIT_16, -half),
+        Vec3::new(half, CELL_UNIT_16, half),
+    );
+
+    // Open doors leave only the two jambs at the frame edges
+    let open_door = create_block(&BlockKind::DoorLower1, &Direction::East, true);
+    let open_door_float_box_array = Block::get_float_box_array(&open_door);
+
+    assert_eq!(open_door_float_box_array.len(), 2);
+
+    for float_box in open_door_float_box_array {
+        assert!((float_box.radius.x - CELL_UNIT_16).abs() < 1e-6);
+        assert!((float_box.radius.y - CELL_UNIT_16).abs() < 1e-6);
+        assert!((float_box.center_position.y.abs() - (half - CELL_UNIT_16)).abs() < 1e-6);
+    }
+
+    // Ladders are a quarter cell deep against the face they are mounted on
+    for (direction, min, max) in [
+        (
+            Direction::North,
+            Vec3::new(-half, half - CELL_UNIT_04, -half),
+            Vec3::broadcast(half),
+        ),
+        (
+            Direction::West,
+            Vec3::broadcast(-half),
+            Vec3::new(-half + CELL_UNIT_04, half, half),
+        ),
+        (
+            Direction::South,
+            Vec3::broadcast(-half),
+            Vec3::new(half, -half + CELL_UNIT_04, half),
+        ),
+        (
+            Direction::East,
+            Vec3::new(half - CELL_UNIT_04, -half, -half),
+            Vec3::broadcast(half),
+        ),
+    ] {
+        assert_extent(
+            &create_block(&BlockKind::Ladder1, &direction, false),
+            min,
+            max,
+        );
+    }
+}
+
+#[test]
+fn hits_use_block_float_boxes_at_their_grid_position() {
+    let mut world = World::new(813);
+
+    let door_grid_position = IVec3::new(20, -7, 3);
+
+    World::set_block(
+        door_grid_position,
+        &Direction::East,
+        &BlockKind::DoorLower1,
+        &mut world,
+    );
+
+    let radius = Vec3::broadcast(CELL_UNIT_04);
+
+    let float_box = FloatBox::new(
+        Vec3::from(door_grid_position) - Vec3::unit_x() * 2.0,
+        radius,
+    );
+
+    let axis_resolution = Physics::compute_axis_resolution(&float_box, Axis::X, 4.0, &world);
+
+    let gap = 2.0 - CELL_UNIT_16 - radius.x;
+
+    assert!(axis_resolution.hit_option.is_some());
+    assert!((axis_resolution.delta_resolved - (gap - COLLISION_EPSILON)).abs() < 1e-4);
+
+    // The same box at the origin must not collide with the untranslated door shape
+    let origin_float_box = FloatBox::new(-Vec3::unit_x() * 2.0, radius);
+
+    let origin_resolution =
+        Physics::compute_axis_resolution(&origin_float_box, Axis::X, 4.0, &world);
+
+    assert!(origin_resolution.hit_option.is_none());
+}
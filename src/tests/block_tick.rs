@@ -0,0 +1,227 @@
+use crate::simulation::state::world::{
+    block::{block_kind::BlockKind, block_state::BlockState},
+    block_tick::{block_tick_queue::BlockTickQueue, BlockTick},
+    door::Door,
+    grid::Direction,
+    World,
+};
+use ultraviolet::IVec3;
+
+fn is_door_open(grid_position: IVec3, world: &World) -> bool {
+    let block = World::get_block(grid_position, &world.sector_map).expect("Door is missing");
+
+    let BlockState::Door(door_data) = block.block_state else {
+        panic!("Block is not a door");
+    };
+
+    door_data.is_open
+}
+
+fn create_cube_world(block_kind: &BlockKind, seed: u64) -> World {
+    let mut world = World::new(seed);
+
+    World::set_block_cube(
+        IVec3::new(-2, -2, -2),
+        IVec3::new(2, 2, 2),
+        &Direction::North,
+        block_kind,
+        &mut world,
+    );
+
+    world
+}
+
+fn get_block_kind_vec(world: &World) -> Vec<BlockKind> {
+    let mut block_kind_vec = Vec::new();
+
+    for z in -2..=2 {
+        for y in -2..=2 {
+            for x in -2..=2 {
+                let block = World::get_block(IVec3::new(x, y, z), &world.sector_map)
+                    .expect("Block is missing");
+
+                block_kind_vec.push(block.block_kind);
+            }
+        }
+    }
+
+    block_kind_vec
+}
+
+#[test]
+fn scheduled_tick_closes_door_after_delay() {
+    let mut world = World::new(813);
+
+    let door_grid_position = IVec3::new(0, 0, 0);
+
+    World::set_block(
+        door_grid_position,
+        &Direction::North,
+        &BlockKind::DoorLower1,
+        &mut world,
+    );
+
+    let mut door_block =
+        World::get_block(door_grid_position, &world.sector_map).expect("Door is missing");
+
+    let BlockState::Door(door_data) = &mut door_block.block_state else {
+        panic!("Block is not a door");
+    };
+
+    door_data.is_open = true;
+
+    World::store_block(door_block, &mut world);
+
+    BlockTick::schedule(door_grid_position, 3, &mut world);
+
+    World::tick(&mut world);
+    World::tick(&mut world);
+
+    assert!(is_door_open(door_grid_position, &world));
+
+    World::tick(&mut world);
+
+    assert!(!is_door_open(door_grid_position, &world));
+    assert!(world.block_tick_queue.scheduled_tick_map.is_empty());
+}
+
+#[test]
+fn block_tick_queue_pops_due_ticks_in_schedule_order() {
+    let mut block_tick_queue = BlockTickQueue::new();
+
+    BlockTickQueue::push(5, IVec3::new(3, 0, 0), &mut block_tick_queue);
+    BlockTickQueue::push(2, IVec3::new(1, 0, 0), &mut block_tick_queue);
+    BlockTickQueue::push(2, IVec3::new(2, 0, 0), &mut block_tick_queue);
+
+    assert!(BlockTickQueue::pop_due(1, &mut block_tick_queue).is_empty());
+
+    assert_eq!(
+        BlockTickQueue::pop_due(4, &mut block_tick_queue),
+        vec![IVec3::new(1, 0, 0), IVec3::new(2, 0, 0)]
+    );
+
+    assert_eq!(
+        BlockTickQueue::pop_due(5, &mut block_tick_queue),
+        vec![IVec3::new(3, 0, 0)]
+    );
+}
+
+#[test]
+fn block_tick_queue_index_follows_pops_and_cancels() {
+    let mut block_tick_queue = BlockTickQueue::new();
+
+    BlockTickQueue::push(2, IVec3::new(1, 0, 0), &mut block_tick_queue);
+    BlockTickQueue::push(4, IVec3::new(1, 0, 0), &mut block_tick_queue);
+    BlockTickQueue::push(3, IVec3::new(2, 0, 0), &mut block_tick_queue);
+
+    BlockTickQueue::pop_due(2, &mut block_tick_queue);
+
+    assert!(BlockTickQueue::is_scheduled(
+        IVec3::new(1, 0, 0),
+        &block_tick_queue
+    ));
+
+    BlockTickQueue::cancel(IVec3::new(1, 0, 0), &mut block_tick_queue);
+
+    assert!(!BlockTickQueue::is_scheduled(
+        IVec3::new(1, 0, 0),
+        &block_tick_queue
+    ));
+
+    assert_eq!(
+        BlockTickQueue::pop_due(4, &mut block_tick_queue),
+        vec![IVec3::new(2, 0, 0)]
+    );
+
+    assert!(!BlockTickQueue::is_scheduled(
+        IVec3::new(2, 0, 0),
+        &block_tick_queue
+    ));
+    assert!(block_tick_queue.grid_position_index.is_empty());
+}
+
+#[test]
+fn random_ticks_close_stray_doors_and_leave_decorative_blocks() {
+    let door_grid_position = IVec3::new(4, 0, 0);
+
+    let mut close_tick_vec = Vec::new();
+
+    for _ in 0..2 {
+        let mut world = create_cube_world(&BlockKind::Server1, 813);
+
+        World::set_block(
+            door_grid_position,
+            &Direction::North,
+            &BlockKind::DoorLower1,
+            &mut world,
+        );
+
+        World::set_block(
+            door_grid_position + IVec3::unit_z(),
+            &Direction::North,
+            &BlockKind::DoorUpper1,
+            &mut world,
+        );
+
+        Door::set_open(door_grid_position, true, &mut world);
+
+        let initial_block_kind_vec = get_block_kind_vec(&world);
+
+        while is_door_open(door_grid_position, &world) {
+            assert!(world.time.tick < 20_000, "Stray door never closed");
+
+            World::tick(&mut world);
+        }
+
+        assert!(get_block_kind_vec(&world) == initial_block_kind_vec);
+
+        close_tick_vec.push(world.time.tick);
+    }
+
+    assert_eq!(close_tick_vec[0], close_tick_vec[1]);
+}
+
+#[test]
+fn random_ticks_weather_carved_blocks_until_they_settle() {
+    let mut block_kind_vec_vec = Vec::new();
+
+    for _ in 0..2 {
+        let mut world = create_cube_world(&BlockKind::Carved1, 813);
+
+        for _ in 0..4000 {
+            World::tick(&mut world);
+        }
+
+        block_kind_vec_vec.push(get_block_kind_vec(&world));
+    }
+
+    let block_kind_vec = &block_kind_vec_vec[0];
+
+    assert_eq!(block_kind_vec, &block_kind_vec_vec[1]);
+    assert!(block_kind_vec.contains(&BlockKind::Carved4));
+    assert!(block_kind_vec.iter().all(|block_kind| matches!(
+        block_kind,
+        BlockKind::Carved1 | BlockKind::Carved2 | BlockKind::Carved3 | BlockKind::Carved4
+    )));
+
+    let mut world = create_cube_world(&BlockKind::Carved4, 813);
+
+    let sector_version_vec: Vec<u64> = world
+        .sector_map
+        .values()
+        .map(|sector| sector.version)
+        .collect();
+
+    for _ in 0..4000 {
+        World::tick(&mut world);
+    }
+
+    assert_eq!(
+        world
+            .sector_map
+            .values()
+            .map(|sector| sector.version)
+            .collect::<Vec<u64>>(),
+        sector_version_vec
+    );
+}
@@ -0,0 +1,94 @@
+use crate::{
+    simulation::{
+        state::{
+            world::{
+                block::block_kind::BlockKind,
+                grid::{self, Direction},
+                sector_store::SectorStore,
+            },
+            Checksum, State, World,
+        },
+        supervisor::{recorder::RecorderMode, supervisor_status::SupervisorStatus},
+        Simulation,
+    },
+    tests::common::{create_simulation, send_generate_messages, send_scripted_input},
+};
+use ultraviolet::IVec3;
+
+fn get_checksum_vec(seed: u64) -> Vec<(u32, u64)> {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    simulation.supervisor.checksum_interval = 5;
+
+    send_generate_messages(seed, &message_tx);
+
+    let mut checksum_vec = Vec::new();
+
+    Simulation::run_headless(
+        120,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |supervisor, _| {
+            if supervisor.supervisor_status == SupervisorStatus::Run
+                && supervisor.checksum_tick == supervisor.tick
+            {
+                checksum_vec.push((supervisor.checksum_tick, supervisor.checksum));
+            }
+
+            send_scripted_input(supervisor.tick, &message_tx);
+        },
+    );
+
+    checksum_vec
+}
+
+#[test]
+fn same_seed_produces_identical_checksum_sequence() {
+    let checksum_vec1 = get_checksum_vec(813);
+    let checksum_vec2 = get_checksum_vec(813);
+
+    assert_eq!(checksum_vec1.len(), 24);
+    assert_eq!(checksum_vec1, checksum_vec2);
+}
+
+#[test]
+fn different_seed_produces_different_checksum_sequence() {
+    let checksum_vec1 = get_checksum_vec(813);
+    let checksum_vec2 = get_checksum_vec(814);
+
+    assert_ne!(checksum_vec1.last(), checksum_vec2.last());
+}
+
+#[test]
+fn evicted_sectors_stay_in_the_checksum() {
+    let mut state = State::new();
+
+    let grid_position = IVec3::new(40, -40, 8);
+
+    World::set_block(
+        grid_position,
+        &Direction::North,
+        &BlockKind::EagleStone,
+        &mut state.world,
+    );
+
+    let resident_checksum = Checksum::compute(&state);
+
+    let sector_index = grid::grid_position_to_sector_index(grid_position);
+
+    let mut sector = state
+        .world
+        .sector_map
+        .remove(&sector_index)
+        .expect("Sector is missing");
+
+    SectorStore::write(&sector, &mut state.world.sector_store).expect("Failed to store sector");
+
+    assert_eq!(Checksum::compute(&state), resident_checksum);
+
+    sector.version += 1;
+
+    SectorStore::write(&sector, &mut state.world.sector_store).expect("Failed to store sector");
+
+    assert_ne!(Checksum::compute(&state), resident_checksum);
+}
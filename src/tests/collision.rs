@@ -0,0 +1,382 @@
+use crate::simulation::{
+    constants::*,
+    state::{
+        physics::{
+            axis_resolution::AxisResolution,
+            body::{body_label::BodyLabel, Body, Contact, ContactSet},
+        },
+        population::{
+            nation::nation_kind::NationKind,
+            person::{person_id::PersonID, Person},
+        },
+        world::{
+            block::block_kind::BlockKind,
+            door::Door,
+            grid::{axis::Axis, Direction},
+        },
+        Physics, Population, World,
+    },
+};
+use crate::utils::ldmath::FloatBox;
+use ultraviolet::{IVec3, Vec3};
+
+const WALL_X: f32 = 2.5;
+const CEILING_Z: f32 = 3.5;
+const DOOR_GRID_POSITION: IVec3 = IVec3::new(3, 0, 0);
+
+fn create_collision_world() -> World {
+    let mut world = World::new(813);
+
+    World::set_block_cube(
+        IVec3::new(-4, -4, -1),
+        IVec3::new(4, 4, -1),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut world,
+    );
+
+    World::set_block_cube(
+        IVec3::new(3, -4, 0),
+        IVec3::new(3, 4, 3),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut world,
+    );
+
+    World::set_block_cube(
+        IVec3::new(-4, -4, 4),
+        IVec3::new(2, 4, 4),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut world,
+    );
+
+    World::set_block(
+        DOOR_GRID_POSITION,
+        &Direction::East,
+        &BlockKind::DoorLower1,
+        &mut world,
+    );
+
+    World::set_block(
+        DOOR_GRID_POSITION + IVec3::unit_z(),
+        &Direction::East,
+        &BlockKind::DoorUpper1,
+        &mut world,
+    );
+
+    World::set_block_cube(
+        IVec3::new(-2, 2, 0),
+        IVec3::new(-2, 2, 3),
+        &Direction::North,
+        &BlockKind::Ladder1,
+        &mut world,
+    );
+
+    world
+}
+
+fn get_core_float_box(feet_position: Vec3) -> FloatBox {
+    let radius = Vec3::new(
+        JUDGE_DEFAULT_RADIUS_X,
+        JUDGE_DEFAULT_RADIUS_Y,
+        JUDGE_DEFAULT_RADIUS_Z,
+    );
+
+    // Rest the box a separation bias above the floor, as resolved persons do
+    let center_position = feet_position + Vec3::unit_z() * (radius.z + COLLISION_EPSILON);
+
+    FloatBox::new(center_position, radius)
+}
+
+fn assert_blocked(
+    float_box: &FloatBox,
+    delta_axis: Axis,
+    delta_intent: f32,
+    gap: f32,
+    axis_resolution: &AxisResolution,
+) {
+    let axis_index = Axis::index(delta_axis);
+    let delta_intent_sign = delta_intent.signum();
+
+    let hit = axis_resolution
+        .hit_option
+        .as_ref()
+        .expect("Sweep was not blocked");
+
+    assert!(
+        (axis_resolution.delta_resolved - delta_intent_sign * (gap - COLLISION_EPSILON)).abs()
+            < 1e-4
+    );
+    assert!((axis_resolution.time_of_impact - gap / delta_intent.abs()).abs() < 1e-4);
+    assert_eq!(axis_resolution.velocity_mask, 0.0);
+    assert_eq!(hit.normal, -Axis::unit(delta_axis) * delta_intent_sign);
+
+    let contact_float_box =
+        FloatBox::translated(Axis::unit(delta_axis) * delta_intent_sign * gap, float_box);
+
+    let contact_face = if delta_intent > 0.0 {
+        FloatBox::get_max(&contact_float_box)[axis_index]
+    } else {
+        FloatBox::get_min(&contact_float_box)[axis_index]
+    };
+
+    assert!((hit.contact_point[axis_index] - contact_face).abs() < 1e-4);
+
+    for cross_axis in Axis::ALL {
+        let cross_index = Axis::index(cross_axis);
+
+        if cross_axis != delta_axis {
+            assert!(hit.contact_point[cross_index] >= FloatBox::get_min(float_box)[cross_index]);
+            assert!(hit.contact_point[cross_index] <= FloatBox::get_max(float_box)[cross_index]);
+        }
+    }
+}
+
+fn assert_free(delta_intent: f32, axis_resolution: &AxisResolution) {
+    assert!(axis_resolution.hit_option.is_none());
+    assert_eq!(axis_resolution.delta_resolved, delta_intent);
+    assert_eq!(axis_resolution.time_of_impact, 1.0);
+    assert_eq!(axis_resolution.velocity_mask, 1.0);
+}
+
+#[test]
+fn sweep_stops_at_the_wall_face() {
+    let world = create_collision_world();
+
+    let float_box = get_core_float_box(Vec3::new(0.0, -2.0, -0.5));
+
+    for delta_intent in [0.5, 5.0, 50.0] {
+        let axis_resolution =
+            Physics::compute_axis_resolution(&float_box, Axis::X, delta_intent, &world);
+
+        if delta_intent < WALL_X - JUDGE_DEFAULT_RADIUS_X {
+            assert_free(delta_intent, &axis_resolution);
+        } else {
+            assert_blocked(
+                &float_box,
+                Axis::X,
+                delta_intent,
+                WALL_X - JUDGE_DEFAULT_RADIUS_X,
+                &axis_resolution,
+            );
+        }
+    }
+}
+
+#[test]
+fn sweep_leaves_a_touching_wall_but_cannot_enter_it() {
+    let world = create_collision_world();
+
+    let float_box = get_core_float_box(Vec3::new(WALL_X - JUDGE_DEFAULT_RADIUS_X, -2.0, -0.5));
+
+    let away_resolution = Physics::compute_axis_resolution(&float_box, Axis::X, -1.0, &world);
+    let into_resolution = Physics::compute_axis_resolution(&float_box, Axis::X, 1.0, &world);
+
+    assert_free(-1.0, &away_resolution);
+    assert_blocked(&float_box, Axis::X, 1.0, 0.0, &into_resolution);
+}
+
+#[test]
+fn sweep_stops_at_the_ceiling_and_the_floor() {
+    let world = create_collision_world();
+
+    let float_box = get_core_float_box(Vec3::new(0.0, 0.0, -0.5));
+
+    let ceiling_gap = CEILING_Z - FloatBox::get_max(&float_box).z;
+
+    let up_resolution = Physics::compute_axis_resolution(&float_box, Axis::Z, 5.0, &world);
+    let down_resolution = Physics::compute_axis_resolution(&float_box, Axis::Z, -5.0, &world);
+
+    assert_blocked(&float_box, Axis::Z, 5.0, ceiling_gap, &up_resolution);
+    assert_blocked(
+        &float_box,
+        Axis::Z,
+        -5.0,
+        COLLISION_EPSILON,
+        &down_resolution,
+    );
+}
+
+#[test]
+fn closed_doors_block_and_open_doors_pass() {
+    let mut world = create_collision_world();
+
+    let float_box = get_core_float_box(Vec3::new(0.0, 0.0, -0.5));
+
+    let door_face_x = DOOR_GRID_POSITION.x as f32 - CELL_UNIT_16;
+
+    let closed_resolution = Physics::compute_axis_resolution(&float_box, Axis::X, 5.0, &world);
+
+    assert_blocked(
+        &float_box,
+        Axis::X,
+        5.0,
+        door_face_x - JUDGE_DEFAULT_RADIUS_X,
+        &closed_resolution,
+    );
+
+    assert!(Door::toggle_open(
+        DOOR_GRID_POSITION,
+        &NationKind::Eagle,
+        &mut world
+    ));
+
+    let open_resolution = Physics::compute_axis_resolution(&float_box, Axis::X, 5.0, &world);
+
+    assert_free(5.0, &open_resolution);
+}
+
+#[test]
+fn ladders_never_block_a_sweep() {
+    let world = create_collision_world();
+
+    let float_box = get_core_float_box(Vec3::new(-4.0, 2.0, -0.5));
+
+    let across_resolution = Physics::compute_axis_resolution(&float_box, Axis::X, 4.0, &world);
+
+    assert_free(4.0, &across_resolution);
+
+    let ladder_float_box = get_core_float_box(Vec3::new(-2.0, 2.0, -0.5));
+
+    let climb_resolution =
+        Physics::compute_axis_resolution(&ladder_float_box, Axis::Z, 1.0, &world);
+
+    assert_free(1.0, &climb_resolution);
+}
+
+#[test]
+fn judge_comes_to_rest_against_the_wall_and_under_the_ceiling() {
+    let world = create_collision_world();
+
+    let mut population = Population::new(813);
+    let mut physics = Physics::new();
+
+    Population::generate_judge(PersonID::JUDGE_ID_1, &mut population);
+
+    let judge = population
+        .person_map
+        .get_mut(&PersonID::JUDGE_ID_1)
+        .expect("Judge is missing");
+
+    Person::set_world_position(Vec3::new(0.0, -2.0, 0.0), judge);
+    Person::set_velocity(Vec3::new(8.0, 0.0, 20.0), judge);
+
+    let mut max_head_z = f32::MIN;
+
+    for _ in 0..60 {
+        Physics::tick(&world, &mut population, &mut physics);
+
+        let judge = &population.person_map[&PersonID::JUDGE_ID_1];
+
+        let head_z =
+            judge.transform.world_position.z - CELL_RADIUS_IN_METERS + 2.0 * JUDGE_DEFAULT_RADIUS_Z;
+
+        max_head_z = max_head_z.max(head_z);
+    }
+
+    let judge = &population.person_map[&PersonID::JUDGE_ID_1];
+
+    let rest_x = WALL_X - JUDGE_DEFAULT_RADIUS_X - COLLISION_EPSILON;
+
+    assert!((judge.transform.world_position.x - rest_x).abs() < 1e-4);
+    assert!(max_head_z <= CEILING_Z - COLLISION_EPSILON + 1e-4);
+    assert!(max_head_z > CEILING_Z - 0.1);
+    assert_eq!(judge.motion.velocity.x, 0.0);
+    assert!(ContactSet::has(Contact::Ground, &judge.body.contact_set));
+}
+
+#[test]
+fn judge_core_collider_rests_on_the_feet() {
+    let mut population = Population::new(813);
+
+    Population::generate_judge(PersonID::JUDGE_ID_1, &mut population);
+
+    let judge = population
+        .person_map
+        .get_mut(&PersonID::JUDGE_ID_1)
+        .expect("Judge is missing");
+
+    let world_position = Vec3::new(3.0, -2.0, 5.0);
+
+    Person::set_world_position(world_position, judge);
+
+    let core_float_box = &Body::get_collider(&BodyLabel::Core, &judge.body)
+        .expect("Body is missing core")
+        .float_box;
+
+    let feet_z = world_position.z - CELL_RADIUS_IN_METERS;
+
+    // The core spans from the feet up, not around the cell center
+    assert!((FloatBox::get_min(core_float_box).z - feet_z).abs() < 1e-6);
+    assert!(
+        (FloatBox::get_max(core_float_box).z - (feet_z + 2.0 * JUDGE_DEFAULT_RADIUS_Z)).abs()
+            < 1e-6
+    );
+    assert_eq!(core_float_box.center_position.x, world_position.x);
+    assert_eq!(core_float_box.center_position.y, world_position.y);
+}
+
+#[test]
+fn swept_and_bisection_solvers_agree_on_tick_sized_sweeps() {
+    let world = create_collision_world();
+
+    let door_face_x = DOOR_GRID_POSITION.x as f32 - CELL_UNIT_16;
+
+    let ceiling_feet_z = CEILING_Z - 2.0 * JUDGE_DEFAULT_RADIUS_Z - 1.0;
+
+    // Feet positions leaving the floor, approaching the wall, the ceiling and the door, and
+    // crossing the ladder
+    let case_vec = [
+        (
+            Vec3::new(WALL_X - JUDGE_DEFAULT_RADIUS_X - 1.0, -2.0, -0.5),
+            Axis::X,
+        ),
+        (Vec3::new(0.0, 0.0, -0.5), Axis::Z),
+        (Vec3::new(0.0, 0.0, ceiling_feet_z), Axis::Z),
+        (
+            Vec3::new(door_face_x - JUDGE_DEFAULT_RADIUS_X - 1.0, 0.0, -0.5),
+            Axis::X,
+        ),
+        (Vec3::new(-3.0, 2.0, -0.5), Axis::X),
+        (Vec3::new(-2.0, 2.0, -0.5), Axis::Z),
+    ];
+
+    for (feet_position, delta_axis) in case_vec {
+        for step in 0..20 {
+            let offset = Axis::unit(delta_axis) * (step as f32 * 0.05);
+            let float_box = get_core_float_box(feet_position + offset);
+
+            for delta_intent in [-0.4, -0.1, 0.1, 0.4] {
+                let swept_resolution =
+                    Physics::compute_axis_resolution(&float_box, delta_axis, delta_intent, &world);
+                let bisection_resolution = Physics::compute_axis_resolution_bisection(
+                    &float_box,
+                    delta_axis,
+                    delta_intent,
+                    &world,
+                );
+
+                assert!(
+                    (swept_resolution.delta_resolved - bisection_resolution.delta_resolved).abs()
+                        <= COLLISION_EPSILON,
+                    "swept {} and bisection {} disagree",
+                    swept_resolution.delta_resolved,
+                    bisection_resolution.delta_resolved
+                );
+                assert_eq!(
+                    swept_resolution.velocity_mask,
+                    bisection_resolution.velocity_mask
+                );
+            }
+        }
+    }
+
+    // Bisection only samples end positions, so a long sweep tunnels through the closed door
+    let float_box = get_core_float_box(Vec3::new(0.0, 0.0, -0.5));
+
+    let bisection_resolution =
+        Physics::compute_axis_resolution_bisection(&float_box, Axis::X, 5.0, &world);
+
+    assert_eq!(bisection_resolution.delta_resolved, 5.0);
+}
@@ -0,0 +1,164 @@
+use crate::simulation::{
+    state::{
+        action::act::{move_data::MoveData, Act},
+        population::{
+            motion::Stance,
+            person::{person_id::PersonID, Person},
+        },
+        world::sector::sector_index::SectorIndex,
+        Action, Physics, State,
+    },
+    supervisor::{
+        message::{MoveInputData, PersonInputData, RotateInputData, SeedData},
+        recorder::RecorderMode,
+        Message, Recorder, Viewer,
+    },
+    Simulation,
+};
+use std::collections::HashMap;
+use ultraviolet::Vec3;
+
+pub fn create_simulation(
+    recorder_mode: RecorderMode,
+) -> (Box<Simulation>, crossbeam::channel::Sender<Message>) {
+    let (message_tx, message_rx) = crossbeam::channel::unbounded::<Message>();
+    let (view_input, _view_output) = Viewer::create_view_channel();
+
+    let mut simulation = Box::new(Simulation::new(message_rx, view_input));
+
+    simulation.supervisor.recorder = Recorder::new(recorder_mode).expect("Failed to load replay");
+
+    (simulation, message_tx)
+}
+
+pub fn send_generate_messages(seed: u64, message_tx: &crossbeam::channel::Sender<Message>) {
+    message_tx
+        .send(Message::SetSeed(SeedData { seed }))
+        .unwrap();
+    message_tx.send(Message::Generate).unwrap();
+}
+
+pub fn send_scripted_input(tick: u32, message_tx: &crossbeam::channel::Sender<Message>) {
+    if tick < 40 {
+        return;
+    }
+
+    let move_input_data = MoveInputData {
+        person_id: PersonID::JUDGE_ID_1,
+        input_x: 1.0,
+        input_y: if tick < 70 { 1.0 } else { -1.0 },
+        input_z: 0.0,
+        sprint: false,
+        crouch: false,
+    };
+
+    message_tx
+        .send(Message::MoveInput(move_input_data))
+        .unwrap();
+
+    let rotate_input_data = RotateInputData {
+        person_id: PersonID::JUDGE_ID_1,
+        input_x: 0.0,
+        input_y: 0.0,
+        input_z: 3.0,
+    };
+
+    message_tx
+        .send(Message::RotateInput(rotate_input_data))
+        .unwrap();
+
+    if tick % 20 == 0 {
+        let person_input_data = PersonInputData {
+            person_id: PersonID::JUDGE_ID_1,
+        };
+
+        message_tx
+            .send(Message::JumpInput(person_input_data.clone()))
+            .unwrap();
+        message_tx
+            .send(Message::Interact2(person_input_data))
+            .unwrap();
+    }
+}
+
+pub fn get_snapshot(state: &State) -> Vec<u8> {
+    let mut person_id_vec: Vec<&PersonID> = state.population.person_map.keys().collect();
+    person_id_vec.sort();
+
+    let person_vec: Vec<_> = person_id_vec
+        .iter()
+        .map(|person_id| {
+            let person = &state.population.person_map[person_id];
+
+            (person_id, &person.transform, &person.motion)
+        })
+        .collect();
+
+    let snapshot = (
+        state.world.time.tick,
+        &state.world.sector_map,
+        &state.rng,
+        &state.world.rng,
+        &state.population.rng,
+        &state.population.id_generator,
+        person_vec,
+        state.world.tower.area_map.len(),
+    );
+
+    bincode::serialize(&snapshot).expect("Failed to serialize snapshot")
+}
+
+pub fn get_visible_sector_version_map(
+    person_id: &PersonID,
+    state: &State,
+) -> HashMap<SectorIndex, u64> {
+    Viewer::get_visible_sector_index_vec(person_id, state)
+        .into_iter()
+        .filter_map(|sector_index| {
+            state
+                .world
+                .sector_map
+                .get(&sector_index)
+                .map(|sector| (sector_index, sector.version))
+        })
+        .collect()
+}
+
+pub fn get_changed_sector_count(
+    sector_version_map: &HashMap<SectorIndex, u64>,
+    state: &State,
+) -> usize {
+    sector_version_map
+        .iter()
+        .filter(|(sector_index, version)| {
+            state.world.sector_map[*sector_index].version != **version
+        })
+        .count()
+}
+
+pub fn get_judge(state: &State) -> &Person {
+    &state.population.person_map[&PersonID::JUDGE_ID_1]
+}
+
+pub fn get_judge_mut(state: &mut State) -> &mut Person {
+    state
+        .population
+        .person_map
+        .get_mut(&PersonID::JUDGE_ID_1)
+        .expect("Judge is missing")
+}
+
+pub fn run_ticks(tick_count: usize, move_direction: Vec3, stance: Stance, state: &mut State) {
+    for _ in 0..tick_count {
+        let move_data = MoveData {
+            person_id: PersonID::JUDGE_ID_1,
+            move_direction,
+            stance,
+        };
+
+        state.action.act_deque.push_back(Act::Move(move_data));
+
+        Action::tick(state);
+        Physics::tick(&state.world, &mut state.population, &mut state.physics);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::{
+    interface::console::Console,
+    simulation::{
+        state::{
+            population::{nation::nation_kind::NationKind, person::person_id::PersonID},
+            world::{block::block_kind::BlockKind, grid::Direction},
+        },
+        supervisor::Message,
+    },
+};
+use std::collections::VecDeque;
+use ultraviolet::IVec3;
+
+fn execute(command_string: &str) -> Result<Vec<Message>, String> {
+    let mut message_deque = VecDeque::new();
+
+    Console::execute(command_string, PersonID::JUDGE_ID_1, &mut message_deque)?;
+
+    Ok(message_deque.into_iter().collect())
+}
+
+#[test]
+fn commands_parse_into_messages() {
+    match execute("setblock 1 -2 3 eaglestone east")
+        .unwrap()
+        .as_slice()
+    {
+        [Message::SetBlock(set_block_data)] => {
+            assert_eq!(set_block_data.person_id, PersonID::JUDGE_ID_1);
+            assert_eq!(set_block_data.grid_position, IVec3::new(1, -2, 3));
+            assert_eq!(
+                set_block_data.block_kind_option,
+                Some(BlockKind::EagleStone)
+            );
+            assert_eq!(set_block_data.direction, Direction::East);
+        }
+        message_slice => panic!("Unexpected messages: {message_slice:?}"),
+    }
+
+    match execute("fill 0 0 0 -3 2 1 air").unwrap().as_slice() {
+        [Message::Fill(fill_data)] => {
+            assert_eq!(fill_data.person_id, PersonID::JUDGE_ID_1);
+            assert_eq!(fill_data.block_kind_option, None);
+            assert_eq!(fill_data.direction, Direction::North);
+        }
+        message_slice => panic!("Unexpected messages: {message_slice:?}"),
+    }
+
+    match execute("SPAWN wolf 3").unwrap().as_slice() {
+        [Message::Spawn(spawn_data)] => {
+            assert_eq!(spawn_data.nation_kind, NationKind::Wolf);
+            assert_eq!(spawn_data.count, 3);
+        }
+        message_slice => panic!("Unexpected messages: {message_slice:?}"),
+    }
+
+    match execute("replace 4 0 0 0 2 1 eaglestone air")
+        .unwrap()
+        .as_slice()
+    {
+        [Message::ReplaceRegion(replace_data)] => {
+            assert_eq!(replace_data.person_id, PersonID::JUDGE_ID_1);
+            assert_eq!(replace_data.mask_block_kind, BlockKind::EagleStone);
+            assert_eq!(replace_data.block_kind_option, None);
+        }
+        message_slice => panic!("Unexpected messages: {message_slice:?}"),
+    }
+
+    assert!(matches!(
+        execute("paste 1 2 3 west").unwrap().as_slice(),
+        [Message::PasteRegion(_)]
+    ));
+
+    match execute("schematic guard_post 0 0 0 4 4 2")
+        .unwrap()
+        .as_slice()
+    {
+        [Message::SaveSchematic(schematic_data)] => {
+            assert_eq!(schematic_data.name, "guard_post");
+            assert_eq!(schematic_data.max, IVec3::new(4, 4, 2));
+        }
+        message_slice => panic!("Unexpected messages: {message_slice:?}"),
+    }
+
+    assert_eq!(execute("time step 4").unwrap().len(), 4);
+    assert!(matches!(
+        execute("tp 1.5 2 -3").unwrap().as_slice(),
+        [Message::Teleport(_)]
+    ));
+}
+
+#[test]
+fn invalid_commands_report_errors() {
+    assert!(execute("").is_err());
+    assert!(execute("teleport 1 2 3").is_err());
+    assert!(execute("tp 1 2").is_err());
+    assert!(execute("setblock 1 2 x Engraved1").is_err());
+    assert!(execute("setblock 1 2 3 Granite").is_err());
+    assert!(execute("fill 0 0 0 100 100 100 Engraved1").is_err());
+    assert!(execute("time scale 100").is_err());
+    assert!(execute("spawn Dragon 1").is_err());
+    assert!(execute("schematic ../room 0 0 0 1 1 1").is_err());
+}
+
+#[test]
+fn completion_extends_unique_and_shared_prefixes() {
+    assert_eq!(Console::complete("gen").0, "generate ");
+    assert_eq!(Console::complete("time u").0, "time unbounded ");
+    assert_eq!(Console::complete("spawn l").0, "spawn Lion ");
+    assert_eq!(
+        Console::complete("setblock 1 2 3 eaglest").0,
+        "setblock 1 2 3 EagleStone "
+    );
+
+    let (completed_string, candidate_vec) = Console::complete("se");
+
+    assert_eq!(completed_string, "se");
+    assert_eq!(candidate_vec, vec!["seed", "setblock"]);
+}
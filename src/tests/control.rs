@@ -0,0 +1,140 @@
+use crate::{
+    simulation::{
+        supervisor::{recorder::RecorderMode, Control},
+        Simulation,
+    },
+    tests::common::create_simulation,
+};
+use serde_json::Value;
+use std::{
+    io::{BufRead, BufReader, Write},
+    net::TcpStream,
+    time::Duration,
+};
+
+fn run_ticks(tick_count: u32, simulation: &mut Simulation) {
+    for _ in 0..tick_count {
+        Simulation::run_headless(
+            1,
+            &mut simulation.supervisor,
+            &mut simulation.state,
+            |_, _| (),
+        );
+
+        std::thread::sleep(Duration::from_millis(1));
+    }
+}
+
+fn send_request(request: &str, stream: &mut TcpStream) {
+    stream.write_all(request.as_bytes()).unwrap();
+    stream.write_all(b"\n").unwrap();
+    stream.flush().unwrap();
+}
+
+fn read_response(reader: &mut BufReader<TcpStream>) -> Value {
+    let mut line = String::new();
+
+    reader
+        .read_line(&mut line)
+        .expect("Failed to read response");
+
+    serde_json::from_str(&line).expect("Response is not valid JSON")
+}
+
+fn read_non_summary_response(reader: &mut BufReader<TcpStream>) -> Value {
+    loop {
+        let response = read_response(reader);
+
+        if response["type"] != "summary" {
+            return response;
+        }
+    }
+}
+
+#[test]
+fn local_client_drives_simulation() {
+    let (mut simulation, _message_tx) = create_simulation(RecorderMode::Off);
+
+    simulation.supervisor.control = Control::bind("127.0.0.1:0").expect("Failed to bind control");
+
+    let local_addr = Control::get_local_addr(&simulation.supervisor.control).unwrap();
+
+    let mut stream = TcpStream::connect(local_addr).unwrap();
+    stream
+        .set_read_timeout(Some(Duration::from_secs(10)))
+        .unwrap();
+    stream.set_nodelay(true).unwrap();
+
+    let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+    send_request(
+        r#"{"type":"message","message":{"SetSeed":{"seed":813}}}"#,
+        &mut stream,
+    );
+    send_request(r#"{"type":"message","message":"Generate"}"#, &mut stream);
+    send_request("not json", &mut stream);
+    send_request(
+        r#"{"type":"message","message":{"Load":{"save_name":"../../x"}}}"#,
+        &mut stream,
+    );
+
+    run_ticks(100, &mut simulation);
+
+    assert_eq!(read_response(&mut reader)["type"], "ok");
+    assert_eq!(read_response(&mut reader)["type"], "ok");
+    assert_eq!(read_response(&mut reader)["type"], "error");
+    assert_eq!(read_response(&mut reader)["type"], "error");
+
+    send_request(r#"{"type":"subscribe"}"#, &mut stream);
+    send_request(
+        r#"{"type":"message","message":{"SetBlock":{"person_id":1,"grid_position":[3,4,5],"block_kind_option":"EagleStone","direction":"North"}}}"#,
+        &mut stream,
+    );
+
+    run_ticks(3, &mut simulation);
+
+    send_request(r#"{"type":"get_judge"}"#, &mut stream);
+    send_request(r#"{"type":"get_persons"}"#, &mut stream);
+    send_request(
+        r#"{"type":"get_block","grid_position":[3,4,5]}"#,
+        &mut stream,
+    );
+
+    run_ticks(5, &mut simulation);
+
+    assert_eq!(read_response(&mut reader)["type"], "ok");
+    assert_eq!(read_response(&mut reader)["type"], "ok");
+
+    let summary_response = read_response(&mut reader);
+
+    assert_eq!(summary_response["type"], "summary");
+    assert_eq!(
+        summary_response["summary"]["person_count"],
+        simulation.state.population.person_map.len()
+    );
+    assert!(summary_response["summary"]["statistics_option"].is_null());
+    assert!(
+        summary_response["summary"]["checksum_tick"].as_u64()
+            <= summary_response["summary"]["supervisor_tick"].as_u64()
+    );
+
+    let judge_response = read_non_summary_response(&mut reader);
+
+    assert_eq!(judge_response["type"], "judge");
+    assert_eq!(judge_response["person_data"]["person_id"], 1);
+
+    let persons_response = read_non_summary_response(&mut reader);
+
+    assert_eq!(
+        persons_response["person_data_vec"]
+            .as_array()
+            .unwrap()
+            .len(),
+        simulation.state.population.person_map.len()
+    );
+
+    let block_response = read_non_summary_response(&mut reader);
+
+    assert_eq!(block_response["type"], "block");
+    assert_eq!(block_response["block_kind_option"], "EagleStone");
+}
@@ -0,0 +1,237 @@
+use crate::simulation::{
+    constants::*,
+    state::{
+        action::act::{Act, DoorData},
+        navigation::{
+            path::{self, state::StepResult},
+            Graph, Navigation,
+        },
+        population::{
+            nation::nation_kind::NationKind,
+            person::{person_id::PersonID, Person},
+        },
+        world::{block::block_kind::BlockKind, door::Door, grid::Direction},
+        Action, State, World,
+    },
+};
+use ultraviolet::{IVec3, Vec3};
+
+const DOOR_GRID_POSITION: IVec3 = IVec3::new(0, 0, 0);
+
+fn create_door_world() -> World {
+    let mut world = World::new(813);
+
+    World::set_block_cube(
+        IVec3::new(-3, -3, -1),
+        IVec3::new(3, 3, -1),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut world,
+    );
+
+    World::set_block_cube(
+        IVec3::new(0, -3, 0),
+        IVec3::new(0, 3, 1),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut world,
+    );
+
+    World::set_block(
+        DOOR_GRID_POSITION,
+        &Direction::East,
+        &BlockKind::DoorLower1,
+        &mut world,
+    );
+
+    World::set_block(
+        DOOR_GRID_POSITION + IVec3::unit_z(),
+        &Direction::East,
+        &BlockKind::DoorUpper1,
+        &mut world,
+    );
+
+    world
+}
+
+fn is_open(grid_position: IVec3, world: &World) -> bool {
+    Door::get_door_data(grid_position, &world.sector_map)
+        .expect("Door is missing")
+        .is_open
+}
+
+fn find_path(start: IVec3, end: IVec3, graph: &Graph) -> Option<Vec<IVec3>> {
+    let mut path_state = path::State::new(0, start, end);
+
+    loop {
+        match path_state.step(graph) {
+            StepResult::Continue => (),
+            StepResult::Found(path) => return Some(path),
+            StepResult::Impossible => return None,
+        }
+    }
+}
+
+#[test]
+fn door_halves_open_together_and_close_after_delay() {
+    let mut world = create_door_world();
+
+    let version = world.sector_map.values().next().unwrap().version;
+
+    assert!(Door::toggle_open(
+        DOOR_GRID_POSITION + IVec3::unit_z(),
+        &NationKind::Eagle,
+        &mut world
+    ));
+
+    assert!(is_open(DOOR_GRID_POSITION, &world));
+    assert!(is_open(DOOR_GRID_POSITION + IVec3::unit_z(), &world));
+    assert!(world.sector_map.values().next().unwrap().version > version);
+
+    for _ in 0..BLOCK_DOOR_CLOSE_DELAY_IN_TICKS {
+        World::tick(&mut world);
+    }
+
+    assert!(!is_open(DOOR_GRID_POSITION, &world));
+    assert!(!is_open(DOOR_GRID_POSITION + IVec3::unit_z(), &world));
+}
+
+#[test]
+fn reopened_door_ignores_the_earlier_close() {
+    let mut world = create_door_world();
+
+    let half_delay = BLOCK_DOOR_CLOSE_DELAY_IN_TICKS / 2;
+
+    assert!(Door::toggle_open(
+        DOOR_GRID_POSITION,
+        &NationKind::Eagle,
+        &mut world
+    ));
+
+    for _ in 0..half_delay {
+        World::tick(&mut world);
+    }
+
+    assert!(Door::toggle_open(
+        DOOR_GRID_POSITION,
+        &NationKind::Eagle,
+        &mut world
+    ));
+    assert!(Door::toggle_open(
+        DOOR_GRID_POSITION + IVec3::unit_z(),
+        &NationKind::Eagle,
+        &mut world
+    ));
+
+    for _ in 0..BLOCK_DOOR_CLOSE_DELAY_IN_TICKS - 1 {
+        World::tick(&mut world);
+    }
+
+    assert!(is_open(DOOR_GRID_POSITION, &world));
+
+    World::tick(&mut world);
+
+    assert!(!is_open(DOOR_GRID_POSITION, &world));
+    assert!(world.block_tick_queue.scheduled_tick_map.is_empty());
+}
+
+#[test]
+fn locked_door_opens_only_for_key_nation() {
+    let mut world = create_door_world();
+
+    assert!(Door::toggle_locked(
+        DOOR_GRID_POSITION,
+        &NationKind::Lion,
+        &mut world
+    ));
+
+    let door_data = Door::get_door_data(DOOR_GRID_POSITION + IVec3::unit_z(), &world.sector_map)
+        .expect("Door is missing");
+
+    assert!(door_data.is_locked);
+    assert_eq!(door_data.key_nation_kind_option, Some(NationKind::Lion));
+
+    assert!(!Door::toggle_open(
+        DOOR_GRID_POSITION,
+        &NationKind::Eagle,
+        &mut world
+    ));
+    assert!(!Door::toggle_locked(
+        DOOR_GRID_POSITION,
+        &NationKind::Eagle,
+        &mut world
+    ));
+    assert!(!is_open(DOOR_GRID_POSITION, &world));
+
+    assert!(Door::toggle_open(
+        DOOR_GRID_POSITION,
+        &NationKind::Lion,
+        &mut world
+    ));
+    assert!(is_open(DOOR_GRID_POSITION, &world));
+
+    assert!(!Door::toggle_locked(
+        DOOR_GRID_POSITION,
+        &NationKind::Lion,
+        &mut world
+    ));
+}
+
+#[test]
+fn door_act_toggles_the_door_in_sight() {
+    let mut state = State::new();
+
+    state.world = create_door_world();
+
+    let mut person = Person::new(PersonID::JUDGE_ID_1);
+
+    Person::set_world_position(Vec3::new(-2.0, 0.0, 0.0), &mut person);
+    Person::set_rotation(-90.0, 0.0, &mut person);
+
+    state
+        .population
+        .person_map
+        .insert(PersonID::JUDGE_ID_1, person);
+
+    let door_data = DoorData {
+        person_id: PersonID::JUDGE_ID_1,
+    };
+
+    state.action.act_deque.push_back(Act::Door(door_data));
+
+    Action::tick(&mut state);
+
+    assert!(is_open(DOOR_GRID_POSITION, &state.world));
+}
+
+#[test]
+fn navigation_graph_follows_door_state() {
+    let mut world = create_door_world();
+    let mut navigation = Navigation::new();
+
+    let start = IVec3::new(-2, 0, 0);
+    let end = IVec3::new(2, 0, 0);
+
+    Navigation::tick(&world, &mut navigation);
+
+    let path = find_path(start, end, &navigation.graph).expect("Closed door blocks path");
+
+    assert!(path.contains(&DOOR_GRID_POSITION));
+    assert_eq!(
+        Graph::get_cost(DOOR_GRID_POSITION, &navigation.graph),
+        NAVIGATION_DOOR_COST as i32
+    );
+
+    Door::toggle_open(DOOR_GRID_POSITION, &NationKind::Eagle, &mut world);
+    Navigation::tick(&world, &mut navigation);
+
+    assert_eq!(Graph::get_cost(DOOR_GRID_POSITION, &navigation.graph), 1);
+    assert!(find_path(start, end, &navigation.graph).is_some());
+
+    Door::toggle_open(DOOR_GRID_POSITION, &NationKind::Eagle, &mut world);
+    Door::toggle_locked(DOOR_GRID_POSITION, &NationKind::Eagle, &mut world);
+    Navigation::tick(&world, &mut navigation);
+
+    assert!(Graph::is_solid(DOOR_GRID_POSITION, &navigation.graph));
+    assert!(find_path(start, end, &navigation.graph).is_none());
+}
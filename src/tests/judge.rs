@@ -0,0 +1,92 @@
+use crate::{
+    simulation::{
+        constants::JUDGE_SPAWN_POSITION,
+        state::population::person::person_id::PersonID,
+        supervisor::{
+            message::{AddJudgeData, MoveInputData},
+            recorder::RecorderMode,
+            Message, Viewer,
+        },
+        Simulation,
+    },
+    tests::common::{create_simulation, send_generate_messages},
+};
+use ultraviolet::Vec2;
+
+#[test]
+fn each_judge_is_controlled_and_viewed_independently() {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    let (view_input, mut view_output) = Viewer::create_view_channel();
+
+    Viewer::attach(
+        PersonID::JUDGE_ID_2,
+        view_input,
+        &mut simulation.supervisor.viewer,
+    );
+
+    send_generate_messages(813, &message_tx);
+
+    Simulation::run_headless(
+        100,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |supervisor, _| {
+            if supervisor.tick == 40 {
+                let add_judge_data = AddJudgeData {
+                    person_id: PersonID::JUDGE_ID_2,
+                };
+
+                message_tx.send(Message::AddJudge(add_judge_data)).unwrap();
+            }
+
+            if supervisor.tick > 60 {
+                let move_input_data = MoveInputData {
+                    person_id: PersonID::JUDGE_ID_2,
+                    input_x: 0.0,
+                    input_y: 1.0,
+                    input_z: 0.0,
+                    sprint: false,
+                    crouch: false,
+                };
+
+                message_tx
+                    .send(Message::MoveInput(move_input_data))
+                    .unwrap();
+            }
+        },
+    );
+
+    let person_map = &simulation.state.population.person_map;
+
+    let judge1 = &person_map[&PersonID::JUDGE_ID_1];
+    let judge2 = &person_map[&PersonID::JUDGE_ID_2];
+
+    assert!(simulation
+        .state
+        .population
+        .controlled_id_set
+        .contains(&PersonID::JUDGE_ID_2));
+
+    let judge1_offset = judge1.transform.world_position - JUDGE_SPAWN_POSITION;
+    let judge2_offset = judge2.transform.world_position - JUDGE_SPAWN_POSITION;
+
+    assert!(judge1_offset.xy().mag() < 0.01);
+    assert!((judge2_offset.xy() - Vec2::new(2.0, 0.0)).mag() > 1.0);
+
+    let (view, world_view) = Viewer::get_view(&mut view_output);
+
+    assert!(!world_view.sector_view_map.is_empty());
+
+    assert_eq!(
+        view.population_view.leadership_view.judge_id,
+        PersonID::JUDGE_ID_2
+    );
+
+    let judge2_view = &view.population_view.person_view_map[&PersonID::JUDGE_ID_2];
+
+    assert_eq!(
+        judge2_view.transform.world_position,
+        judge2.transform.world_position
+    );
+}
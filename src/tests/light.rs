@@ -0,0 +1,180 @@
+use crate::simulation::state::world::{
+    block::block_kind::BlockKind,
+    grid::Direction,
+    light::{light_channel::LightChannel, Light},
+    sector::sector_index::SectorIndex,
+    World,
+};
+use ultraviolet::IVec3;
+
+fn create_lit_world() -> World {
+    let mut world = World::new(813);
+
+    for z in -1..=1 {
+        for y in -1..=1 {
+            for x in -1..=1 {
+                World::load_sector(SectorIndex::new(IVec3::new(x, y, z)), &mut world);
+            }
+        }
+    }
+
+    Light::update(&mut world);
+
+    world
+}
+
+fn get_level(grid_position: IVec3, light_channel: &LightChannel, world: &World) -> u8 {
+    Light::get_level(grid_position, light_channel, &world.sector_map).expect("Sector not resident")
+}
+
+fn get_light_snapshot(world: &World) -> Vec<u8> {
+    let light_field_vec: Vec<_> = world
+        .sector_map
+        .values()
+        .map(|sector| &sector.light_field)
+        .collect();
+
+    bincode::serialize(&light_field_vec).expect("Failed to serialize light")
+}
+
+#[test]
+fn sky_light_falls_and_is_shadowed_by_roofs() {
+    let mut world = create_lit_world();
+
+    assert_eq!(
+        get_level(IVec3::new(0, 0, -20), &LightChannel::Sky, &world),
+        15
+    );
+
+    World::set_block_cube(
+        IVec3::new(-12, -12, 4),
+        IVec3::new(12, 12, 4),
+        &Direction::North,
+        &BlockKind::Smooth3,
+        &mut world,
+    );
+
+    let sector_version = world.sector_map[&SectorIndex::new(IVec3::new(0, 0, -1))].version;
+
+    Light::update(&mut world);
+
+    assert_eq!(
+        get_level(IVec3::new(0, 0, 5), &LightChannel::Sky, &world),
+        15
+    );
+    assert_eq!(
+        get_level(IVec3::new(0, 0, 3), &LightChannel::Sky, &world),
+        2
+    );
+    assert_eq!(
+        get_level(IVec3::new(0, 0, -20), &LightChannel::Sky, &world),
+        2
+    );
+    assert_eq!(
+        get_level(IVec3::new(13, 0, -20), &LightChannel::Sky, &world),
+        15
+    );
+    assert_ne!(
+        world.sector_map[&SectorIndex::new(IVec3::new(0, 0, -1))].version,
+        sector_version
+    );
+
+    World::remove_block(IVec3::new(0, 0, 4), &mut world);
+
+    Light::update(&mut world);
+
+    assert_eq!(
+        get_level(IVec3::new(0, 0, -20), &LightChannel::Sky, &world),
+        15
+    );
+    assert_eq!(
+        get_level(IVec3::new(1, 0, -20), &LightChannel::Sky, &world),
+        14
+    );
+}
+
+#[test]
+fn block_light_spreads_from_emitters_and_is_removed_with_them() {
+    let mut world = create_lit_world();
+
+    World::set_block_cube(
+        IVec3::new(-25, -25, 8),
+        IVec3::new(25, 25, 8),
+        &Direction::North,
+        &BlockKind::Smooth3,
+        &mut world,
+    );
+
+    World::set_block(
+        IVec3::new(7, 0, 0),
+        &Direction::North,
+        &BlockKind::Server1,
+        &mut world,
+    );
+
+    Light::update(&mut world);
+
+    let emission = BlockKind::get_light_emission(&BlockKind::Server1);
+
+    assert_eq!(
+        get_level(IVec3::new(7, 0, 0), &LightChannel::Block, &world),
+        emission
+    );
+    assert_eq!(
+        get_level(IVec3::new(10, 0, 0), &LightChannel::Block, &world),
+        emission - 3
+    );
+    assert_eq!(
+        get_level(IVec3::new(7, 2, 2), &LightChannel::Block, &world),
+        emission - 4
+    );
+
+    World::remove_block(IVec3::new(7, 0, 0), &mut world);
+
+    Light::update(&mut world);
+
+    assert_eq!(
+        get_level(IVec3::new(10, 0, 0), &LightChannel::Block, &world),
+        0
+    );
+}
+
+#[test]
+fn incremental_updates_match_a_full_relight() {
+    let mut world = create_lit_world();
+
+    World::set_block_cube(
+        IVec3::new(-20, -20, 6),
+        IVec3::new(20, 20, 6),
+        &Direction::North,
+        &BlockKind::Smooth3,
+        &mut world,
+    );
+
+    Light::update(&mut world);
+
+    World::set_block(
+        IVec3::new(-3, 4, -2),
+        &Direction::North,
+        &BlockKind::Vent1,
+        &mut world,
+    );
+    World::remove_block(IVec3::new(20, 0, 6), &mut world);
+    World::set_block_cube(
+        IVec3::new(-8, -8, -12),
+        IVec3::new(8, 8, -12),
+        &Direction::North,
+        &BlockKind::Smooth3,
+        &mut world,
+    );
+
+    Light::update(&mut world);
+
+    let incremental_snapshot = get_light_snapshot(&world);
+
+    world.light_sector_set = world.sector_map.keys().copied().collect();
+
+    Light::update(&mut world);
+
+    assert!(incremental_snapshot == get_light_snapshot(&world));
+}
@@ -0,0 +1,226 @@
+use crate::{
+    simulation::{
+        constants::*,
+        state::{
+            action::act::{Act, JumpData},
+            physics::{
+                body::{body_label::BodyLabel, Body, Contact, ContactSet},
+                collider::Collider,
+            },
+            population::{
+                motion::Stance,
+                person::{person_id::PersonID, Person},
+            },
+            world::{block::block_kind::BlockKind, grid::Direction},
+            Population, State, World,
+        },
+    },
+    tests::common::{get_judge, get_judge_mut, run_ticks},
+};
+use ultraviolet::{IVec3, Vec3};
+
+const FORWARD: Vec3 = Vec3::new(1.0, 0.0, 0.0);
+
+fn create_floor_state() -> State {
+    let mut state = State::new();
+
+    state.world = World::new(813);
+
+    World::set_block_cube(
+        IVec3::new(-40, -4, -1),
+        IVec3::new(40, 4, -1),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut state.world,
+    );
+
+    Population::generate_judge(PersonID::JUDGE_ID_1, &mut state.population);
+
+    let judge = get_judge_mut(&mut state);
+
+    Person::set_world_position(Vec3::new(-36.0, 0.0, 0.0), judge);
+
+    run_ticks(4, Vec3::zero(), Stance::Walk, &mut state);
+
+    state
+}
+
+fn get_horizontal_speed(state: &State) -> f32 {
+    get_judge(state).motion.velocity.xy().mag()
+}
+
+fn get_core_radius(state: &State) -> Vec3 {
+    Collider::get_radius(
+        Body::get_collider(&BodyLabel::Core, &get_judge(state).body).expect("Body is missing core"),
+    )
+}
+
+fn run_to_top_speed(stance: Stance, state: &mut State) -> f32 {
+    run_ticks(1, FORWARD, stance, state);
+
+    let first_speed = get_horizontal_speed(state);
+
+    run_ticks(10, FORWARD, stance, state);
+
+    first_speed
+}
+
+#[test]
+fn judge_accelerates_and_decelerates_instead_of_snapping() {
+    let mut state = create_floor_state();
+
+    let ground_speed = get_judge(&state).motion.ground_speed;
+
+    let first_speed = run_to_top_speed(Stance::Walk, &mut state);
+
+    assert!(first_speed > 0.0);
+    assert!(first_speed < ground_speed);
+    assert!((get_horizontal_speed(&state) - ground_speed).abs() < 1e-3);
+
+    run_ticks(1, Vec3::zero(), Stance::Walk, &mut state);
+
+    let coasting_speed = get_horizontal_speed(&state);
+
+    assert!(coasting_speed > 0.0);
+    assert!(coasting_speed < ground_speed);
+
+    run_ticks(10, Vec3::zero(), Stance::Walk, &mut state);
+
+    assert_eq!(get_horizontal_speed(&state), 0.0);
+}
+
+#[test]
+fn stances_set_the_top_speed() {
+    let mut state = create_floor_state();
+
+    let motion = get_judge(&state).motion.clone();
+
+    for (stance, stance_speed) in [
+        (Stance::Walk, motion.ground_speed),
+        (Stance::Sprint, motion.sprint_speed),
+        (Stance::Crouch, motion.crouch_speed),
+    ] {
+        run_to_top_speed(stance, &mut state);
+
+        assert_eq!(get_judge(&state).motion.stance, stance);
+        assert!((get_horizontal_speed(&state) - stance_speed).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn air_control_only_bends_the_jump() {
+    let mut state = create_floor_state();
+
+    run_to_top_speed(Stance::Walk, &mut state);
+
+    let jump_data = JumpData {
+        person_id: PersonID::JUDGE_ID_1,
+    };
+
+    state.action.act_deque.push_back(Act::Jump(jump_data));
+
+    run_ticks(1, FORWARD, Stance::Walk, &mut state);
+
+    assert!(!ContactSet::has(
+        Contact::Ground,
+        &get_judge(&state).body.contact_set
+    ));
+
+    let motion = &get_judge(&state).motion;
+
+    let air_speed_change =
+        motion.air_control * motion.ground_acceleration * SIMULATION_TICK_IN_SECONDS;
+
+    let launch_velocity_x = motion.velocity.x;
+
+    run_ticks(1, -FORWARD, Stance::Walk, &mut state);
+
+    let turn_velocity_x = get_judge(&state).motion.velocity.x;
+
+    assert!(turn_velocity_x < launch_velocity_x);
+    assert!((launch_velocity_x - turn_velocity_x - air_speed_change).abs() < 1e-3);
+
+    run_ticks(1, Vec3::zero(), Stance::Walk, &mut state);
+
+    assert_eq!(get_judge(&state).motion.velocity.x, turn_velocity_x);
+}
+
+#[test]
+fn crouching_shrinks_the_core_until_there_is_room_to_stand() {
+    let mut state = create_floor_state();
+
+    let stand_radius = get_core_radius(&state);
+    let stand_sight_z = get_judge(&state).sight.local_position.z;
+    let crouch_scale = get_judge(&state).motion.crouch_scale;
+
+    run_ticks(1, Vec3::zero(), Stance::Crouch, &mut state);
+
+    let judge = get_judge(&state);
+
+    assert!((get_core_radius(&state).z - stand_radius.z * crouch_scale).abs() < 1e-6);
+    assert!(judge.sight.local_position.z < stand_sight_z);
+    assert!(ContactSet::has(Contact::Ground, &judge.body.contact_set));
+
+    let ceiling_grid_position = IVec3::new(-36, 0, 1);
+
+    World::set_block_cube(
+        ceiling_grid_position - IVec3::new(1, 1, 0),
+        ceiling_grid_position + IVec3::new(1, 1, 0),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut state.world,
+    );
+
+    run_ticks(1, Vec3::zero(), Stance::Walk, &mut state);
+
+    assert_eq!(get_judge(&state).motion.stance, Stance::Crouch);
+
+    World::remove_block_cube(
+        ceiling_grid_position - IVec3::new(1, 1, 0),
+        ceiling_grid_position + IVec3::new(1, 1, 0),
+        &mut state.world,
+    );
+
+    run_ticks(1, Vec3::zero(), Stance::Walk, &mut state);
+
+    assert_eq!(get_judge(&state).motion.stance, Stance::Walk);
+    assert!((get_core_radius(&state).z - stand_radius.z).abs() < 1e-6);
+    assert!((get_judge(&state).sight.local_position.z - stand_sight_z).abs() < 1e-6);
+}
+
+#[test]
+fn crouch_cycles_restore_the_exact_standing_core() {
+    let mut state = create_floor_state();
+
+    let stand_radius = get_core_radius(&state);
+
+    for _ in 0..1000 {
+        run_ticks(1, Vec3::zero(), Stance::Crouch, &mut state);
+        run_ticks(1, Vec3::zero(), Stance::Walk, &mut state);
+    }
+
+    assert_eq!(get_judge(&state).motion.stance, Stance::Walk);
+    assert_eq!(get_core_radius(&state), stand_radius);
+}
+
+#[test]
+fn citizens_are_tuned_separately_from_the_judge() {
+    let state = create_floor_state();
+
+    let mut population = Population::new(813);
+
+    let person = Population::generate_person(&mut population);
+
+    let judge_motion = &get_judge(&state).motion;
+
+    assert_eq!(judge_motion.sprint_speed, JUDGE_DEFAULT_SPRINT_SPEED);
+    assert_eq!(
+        judge_motion.ground_acceleration,
+        JUDGE_DEFAULT_GROUND_ACCELERATION
+    );
+    assert_eq!(person.motion.sprint_speed, PERSON_DEFAULT_SPRINT_SPEED);
+    assert_eq!(
+        person.motion.ground_acceleration,
+        PERSON_DEFAULT_GROUND_ACCELERATION
+    );
+}
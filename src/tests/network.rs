@@ -0,0 +1,209 @@
+use crate::{
+    interface::client::Client,
+    simulation::{
+        state::{
+            population::person::person_id::PersonID,
+            world::{
+                block::block_kind::BlockKind,
+                grid::{self, Direction},
+            },
+            World,
+        },
+        supervisor::{
+            message::{SeedData, SetBlockData},
+            recorder::RecorderMode,
+            viewer::{view::SectorView, ViewOutput},
+            Message, Server, Viewer,
+        },
+        Simulation,
+    },
+    tests::common::{create_simulation, get_changed_sector_count, get_visible_sector_version_map},
+};
+use std::time::Duration;
+use ultraviolet::IVec3;
+
+struct TestClient {
+    client: Client,
+    message_tx: crossbeam::channel::Sender<Message>,
+    view_output: ViewOutput,
+}
+
+fn connect_client(simulation: &Simulation) -> TestClient {
+    let (message_tx, message_rx) = crossbeam::channel::unbounded::<Message>();
+    let (view_input, view_output) = Viewer::create_view_channel();
+
+    let local_addr = Server::get_local_addr(&simulation.supervisor.server).unwrap();
+
+    let client = Client::connect(&local_addr.to_string(), message_rx, view_input)
+        .expect("Failed to connect client");
+
+    TestClient {
+        client,
+        message_tx,
+        view_output,
+    }
+}
+
+fn run_ticks(tick_count: u32, simulation: &mut Simulation, test_client_vec: &mut [TestClient]) {
+    for _ in 0..tick_count {
+        for test_client in test_client_vec.iter_mut() {
+            assert!(Client::tick(&mut test_client.client));
+        }
+
+        std::thread::sleep(Duration::from_millis(1));
+
+        Simulation::run_headless(
+            1,
+            &mut simulation.supervisor,
+            &mut simulation.state,
+            |_, _| (),
+        );
+
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    for test_client in test_client_vec.iter_mut() {
+        assert!(Client::tick(&mut test_client.client));
+    }
+}
+
+fn get_sent_sector_count(person_id: PersonID, simulation: &Simulation) -> usize {
+    simulation
+        .supervisor
+        .server
+        .server_client_vec
+        .iter()
+        .find(|server_client| server_client.person_id == person_id)
+        .expect("Server client is missing")
+        .sent_sector_count
+}
+
+#[test]
+fn remote_clients_receive_authoritative_views() {
+    let (mut simulation, _message_tx) = create_simulation(RecorderMode::Off);
+
+    simulation.supervisor.server = Server::bind("127.0.0.1:0").expect("Failed to bind server");
+
+    Viewer::detach(&PersonID::JUDGE_ID_1, &mut simulation.supervisor.viewer);
+
+    let mut test_client_vec = vec![connect_client(&simulation)];
+
+    run_ticks(2, &mut simulation, &mut test_client_vec);
+
+    assert_eq!(
+        test_client_vec[0].client.person_id_option,
+        Some(PersonID::JUDGE_ID_1)
+    );
+
+    test_client_vec[0]
+        .message_tx
+        .send(Message::SetSeed(SeedData { seed: 813 }))
+        .unwrap();
+    test_client_vec[0]
+        .message_tx
+        .send(Message::Generate)
+        .unwrap();
+
+    run_ticks(60, &mut simulation, &mut test_client_vec);
+
+    let judge_world_position = simulation.state.population.person_map[&PersonID::JUDGE_ID_1]
+        .transform
+        .world_position;
+
+    {
+        let (view, world_view) = Viewer::get_view(&mut test_client_vec[0].view_output);
+
+        assert_eq!(
+            view.population_view.leadership_view.judge_id,
+            PersonID::JUDGE_ID_1
+        );
+        assert!(view
+            .population_view
+            .person_view_map
+            .contains_key(&PersonID::JUDGE_ID_1));
+        assert!(!world_view.sector_view_map.is_empty());
+    }
+
+    let sent_sector_count = get_sent_sector_count(PersonID::JUDGE_ID_1, &simulation);
+
+    assert!(sent_sector_count > 0);
+
+    run_ticks(20, &mut simulation, &mut test_client_vec);
+
+    assert_eq!(
+        get_sent_sector_count(PersonID::JUDGE_ID_1, &simulation),
+        sent_sector_count
+    );
+
+    let grid_position =
+        grid::world_position_to_grid_position(judge_world_position) + IVec3::new(0, 0, 3);
+
+    let set_block_data = SetBlockData {
+        person_id: PersonID::JUDGE_ID_1,
+        grid_position,
+        block_kind_option: Some(BlockKind::EagleStone),
+        direction: Direction::East,
+    };
+
+    let sector_version_map =
+        get_visible_sector_version_map(&PersonID::JUDGE_ID_1, &simulation.state);
+
+    test_client_vec[0]
+        .message_tx
+        .send(Message::SetBlock(set_block_data))
+        .unwrap();
+
+    run_ticks(4, &mut simulation, &mut test_client_vec);
+
+    let changed_sector_count = get_changed_sector_count(&sector_version_map, &simulation.state);
+
+    assert!(changed_sector_count < sector_version_map.len());
+    assert_eq!(
+        get_sent_sector_count(PersonID::JUDGE_ID_1, &simulation),
+        sent_sector_count + changed_sector_count
+    );
+
+    {
+        let (sector_index, cell_index) = grid::grid_position_to_indices(grid_position);
+
+        let (_, world_view) = Viewer::get_view(&mut test_client_vec[0].view_output);
+        let sector_view = &world_view.sector_view_map[&sector_index];
+
+        let block = SectorView::get_block(cell_index, sector_view).expect("Block was not synced");
+
+        assert_eq!(block.block_kind, BlockKind::EagleStone);
+    }
+
+    test_client_vec.push(connect_client(&simulation));
+
+    run_ticks(4, &mut simulation, &mut test_client_vec);
+
+    assert_eq!(
+        test_client_vec[1].client.person_id_option,
+        Some(PersonID::JUDGE_ID_2)
+    );
+    assert!(simulation
+        .state
+        .population
+        .person_map
+        .contains_key(&PersonID::JUDGE_ID_2));
+
+    let guest_grid_position = grid_position + IVec3::new(1, 0, 0);
+
+    let set_block_data = SetBlockData {
+        person_id: PersonID::JUDGE_ID_2,
+        grid_position: guest_grid_position,
+        block_kind_option: Some(BlockKind::EagleStone),
+        direction: Direction::East,
+    };
+
+    test_client_vec[1]
+        .message_tx
+        .send(Message::SetBlock(set_block_data))
+        .unwrap();
+
+    run_ticks(4, &mut simulation, &mut test_client_vec);
+
+    assert!(World::get_block(guest_grid_position, &simulation.state.world.sector_map).is_none());
+    assert!(get_sent_sector_count(PersonID::JUDGE_ID_2, &simulation) > 0);
+}
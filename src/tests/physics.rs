@@ -0,0 +1,525 @@
+use crate::simulation::{
+    constants::*,
+    state::{
+        action::act::{Act, PlaceBlockData},
+        physics::{
+            body::{Body, Contact, ContactSet},
+            spatial_hash::SpatialHash,
+        },
+        population::{
+            motion,
+            person::{person_id::PersonID, Person},
+        },
+        world::{
+            area::{
+                area_id::AreaID,
+                template::{ElevatorTemplate, Template},
+                Area,
+            },
+            block::block_kind::BlockKind,
+            grid::Direction,
+            tower::Tower,
+        },
+        Physics, Population, State, World,
+    },
+};
+use crate::tests::common::{get_judge, get_judge_mut, run_ticks};
+use crate::utils::ldmath::FloatBox;
+use ultraviolet::{IVec3, Vec2, Vec3};
+
+const LADDER_TOP: f32 = 3.5;
+
+const STAIRS_WAYPOINT_ARRAY: [Vec2; 6] = [
+    Vec2::new(-2.0, -1.0),
+    Vec2::new(-1.0, -1.0),
+    Vec2::new(-1.0, 1.0),
+    Vec2::new(1.0, 1.0),
+    Vec2::new(1.0, -1.0),
+    Vec2::new(-1.0, -1.0),
+];
+
+fn create_ladder_shaft_state() -> State {
+    let mut state = State::new();
+
+    state.world = World::new(813);
+
+    World::set_block_cube(
+        IVec3::new(-3, -3, -1),
+        IVec3::new(3, 3, -1),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut state.world,
+    );
+
+    World::set_block_cube(
+        IVec3::new(-3, 1, 0),
+        IVec3::new(3, 3, 3),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut state.world,
+    );
+
+    World::set_block_cube(
+        IVec3::new(0, 0, 0),
+        IVec3::new(0, 0, 3),
+        &Direction::North,
+        &BlockKind::Ladder1,
+        &mut state.world,
+    );
+
+    Population::generate_judge(PersonID::JUDGE_ID_1, &mut state.population);
+
+    let judge = get_judge_mut(&mut state);
+
+    Person::set_world_position(Vec3::new(0.0, 0.0, 0.1), judge);
+
+    run_ticks(10, Vec3::zero(), motion::Stance::Walk, &mut state);
+
+    state
+}
+
+fn create_elevator_state() -> State {
+    let mut state = State::new();
+
+    state.world = World::new(813);
+
+    let mut area = Area::new(AreaID::new(1));
+    area.grid_position = Tower::get_center_grid_position(0);
+    area.size = Tower::get_center_size();
+
+    ElevatorTemplate::construct(&area, &mut state.world);
+
+    Population::generate_judge(PersonID::JUDGE_ID_1, &mut state.population);
+
+    let judge = get_judge_mut(&mut state);
+
+    Person::set_world_position(Vec3::new(-2.0, -1.0, 1.1), judge);
+
+    judge.motion.ground_speed = 2.0;
+
+    run_ticks(10, Vec3::zero(), motion::Stance::Walk, &mut state);
+
+    state
+}
+
+fn create_crowd_state() -> State {
+    let mut state = State::new();
+
+    state.world = World::new(813);
+
+    World::set_block_cube(
+        IVec3::new(-8, -8, -1),
+        IVec3::new(8, 8, -1),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut state.world,
+    );
+
+    World::set_block_cube(
+        IVec3::new(8, -8, 0),
+        IVec3::new(8, 8, 2),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut state.world,
+    );
+
+    for index in 0..49 {
+        let mut person = Population::generate_person(&mut state.population);
+
+        let world_position = Vec3::new(
+            (2 * (index % 7) - 6) as f32,
+            (2 * (index / 7) - 6) as f32,
+            1.0 + 0.37 * (index % 5) as f32,
+        );
+
+        Person::set_world_position(world_position, &mut person);
+        Person::set_velocity(Vec3::new(4.0 * (index % 3) as f32, 0.0, 0.0), &mut person);
+
+        state.population.person_map.insert(person.person_id, person);
+    }
+
+    state
+}
+
+fn create_pair_state(is_colliding: bool) -> State {
+    let mut state = State::new();
+
+    state.world = World::new(813);
+
+    World::set_block_cube(
+        IVec3::new(-3, -3, -1),
+        IVec3::new(3, 3, -1),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut state.world,
+    );
+
+    for offset_x in [0.0, 0.1] {
+        let mut person = Population::generate_person(&mut state.population);
+
+        Body::set_colliding(is_colliding, &mut person.body);
+
+        Person::set_world_position(Vec3::new(offset_x, 0.0, 0.0), &mut person);
+
+        state.population.person_map.insert(person.person_id, person);
+    }
+
+    state
+}
+
+fn get_pair_distance(state: &State) -> f32 {
+    let person_vec: Vec<&Person> = state.population.person_map.values().collect();
+
+    (person_vec[0].transform.world_position - person_vec[1].transform.world_position)
+        .xy()
+        .mag()
+}
+
+fn walk_to(target: Vec2, state: &mut State) -> bool {
+    let mut ground_contact_kept = true;
+
+    let step_distance = get_judge(state).motion.ground_speed * SIMULATION_TICK_IN_SECONDS;
+
+    for _ in 0..40 {
+        let offset = target - get_judge(state).transform.world_position.xy();
+
+        if offset.mag() < 0.5 * step_distance {
+            return ground_contact_kept;
+        }
+
+        let move_direction = Vec3::new(offset.x, offset.y, 0.0);
+
+        run_ticks(1, move_direction, motion::Stance::Walk, state);
+
+        ground_contact_kept &= has_contact(Contact::Ground, state);
+    }
+
+    panic!("Judge did not reach {:?}", target);
+}
+
+fn get_feet_z(state: &State) -> f32 {
+    get_judge(state).transform.world_position.z - CELL_RADIUS_IN_METERS
+}
+
+fn has_contact(contact: Contact, state: &State) -> bool {
+    ContactSet::has(contact, &get_judge(state).body.contact_set)
+}
+
+#[test]
+fn ladder_is_a_trigger_that_does_not_block_movement() {
+    let mut state = create_ladder_shaft_state();
+
+    assert!(has_contact(Contact::Ground, &state));
+    assert!(has_contact(Contact::Ladder, &state));
+    assert_eq!(get_judge(&state).motion.mode, motion::Mode::Ground);
+
+    run_ticks(
+        10,
+        Vec3::new(1.0, 0.0, 0.0),
+        motion::Stance::Walk,
+        &mut state,
+    );
+
+    let judge = get_judge(&state);
+
+    assert!(judge.transform.world_position.x > 1.0);
+    assert!(!has_contact(Contact::Ladder, &state));
+    assert_eq!(judge.motion.mode, motion::Mode::Ground);
+}
+
+#[test]
+fn judge_climbs_to_the_top_of_the_ladder_and_steps_off() {
+    let mut state = create_ladder_shaft_state();
+
+    run_ticks(
+        1,
+        Vec3::new(0.0, 0.0, 1.0),
+        motion::Stance::Walk,
+        &mut state,
+    );
+
+    assert_eq!(get_judge(&state).motion.mode, motion::Mode::Climb);
+
+    run_ticks(
+        40,
+        Vec3::new(0.0, 0.0, 1.0),
+        motion::Stance::Walk,
+        &mut state,
+    );
+
+    let judge = get_judge(&state);
+
+    let feet_z = judge.transform.world_position.z - 0.5;
+
+    assert_eq!(judge.motion.mode, motion::Mode::Climb);
+    assert!(has_contact(Contact::Ladder, &state));
+    assert!(feet_z > LADDER_TOP);
+    assert!(feet_z < LADDER_TOP + 0.1);
+
+    run_ticks(
+        5,
+        Vec3::new(0.0, 1.0, 1.0),
+        motion::Stance::Walk,
+        &mut state,
+    );
+    run_ticks(5, Vec3::zero(), motion::Stance::Walk, &mut state);
+
+    let judge = get_judge(&state);
+
+    let feet_z = judge.transform.world_position.z - 0.5;
+
+    assert_eq!(judge.motion.mode, motion::Mode::Ground);
+    assert!(has_contact(Contact::Ground, &state));
+    assert!(!has_contact(Contact::Ladder, &state));
+    assert!(judge.transform.world_position.y > 1.0);
+    assert!((feet_z - LADDER_TOP).abs() < 0.01);
+}
+
+#[test]
+fn judge_detaches_at_the_bottom_of_the_ladder() {
+    let mut state = create_ladder_shaft_state();
+
+    run_ticks(
+        5,
+        Vec3::new(0.0, 0.0, 1.0),
+        motion::Stance::Walk,
+        &mut state,
+    );
+
+    let climb_z = get_judge(&state).transform.world_position.z;
+
+    assert!(climb_z > 1.0);
+
+    run_ticks(5, Vec3::zero(), motion::Stance::Walk, &mut state);
+
+    let judge = get_judge(&state);
+
+    assert_eq!(judge.motion.mode, motion::Mode::Climb);
+    assert!((judge.transform.world_position.z - climb_z).abs() < 0.01);
+
+    run_ticks(
+        40,
+        Vec3::new(0.0, 0.0, -1.0),
+        motion::Stance::Walk,
+        &mut state,
+    );
+
+    let judge = get_judge(&state);
+
+    assert_eq!(judge.motion.mode, motion::Mode::Ground);
+    assert!(has_contact(Contact::Ground, &state));
+    assert!(judge.transform.world_position.z < 0.1);
+}
+
+#[test]
+fn judge_walks_up_the_elevator_stairs() {
+    let mut state = create_elevator_state();
+
+    assert!((get_feet_z(&state) - 0.5).abs() < 0.01);
+
+    for waypoint in &STAIRS_WAYPOINT_ARRAY[1..] {
+        walk_to(*waypoint, &mut state);
+    }
+
+    assert!((get_feet_z(&state) - 4.5).abs() < 0.01);
+    assert!(has_contact(Contact::Ground, &state));
+}
+
+#[test]
+fn judge_walks_down_the_elevator_stairs_without_losing_ground() {
+    let mut state = create_elevator_state();
+
+    for waypoint in &STAIRS_WAYPOINT_ARRAY[1..] {
+        walk_to(*waypoint, &mut state);
+    }
+
+    for waypoint in STAIRS_WAYPOINT_ARRAY.iter().rev().skip(1) {
+        assert!(walk_to(*waypoint, &mut state));
+    }
+
+    assert!((get_feet_z(&state) - 0.5).abs() < 0.01);
+}
+
+#[test]
+fn step_up_is_limited_to_one_stair() {
+    let mut state = create_ladder_shaft_state();
+
+    World::set_block(
+        IVec3::new(0, -1, 0),
+        &Direction::North,
+        &BlockKind::Metal1,
+        &mut state.world,
+    );
+
+    run_ticks(
+        10,
+        Vec3::new(0.0, -1.0, 0.0),
+        motion::Stance::Walk,
+        &mut state,
+    );
+
+    let judge = get_judge(&state);
+
+    assert!(judge.transform.world_position.y > -0.4);
+    assert!(get_feet_z(&state) < -0.4);
+}
+
+#[test]
+fn citizens_fall_and_rest_on_the_floor() {
+    let mut state = create_crowd_state();
+
+    for _ in 0..40 {
+        Physics::tick(&state.world, &mut state.population, &mut state.physics);
+    }
+
+    for person in state.population.person_map.values() {
+        let feet_z = person.transform.world_position.z - CELL_RADIUS_IN_METERS;
+
+        assert!((feet_z + 0.5).abs() < 0.01);
+        assert!(ContactSet::has(Contact::Ground, &person.body.contact_set));
+        assert!(person.transform.world_position.x < 7.5 - PERSON_DEFAULT_RADIUS_X);
+    }
+}
+
+#[test]
+fn parallel_physics_matches_a_single_thread() {
+    let mut parallel_state = create_crowd_state();
+    let mut serial_state = create_crowd_state();
+
+    let thread_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .expect("Failed to build thread pool");
+
+    for _ in 0..40 {
+        Physics::tick(
+            &parallel_state.world,
+            &mut parallel_state.population,
+            &mut parallel_state.physics,
+        );
+
+        thread_pool.install(|| {
+            Physics::tick(
+                &serial_state.world,
+                &mut serial_state.population,
+                &mut serial_state.physics,
+            )
+        });
+    }
+
+    for (person_id, parallel_person) in &parallel_state.population.person_map {
+        let serial_person = &serial_state.population.person_map[person_id];
+
+        assert_eq!(
+            parallel_person.transform.world_position,
+            serial_person.transform.world_position
+        );
+        assert_eq!(
+            parallel_person.motion.velocity,
+            serial_person.motion.velocity
+        );
+    }
+}
+
+#[test]
+fn overlapping_persons_are_pushed_apart() {
+    let mut state = create_pair_state(true);
+
+    for _ in 0..20 {
+        Physics::tick(&state.world, &mut state.population, &mut state.physics);
+    }
+
+    assert!(get_pair_distance(&state) > 2.0 * PERSON_DEFAULT_RADIUS_X - 0.01);
+
+    for person in state.population.person_map.values() {
+        assert!(ContactSet::has(Contact::Ground, &person.body.contact_set));
+    }
+}
+
+#[test]
+fn non_colliding_persons_pass_through_each_other() {
+    let mut state = create_pair_state(false);
+
+    for _ in 0..20 {
+        Physics::tick(&state.world, &mut state.population, &mut state.physics);
+    }
+
+    assert!((get_pair_distance(&state) - 0.1).abs() < 0.001);
+}
+
+#[test]
+fn spatial_hash_answers_float_box_queries() {
+    let mut state = create_crowd_state();
+
+    Physics::tick(&state.world, &mut state.population, &mut state.physics);
+
+    let spatial_hash = &state.physics.spatial_hash;
+
+    for (person_id, person) in &state.population.person_map {
+        let float_box = FloatBox::new(person.transform.world_position, Vec3::broadcast(0.1));
+
+        assert_eq!(
+            SpatialHash::query(&float_box, spatial_hash),
+            vec![*person_id]
+        );
+    }
+
+    let wide_float_box = FloatBox::new(Vec3::zero(), Vec3::new(8.0, 8.0, 4.0));
+    let empty_float_box = FloatBox::new(Vec3::new(0.0, 0.0, 20.0), Vec3::one());
+
+    assert_eq!(
+        SpatialHash::query(&wide_float_box, spatial_hash).len(),
+        state.population.person_map.len()
+    );
+    assert!(SpatialHash::query(&empty_float_box, spatial_hash).is_empty());
+}
+
+#[test]
+fn block_placement_is_blocked_by_persons() {
+    let mut state = create_ladder_shaft_state();
+
+    let feet_grid_position = IVec3::new(0, -1, 0);
+
+    Person::set_world_position(Vec3::new(0.0, -1.0, 0.0), get_judge_mut(&mut state));
+    Person::set_rotation(0.0, -89.0, get_judge_mut(&mut state));
+
+    run_ticks(2, Vec3::zero(), motion::Stance::Walk, &mut state);
+
+    let judge = &state.population.person_map[&PersonID::JUDGE_ID_1];
+
+    State::place_block(&judge, &state.physics.spatial_hash, &mut state.world);
+
+    assert!(World::get_block(feet_grid_position, &state.world.sector_map).is_none());
+
+    State::place_block(&judge, &SpatialHash::new(), &mut state.world);
+
+    assert!(World::get_block(feet_grid_position, &state.world.sector_map).is_some());
+}
+
+#[test]
+fn block_placement_after_load_still_sees_persons() {
+    let mut state = create_ladder_shaft_state();
+
+    let feet_grid_position = IVec3::new(0, -1, 0);
+
+    Person::set_world_position(Vec3::new(0.0, -1.0, 0.0), get_judge_mut(&mut state));
+    Person::set_rotation(0.0, -89.0, get_judge_mut(&mut state));
+
+    run_ticks(2, Vec3::zero(), motion::Stance::Walk, &mut state);
+
+    state.physics.spatial_hash = SpatialHash::new();
+    state.active = true;
+
+    let place_block_data = PlaceBlockData {
+        person_id: PersonID::JUDGE_ID_1,
+    };
+
+    state
+        .action
+        .act_deque
+        .push_back(Act::PlaceBlock(place_block_data));
+
+    State::tick(&mut state);
+
+    assert!(World::get_block(feet_grid_position, &state.world.sector_map).is_none());
+}
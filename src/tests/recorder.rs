@@ -0,0 +1,43 @@
+use crate::{
+    simulation::{supervisor::recorder::RecorderMode, Simulation},
+    tests::common::{create_simulation, get_snapshot, send_generate_messages, send_scripted_input},
+};
+use std::path::Path;
+
+fn run_session(path: &Path) -> Vec<u8> {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Record(path.to_path_buf()));
+
+    send_generate_messages(813, &message_tx);
+
+    Simulation::run_headless(
+        100,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |supervisor, _| send_scripted_input(supervisor.tick, &message_tx),
+    );
+
+    get_snapshot(&simulation.state)
+}
+
+#[test]
+fn replay_reproduces_recorded_state() {
+    let path = std::env::temp_dir().join(format!(
+        "last_ditch_recorder_test_{}.ldreplay",
+        std::process::id()
+    ));
+
+    let recorded_snapshot = run_session(&path);
+
+    let (mut simulation, _message_tx) = create_simulation(RecorderMode::Replay(path.clone()));
+
+    Simulation::run_headless(
+        100,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    std::fs::remove_file(&path).expect("Failed to remove replay file");
+
+    assert_eq!(recorded_snapshot, get_snapshot(&simulation.state));
+}
@@ -0,0 +1,290 @@
+use crate::{
+    simulation::{
+        state::{
+            action::act::{
+                Act, CopyRegionData, PasteRegionData, RedoData, ReplaceRegionData, UndoData,
+            },
+            population::{nation::nation_kind::NationKind, person::person_id::PersonID},
+            world::{
+                block::{block_kind::BlockKind, block_state::BlockState},
+                door::Door,
+                grid::{self, Direction},
+            },
+            Action, State, World,
+        },
+        supervisor::{
+            message::{FillData, PersonInputData, SetBlockData, TeleportData},
+            recorder::RecorderMode,
+            Message,
+        },
+        Simulation,
+    },
+    tests::common::{create_simulation, send_generate_messages},
+    utils::ldmath::IntBox,
+};
+use ultraviolet::{IVec3, Vec3};
+
+fn apply_act(act: Act, state: &mut State) {
+    state.action.act_deque.push_back(act);
+
+    Action::tick(state);
+}
+
+fn get_block_kind_option(grid_position: IVec3, state: &State) -> Option<BlockKind> {
+    World::get_block(grid_position, &state.world.sector_map).map(|block| block.block_kind)
+}
+
+fn door_is_open(grid_position: IVec3, state: &State) -> bool {
+    let block =
+        World::get_block(grid_position, &state.world.sector_map).expect("Door block is missing");
+
+    let BlockState::Door(door_data) = block.block_state else {
+        panic!("Block is not a door");
+    };
+
+    door_data.is_open
+}
+
+#[test]
+fn regions_paste_rotated_and_undo() {
+    let mut state = State::new();
+    let person_id = PersonID::JUDGE_ID_1;
+
+    for x in 0..3 {
+        World::set_block(
+            IVec3::new(x, 0, 0),
+            &Direction::East,
+            &BlockKind::EagleStone,
+            &mut state.world,
+        );
+    }
+
+    World::set_block(
+        IVec3::new(0, 1, 0),
+        &Direction::East,
+        &BlockKind::LionStone,
+        &mut state.world,
+    );
+
+    apply_act(
+        Act::CopyRegion(CopyRegionData {
+            person_id,
+            int_box: IntBox::new(IVec3::new(0, 0, 0), IVec3::new(2, 1, 0)),
+        }),
+        &mut state,
+    );
+
+    apply_act(
+        Act::PasteRegion(PasteRegionData {
+            person_id,
+            grid_position: IVec3::new(20, 0, 0),
+            direction: Direction::West,
+        }),
+        &mut state,
+    );
+
+    for y in 0..3 {
+        let block = World::get_block(IVec3::new(20, y, 0), &state.world.sector_map)
+            .expect("Pasted block is missing");
+
+        assert_eq!(block.block_kind, BlockKind::EagleStone);
+        assert_eq!(block.direction, Direction::North);
+    }
+
+    assert_eq!(
+        get_block_kind_option(IVec3::new(19, 0, 0), &state),
+        Some(BlockKind::LionStone)
+    );
+
+    apply_act(
+        Act::ReplaceRegion(ReplaceRegionData {
+            person_id,
+            int_box: IntBox::new(IVec3::new(19, 0, 0), IVec3::new(20, 2, 0)),
+            mask_block_kind: BlockKind::EagleStone,
+            block_kind_option: None,
+        }),
+        &mut state,
+    );
+
+    assert_eq!(get_block_kind_option(IVec3::new(20, 1, 0), &state), None);
+    assert_eq!(
+        get_block_kind_option(IVec3::new(19, 0, 0), &state),
+        Some(BlockKind::LionStone)
+    );
+
+    apply_act(Act::Undo(UndoData { person_id }), &mut state);
+
+    assert_eq!(
+        get_block_kind_option(IVec3::new(20, 1, 0), &state),
+        Some(BlockKind::EagleStone)
+    );
+
+    apply_act(Act::Undo(UndoData { person_id }), &mut state);
+
+    assert_eq!(get_block_kind_option(IVec3::new(20, 1, 0), &state), None);
+    assert_eq!(get_block_kind_option(IVec3::new(19, 0, 0), &state), None);
+    assert_eq!(
+        get_block_kind_option(IVec3::new(1, 0, 0), &state),
+        Some(BlockKind::EagleStone)
+    );
+
+    apply_act(Act::Redo(RedoData { person_id }), &mut state);
+
+    assert_eq!(
+        get_block_kind_option(IVec3::new(20, 2, 0), &state),
+        Some(BlockKind::EagleStone)
+    );
+    assert_eq!(
+        get_block_kind_option(IVec3::new(19, 0, 0), &state),
+        Some(BlockKind::LionStone)
+    );
+
+    let edit_journal = &state.world.edit_journal_map[&person_id];
+
+    assert_eq!(edit_journal.undo_vec.len(), 1);
+    assert_eq!(edit_journal.redo_vec.len(), 1);
+}
+
+#[test]
+fn console_edits_are_journaled() {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+    let person_id = PersonID::JUDGE_ID_1;
+
+    send_generate_messages(813, &message_tx);
+
+    Simulation::run_headless(
+        20,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let far_world_position = Vec3::new(4_000.0, -4_000.0, 2_000.0);
+    let grid_position = grid::world_position_to_grid_position(far_world_position) - IVec3::unit_z();
+
+    message_tx
+        .send(Message::Teleport(TeleportData {
+            person_id,
+            world_position: far_world_position,
+        }))
+        .unwrap();
+
+    message_tx
+        .send(Message::Fill(FillData {
+            person_id,
+            min: grid_position + IVec3::new(2, 0, 0),
+            max: grid_position,
+            block_kind_option: Some(BlockKind::EagleStone),
+            direction: Direction::North,
+        }))
+        .unwrap();
+
+    message_tx
+        .send(Message::SetBlock(SetBlockData {
+            person_id,
+            grid_position,
+            block_kind_option: Some(BlockKind::LionStone),
+            direction: Direction::North,
+        }))
+        .unwrap();
+
+    Simulation::run_headless(
+        2,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert_eq!(
+        get_block_kind_option(grid_position, &simulation.state),
+        Some(BlockKind::LionStone)
+    );
+    assert_eq!(
+        simulation.state.world.edit_journal_map[&person_id]
+            .undo_vec
+            .len(),
+        2
+    );
+
+    let person_input_data = PersonInputData { person_id };
+
+    message_tx
+        .send(Message::Undo(person_input_data.clone()))
+        .unwrap();
+
+    Simulation::run_headless(
+        1,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert_eq!(
+        get_block_kind_option(grid_position, &simulation.state),
+        Some(BlockKind::EagleStone)
+    );
+
+    message_tx.send(Message::Undo(person_input_data)).unwrap();
+
+    Simulation::run_headless(
+        1,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    for x in 0..3 {
+        assert_eq!(
+            get_block_kind_option(grid_position + IVec3::new(x, 0, 0), &simulation.state),
+            None
+        );
+    }
+}
+
+#[test]
+fn pasting_a_door_state_is_journaled() {
+    let mut state = State::new();
+    let person_id = PersonID::JUDGE_ID_1;
+
+    let source_grid_position = IVec3::new(0, 0, 0);
+    let target_grid_position = IVec3::new(10, 0, 0);
+
+    for grid_position in [source_grid_position, target_grid_position] {
+        World::set_block(
+            grid_position,
+            &Direction::North,
+            &BlockKind::DoorLower1,
+            &mut state.world,
+        );
+    }
+
+    assert!(Door::toggle_open(
+        source_grid_position,
+        &NationKind::Eagle,
+        &mut state.world
+    ));
+
+    apply_act(
+        Act::CopyRegion(CopyRegionData {
+            person_id,
+            int_box: IntBox::new(source_grid_position, source_grid_position),
+        }),
+        &mut state,
+    );
+
+    apply_act(
+        Act::PasteRegion(PasteRegionData {
+            person_id,
+            grid_position: target_grid_position,
+            direction: Direction::North,
+        }),
+        &mut state,
+    );
+
+    assert!(door_is_open(target_grid_position, &state));
+    assert_eq!(state.world.edit_journal_map[&person_id].undo_vec.len(), 1);
+
+    apply_act(Act::Undo(UndoData { person_id }), &mut state);
+
+    assert!(!door_is_open(target_grid_position, &state));
+}
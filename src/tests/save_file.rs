@@ -0,0 +1,126 @@
+use crate::simulation::{
+    constants::SAVE_FORMAT_MAGIC,
+    state::{
+        action::act::{Act, JumpData, MoveData},
+        population::{motion::Stance, person::person_id::PersonID},
+        work::{
+            construct_task::{generate_data::GenerateData, ConstructTask},
+            construct_worker::ConstructWorker,
+        },
+        SaveFile, State,
+    },
+    supervisor::scheduler::{Command, Scheduler},
+};
+use crate::tests::common::get_snapshot;
+use ultraviolet::Vec3;
+
+fn generate_state(seed: u64) -> State {
+    let mut state = State::new();
+
+    State::seed(seed, &mut state);
+
+    let construct_task = ConstructTask::Generate(GenerateData::new());
+
+    ConstructWorker::enqueue(construct_task, &mut state.work.construct_worker.task_deque);
+
+    state.active = true;
+
+    while !state.work.construct_worker.task_deque.is_empty() {
+        State::tick(&mut state);
+    }
+
+    state
+}
+
+fn tick_with_input(tick_count: u32, state: &mut State) {
+    for tick in 0..tick_count {
+        let move_data = MoveData {
+            person_id: PersonID::JUDGE_ID_1,
+            move_direction: Vec3::new(1.0, 1.0, 0.0).normalized(),
+            stance: Stance::Walk,
+        };
+
+        state.action.act_deque.push_back(Act::Move(move_data));
+
+        if tick % 10 == 0 {
+            let jump_data = JumpData {
+                person_id: PersonID::JUDGE_ID_1,
+            };
+
+            state.action.act_deque.push_back(Act::Jump(jump_data));
+        }
+
+        State::tick(state);
+    }
+}
+
+#[test]
+fn save_file_round_trip_continues_identically() {
+    let mut state = generate_state(813);
+
+    tick_with_input(20, &mut state);
+
+    let path = std::env::temp_dir().join(format!(
+        "last_ditch_save_file_test_{}.ldsave",
+        std::process::id()
+    ));
+
+    let mut scheduler = Scheduler::new();
+
+    let jump_data = JumpData {
+        person_id: PersonID::JUDGE_ID_1,
+    };
+
+    Scheduler::schedule_repeating(
+        state.world.time.tick + 5,
+        15,
+        Command::EnqueueAct(Act::Jump(jump_data)),
+        &mut scheduler,
+    );
+
+    SaveFile::write(&path, &state, &scheduler).expect("Failed to write save file");
+
+    let (mut loaded_state, loaded_scheduler) =
+        SaveFile::read(&path).expect("Failed to read save file");
+
+    std::fs::remove_file(&path).expect("Failed to remove save file");
+
+    assert_eq!(get_snapshot(&state), get_snapshot(&loaded_state));
+    assert_eq!(scheduler.job_map.len(), loaded_scheduler.job_map.len());
+    assert_eq!(scheduler.next_job_id, loaded_scheduler.next_job_id);
+
+    tick_with_input(40, &mut state);
+    tick_with_input(40, &mut loaded_state);
+
+    assert_eq!(get_snapshot(&state), get_snapshot(&loaded_state));
+}
+
+#[test]
+fn save_file_rejects_unknown_version() {
+    let path = std::env::temp_dir().join(format!(
+        "last_ditch_save_file_version_test_{}.ldsave",
+        std::process::id()
+    ));
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&SAVE_FORMAT_MAGIC);
+    bytes.extend_from_slice(&u32::MAX.to_le_bytes());
+
+    std::fs::write(&path, bytes).expect("Failed to write save file");
+
+    let result = SaveFile::read(&path);
+
+    std::fs::remove_file(&path).expect("Failed to remove save file");
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn save_names_stay_inside_the_save_directory() {
+    assert!(SaveFile::name_is_valid("autosave"));
+    assert!(SaveFile::name_is_valid("slot_2-b"));
+
+    for save_name in ["", "..", "../../x", "saves/x", "..\\x", "/tmp/x", "x.y"] {
+        assert!(!SaveFile::name_is_valid(save_name), "{save_name}");
+    }
+}
@@ -0,0 +1,85 @@
+use crate::simulation::{
+    state::{
+        action::act::{Act, JumpData},
+        population::person::person_id::PersonID,
+        State,
+    },
+    supervisor::scheduler::{Command, Scheduler},
+};
+
+fn get_jump_command(person_id: PersonID) -> Command {
+    Command::EnqueueAct(Act::Jump(JumpData { person_id }))
+}
+
+fn get_queued_person_id_vec(state: &mut State) -> Vec<PersonID> {
+    state
+        .action
+        .act_deque
+        .drain(..)
+        .map(|act| match act {
+            Act::Jump(jump_data) => jump_data.person_id,
+            _ => panic!("Unexpected Act"),
+        })
+        .collect()
+}
+
+#[test]
+fn jobs_execute_in_tick_then_insertion_order() {
+    let mut state = State::new();
+    let mut scheduler = Scheduler::new();
+
+    Scheduler::schedule(2, get_jump_command(PersonID::JUDGE_ID_2), &mut scheduler);
+    Scheduler::schedule(1, get_jump_command(PersonID::JUDGE_ID_3), &mut scheduler);
+    Scheduler::schedule(2, get_jump_command(PersonID::JUDGE_ID_1), &mut scheduler);
+
+    Scheduler::tick(&mut state, &mut scheduler);
+    assert!(get_queued_person_id_vec(&mut state).is_empty());
+
+    state.world.time.tick = 1;
+    Scheduler::tick(&mut state, &mut scheduler);
+    assert_eq!(
+        get_queued_person_id_vec(&mut state),
+        vec![PersonID::JUDGE_ID_3]
+    );
+
+    state.world.time.tick = 2;
+    Scheduler::tick(&mut state, &mut scheduler);
+    assert_eq!(
+        get_queued_person_id_vec(&mut state),
+        vec![PersonID::JUDGE_ID_2, PersonID::JUDGE_ID_1]
+    );
+
+    assert!(scheduler.job_map.is_empty());
+}
+
+#[test]
+fn repeating_jobs_reschedule_until_cancelled() {
+    let mut state = State::new();
+    let mut scheduler = Scheduler::new();
+
+    let job_id = Scheduler::schedule_repeating(
+        10,
+        5,
+        get_jump_command(PersonID::JUDGE_ID_1),
+        &mut scheduler,
+    );
+
+    let mut execution_tick_vec = Vec::new();
+
+    for tick in 0..30 {
+        state.world.time.tick = tick;
+
+        Scheduler::tick(&mut state, &mut scheduler);
+
+        if !get_queued_person_id_vec(&mut state).is_empty() {
+            execution_tick_vec.push(tick);
+        }
+
+        if tick == 20 {
+            assert!(Scheduler::cancel(job_id, &mut scheduler));
+        }
+    }
+
+    assert_eq!(execution_tick_vec, vec![10, 15, 20]);
+    assert!(!Scheduler::cancel(job_id, &mut scheduler));
+}
@@ -0,0 +1,144 @@
+use crate::{
+    simulation::{
+        constants::SCHEMATIC_DIRECTORY,
+        state::{
+            world::{
+                area::{
+                    self,
+                    area_id::AreaID,
+                    template::{SchematicTemplate, Template},
+                    Area,
+                },
+                block::{block_kind::BlockKind, block_state::BlockState},
+                grid::Direction,
+                schematic::Schematic,
+            },
+            State, World,
+        },
+        supervisor::{
+            message::{SchematicSetData, SeedData},
+            recorder::RecorderMode,
+            Message,
+        },
+        Simulation,
+    },
+    tests::common::create_simulation,
+    utils::ldmath::IntBox,
+};
+use std::path::Path;
+use ultraviolet::IVec3;
+
+#[test]
+fn schematics_round_trip_and_construct_rotated() {
+    let mut state = State::new();
+
+    World::set_block(
+        IVec3::new(0, 0, 0),
+        &Direction::East,
+        &BlockKind::EagleStone,
+        &mut state.world,
+    );
+
+    World::set_block(
+        IVec3::new(2, 1, 0),
+        &Direction::North,
+        &BlockKind::DoorLower1,
+        &mut state.world,
+    );
+
+    let mut door_block = World::get_block(IVec3::new(2, 1, 0), &state.world.sector_map).unwrap();
+
+    let BlockState::Door(door_data) = &mut door_block.block_state else {
+        panic!("Door is missing its state");
+    };
+
+    door_data.is_open = true;
+
+    World::store_block(door_block, &mut state.world);
+
+    let int_box = IntBox::new(IVec3::new(0, 0, 0), IVec3::new(2, 1, 0));
+    let schematic = Schematic::from_region("test_room", &int_box, &mut state.world);
+
+    assert_eq!(schematic.size, IVec3::new(3, 2, 1));
+    assert_eq!(schematic.schematic_block_vec.len(), 2);
+
+    let path = std::env::temp_dir().join("last_ditch_schematic_test.json");
+
+    Schematic::write(&path, &schematic).expect("Failed to write schematic");
+
+    let schematic = Schematic::read(&path).expect("Failed to read schematic");
+
+    std::fs::remove_file(&path).unwrap();
+
+    state
+        .world
+        .schematic_map
+        .insert(schematic.name.clone(), schematic);
+
+    let mut area = Area::new(AreaID::new(1));
+    area.grid_position = IVec3::new(30, 30, 0);
+    area.size = IVec3::new(4, 4, 2);
+    area.direction = Direction::West;
+    area.style = area::Style::Schematic {
+        name: String::from("test_room"),
+    };
+
+    SchematicTemplate::construct(&area, &mut state.world);
+
+    let stone_grid_position = Area::set_local(IVec3::new(0, 0, 0), IVec3::one(), &area).min;
+    let door_grid_position = Area::set_local(IVec3::new(2, 1, 0), IVec3::one(), &area).min;
+
+    let stone_block = World::get_block(stone_grid_position, &state.world.sector_map)
+        .expect("Stone was not constructed");
+
+    assert_eq!(stone_block.block_kind, BlockKind::EagleStone);
+    assert_eq!(stone_block.direction, Direction::North);
+
+    let door_block = World::get_block(door_grid_position, &state.world.sector_map)
+        .expect("Door was not constructed");
+
+    assert_eq!(door_block.direction, Direction::West);
+    assert!(matches!(
+        door_block.block_state,
+        BlockState::Door(door_data) if door_data.is_open
+    ));
+}
+
+#[test]
+fn schematic_sets_arrive_by_message_and_survive_generation() {
+    let schematic_map = Schematic::load_directory(Path::new(SCHEMATIC_DIRECTORY));
+
+    let guard_post = schematic_map
+        .get("guard_post")
+        .expect("Example schematic is missing");
+
+    assert_eq!(guard_post.size, IVec3::new(3, 3, 2));
+    assert_eq!(guard_post.schematic_block_vec.len(), 9);
+
+    let message = Message::SetSchematics(SchematicSetData { schematic_map });
+
+    let message_bytes = bincode::serialize(&message).expect("Failed to serialize message");
+    let message: Message =
+        bincode::deserialize(&message_bytes).expect("Failed to deserialize message");
+
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    message_tx
+        .send(Message::SetSeed(SeedData { seed: 813 }))
+        .unwrap();
+    message_tx.send(message).unwrap();
+    message_tx.send(Message::Generate).unwrap();
+
+    Simulation::run_headless(
+        4,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert!(simulation
+        .state
+        .world
+        .schematic_map
+        .contains_key("guard_post"));
+}
@@ -0,0 +1,165 @@
+use crate::{
+    interface::{
+        asset_manager::layer_index::LayerIndex,
+        renderer::sector_renderer::{sector_face::SectorFace, sector_model::SectorModel},
+    },
+    simulation::{
+        constants::*,
+        state::world::{
+            block::{block_kind::BlockKind, Block},
+            grid::{self, Direction},
+            sector::{sector_index::SectorIndex, Sector},
+        },
+        supervisor::viewer::view::{sector_view::SectorView, world_view::WorldView},
+    },
+};
+use std::collections::HashMap;
+use ultraviolet::{IVec3, Vec3};
+
+fn create_sector_map(grid_position_vec: &[IVec3]) -> HashMap<SectorIndex, Sector> {
+    let mut sector_map = HashMap::new();
+
+    for grid_position in grid_position_vec {
+        let (sector_index, cell_index) = grid::grid_position_to_indices(*grid_position);
+
+        let sector = sector_map
+            .entry(sector_index)
+            .or_insert_with(|| Sector::new(sector_index));
+
+        let block = Block::new(&BlockKind::Metal1, *grid_position, &Direction::North);
+
+        Sector::set_block(cell_index, Some(block), sector);
+    }
+
+    sector_map
+}
+
+fn create_world_view(sector_map: &HashMap<SectorIndex, Sector>) -> WorldView {
+    let mut world_view = WorldView::default();
+
+    for (sector_index, sector) in sector_map {
+        world_view
+            .sector_view_map
+            .insert(*sector_index, SectorView::new_from_sector(sector));
+    }
+
+    world_view
+}
+
+fn get_up_ao_array(grid_position: IVec3, world_view: &WorldView) -> [u8; 4] {
+    let (sector_index, _) = grid::grid_position_to_indices(grid_position);
+    let sector_view = &world_view.sector_view_map[&sector_index];
+
+    SectorModel::get_face_ao_array(grid_position, &Direction::Up, sector_view, world_view)
+}
+
+#[test]
+fn vertex_ao_counts_side_and_corner_occluders() {
+    assert_eq!(SectorModel::get_vertex_ao(false, false, false), 3);
+    assert_eq!(SectorModel::get_vertex_ao(true, false, false), 2);
+    assert_eq!(SectorModel::get_vertex_ao(false, false, true), 2);
+    assert_eq!(SectorModel::get_vertex_ao(true, false, true), 1);
+    assert_eq!(SectorModel::get_vertex_ao(true, true, false), 0);
+    assert_eq!(SectorModel::get_vertex_ao(true, true, true), 0);
+}
+
+#[test]
+fn face_ao_darkens_vertices_beside_occluders() {
+    let floor_grid_position = IVec3::new(0, 0, 0);
+
+    let sector_map = create_sector_map(&[floor_grid_position]);
+    let world_view = create_world_view(&sector_map);
+
+    assert_eq!(
+        get_up_ao_array(floor_grid_position, &world_view),
+        [3, 3, 3, 3]
+    );
+
+    let sector_map = create_sector_map(&[floor_grid_position, IVec3::new(1, 0, 1)]);
+    let world_view = create_world_view(&sector_map);
+
+    assert_eq!(
+        get_up_ao_array(floor_grid_position, &world_view),
+        [3, 2, 2, 3]
+    );
+
+    let sector_map = create_sector_map(&[
+        floor_grid_position,
+        IVec3::new(1, 0, 1),
+        IVec3::new(0, 1, 1),
+    ]);
+    let world_view = create_world_view(&sector_map);
+
+    assert_eq!(
+        get_up_ao_array(floor_grid_position, &world_view),
+        [3, 2, 0, 2]
+    );
+
+    let sector_map = create_sector_map(&[floor_grid_position, IVec3::new(-1, -1, 1)]);
+    let world_view = create_world_view(&sector_map);
+
+    assert_eq!(
+        get_up_ao_array(floor_grid_position, &world_view),
+        [2, 3, 3, 3]
+    );
+}
+
+#[test]
+fn face_ao_reads_neighbor_sector_views() {
+    let sector_radius_in_cells = SECTOR_RADIUS_IN_CELLS as i32;
+
+    let edge_grid_position = IVec3::new(sector_radius_in_cells, 0, 0);
+    let neighbor_grid_position = IVec3::new(sector_radius_in_cells + 1, 0, 1);
+
+    let sector_map = create_sector_map(&[edge_grid_position, neighbor_grid_position]);
+    let mut world_view = create_world_view(&sector_map);
+
+    let (edge_sector_index, _) = grid::grid_position_to_indices(edge_grid_position);
+    let (neighbor_sector_index, _) = grid::grid_position_to_indices(neighbor_grid_position);
+
+    assert_ne!(edge_sector_index, neighbor_sector_index);
+
+    assert_eq!(
+        get_up_ao_array(edge_grid_position, &world_view),
+        [3, 2, 2, 3]
+    );
+
+    world_view.sector_view_map.remove(&neighbor_sector_index);
+
+    assert_eq!(
+        get_up_ao_array(edge_grid_position, &world_view),
+        [3, 3, 3, 3]
+    );
+}
+
+#[test]
+fn faces_merge_only_when_ao_matches() {
+    let layer_index = LayerIndex::new(0);
+
+    let sector_face1 = SectorFace::new(
+        Vec3::new(0.0, 0.0, 0.5),
+        Direction::Up,
+        layer_index,
+        [LIGHT_LEVEL_MAX, 0],
+        [3, 3, 3, 3],
+    );
+
+    let sector_face2 = SectorFace::new(
+        Vec3::new(1.0, 0.0, 0.5),
+        Direction::Up,
+        layer_index,
+        [LIGHT_LEVEL_MAX, 0],
+        [3, 3, 3, 3],
+    );
+
+    let sector_face3 = SectorFace::new(
+        Vec3::new(2.0, 0.0, 0.5),
+        Direction::Up,
+        layer_index,
+        [LIGHT_LEVEL_MAX, 0],
+        [3, 2, 2, 3],
+    );
+
+    assert!(SectorFace::can_merge(&sector_face1, &sector_face2));
+    assert!(!SectorFace::can_merge(&sector_face2, &sector_face3));
+}
@@ -0,0 +1,120 @@
+use crate::{
+    simulation::{
+        constants::SAVE_AUTOSAVE_NAME,
+        state::SaveFile,
+        supervisor::{recorder::RecorderMode, supervisor_status::SupervisorStatus, Message},
+        Simulation,
+    },
+    tests::common::{create_simulation, send_generate_messages},
+};
+
+#[test]
+fn paused_supervisor_only_advances_on_step() {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    send_generate_messages(813, &message_tx);
+
+    Simulation::run_headless(
+        10,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let paused_tick = simulation.state.world.time.tick;
+
+    message_tx.send(Message::TogglePause).unwrap();
+
+    Simulation::run_headless(
+        10,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert_eq!(simulation.state.world.time.tick, paused_tick);
+
+    message_tx.send(Message::Step).unwrap();
+    message_tx.send(Message::Step).unwrap();
+
+    Simulation::run_headless(
+        10,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert_eq!(simulation.state.world.time.tick, paused_tick + 2);
+}
+
+#[test]
+fn time_scale_is_clamped() {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    send_generate_messages(813, &message_tx);
+
+    for _ in 0..8 {
+        message_tx.send(Message::SpeedUp).unwrap();
+    }
+
+    Simulation::run_headless(
+        1,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert_eq!(simulation.supervisor.time_scale, 16.0);
+
+    for _ in 0..12 {
+        message_tx.send(Message::SlowDown).unwrap();
+    }
+
+    Simulation::run_headless(
+        1,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert_eq!(simulation.supervisor.time_scale, 0.25);
+}
+
+#[test]
+fn quit_without_autosave_leaves_no_save_behind() {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    let autosave_path = SaveFile::get_path(SAVE_AUTOSAVE_NAME);
+    let modified_option = std::fs::metadata(&autosave_path)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+
+    send_generate_messages(813, &message_tx);
+
+    Simulation::run_headless(
+        10,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    message_tx.send(Message::Quit).unwrap();
+
+    Simulation::run_headless(
+        10,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert_eq!(
+        simulation.supervisor.supervisor_status,
+        SupervisorStatus::Done
+    );
+    assert_eq!(
+        std::fs::metadata(&autosave_path)
+            .and_then(|metadata| metadata.modified())
+            .ok(),
+        modified_option
+    );
+}
@@ -0,0 +1,185 @@
+use crate::{
+    simulation::{
+        state::{
+            population::{person::person_id::PersonID, sight::Sight},
+            world::{
+                block::block_kind::BlockKind,
+                grid::{self, Direction},
+            },
+        },
+        supervisor::{
+            message::{SetBlockData, TeleportData},
+            recorder::RecorderMode,
+            viewer::view::{WorldView, WorldViewDelta},
+            Message, Viewer,
+        },
+        Simulation,
+    },
+    tests::common::{
+        create_simulation, get_changed_sector_count, get_visible_sector_version_map,
+        send_generate_messages,
+    },
+};
+use std::collections::HashSet;
+use ultraviolet::{IVec3, Vec3};
+
+fn receive_world_view_delta_vec(
+    world_view_delta_rx: &crossbeam::channel::Receiver<WorldViewDelta>,
+) -> Vec<WorldViewDelta> {
+    world_view_delta_rx.try_iter().collect()
+}
+
+#[test]
+fn world_view_is_published_as_deltas() {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    let (view_input, mut view_output) = Viewer::create_view_channel();
+
+    Viewer::attach(
+        PersonID::JUDGE_ID_1,
+        view_input,
+        &mut simulation.supervisor.viewer,
+    );
+
+    send_generate_messages(813, &message_tx);
+
+    Simulation::run_headless(
+        60,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let (_, world_view) = Viewer::get_view(&mut view_output);
+
+    let visible_sector_index_set: HashSet<_> =
+        Viewer::get_visible_sector_index_vec(&PersonID::JUDGE_ID_1, &simulation.state)
+            .into_iter()
+            .collect();
+
+    let retained_sector_index_set: HashSet<_> =
+        world_view.sector_view_map.keys().copied().collect();
+
+    assert!(!visible_sector_index_set.is_empty());
+    assert_eq!(retained_sector_index_set, visible_sector_index_set);
+
+    Simulation::run_headless(
+        10,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    assert!(receive_world_view_delta_vec(&view_output.world_view_delta_rx).is_empty());
+
+    let judge_world_position = simulation.state.population.person_map[&PersonID::JUDGE_ID_1]
+        .transform
+        .world_position;
+
+    let set_block_data = SetBlockData {
+        person_id: PersonID::JUDGE_ID_1,
+        grid_position: IVec3::new(
+            judge_world_position.x.round() as i32,
+            judge_world_position.y.round() as i32,
+            judge_world_position.z.round() as i32 + 3,
+        ),
+        block_kind_option: Some(BlockKind::EagleStone),
+        direction: Direction::East,
+    };
+
+    let sector_version_map =
+        get_visible_sector_version_map(&PersonID::JUDGE_ID_1, &simulation.state);
+    let sector_index = grid::grid_position_to_sector_index(set_block_data.grid_position);
+
+    message_tx.send(Message::SetBlock(set_block_data)).unwrap();
+
+    Simulation::run_headless(
+        2,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let world_view_delta_vec = receive_world_view_delta_vec(&view_output.world_view_delta_rx);
+
+    // Light changes republish the sectors they reach, not the whole view
+    assert_eq!(world_view_delta_vec.len(), 1);
+    assert_eq!(
+        world_view_delta_vec[0].sector_view_vec.len(),
+        get_changed_sector_count(&sector_version_map, &simulation.state)
+    );
+    assert!(world_view_delta_vec[0].sector_view_vec.len() < sector_version_map.len());
+    assert!(world_view_delta_vec[0]
+        .sector_view_vec
+        .iter()
+        .any(|sector_view| sector_view.sector_index == sector_index));
+    assert!(world_view_delta_vec[0].entered_sector_index_vec.is_empty());
+    assert!(world_view_delta_vec[0].exited_sector_index_vec.is_empty());
+
+    let judge = simulation
+        .state
+        .population
+        .person_map
+        .get_mut(&PersonID::JUDGE_ID_1)
+        .unwrap();
+
+    Sight::set_range(10.0, &mut judge.sight);
+
+    Simulation::run_headless(
+        1,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let world_view_delta_vec = receive_world_view_delta_vec(&view_output.world_view_delta_rx);
+
+    assert_eq!(world_view_delta_vec.len(), 1);
+    assert!(!world_view_delta_vec[0].exited_sector_index_vec.is_empty());
+    assert!(world_view_delta_vec[0].entered_sector_index_vec.is_empty());
+
+    for world_view_delta in world_view_delta_vec {
+        WorldView::apply_delta(world_view_delta, &mut view_output.world_view);
+    }
+
+    let teleport_data = TeleportData {
+        person_id: PersonID::JUDGE_ID_1,
+        world_position: Vec3::new(40.0, 40.0, 40.0),
+    };
+
+    message_tx.send(Message::Teleport(teleport_data)).unwrap();
+
+    Simulation::run_headless(
+        2,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let world_view_delta_vec = receive_world_view_delta_vec(&view_output.world_view_delta_rx);
+
+    // Sectors around the new position stream in over the following tick
+    assert!(!world_view_delta_vec.is_empty());
+    assert!(!world_view_delta_vec[0].exited_sector_index_vec.is_empty());
+    assert!(world_view_delta_vec
+        .iter()
+        .any(|world_view_delta| !world_view_delta.entered_sector_index_vec.is_empty()));
+
+    for world_view_delta in world_view_delta_vec {
+        WorldView::apply_delta(world_view_delta, &mut view_output.world_view);
+    }
+
+    let visible_sector_index_set: HashSet<_> =
+        Viewer::get_visible_sector_index_vec(&PersonID::JUDGE_ID_1, &simulation.state)
+            .into_iter()
+            .collect();
+
+    let retained_sector_index_set: HashSet<_> = view_output
+        .world_view
+        .sector_view_map
+        .keys()
+        .copied()
+        .collect();
+
+    assert_eq!(retained_sector_index_set, visible_sector_index_set);
+}
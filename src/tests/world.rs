@@ -0,0 +1,313 @@
+use crate::{
+    simulation::{
+        constants::SECTOR_SIZE_IN_CELLS,
+        state::{
+            population::person::person_id::PersonID,
+            world::{
+                block::{block_kind::BlockKind, Block},
+                grid::{self, Direction},
+                sector::{block_storage::BlockStorage, sector_index::SectorIndex, Sector},
+                sector_store::SectorStore,
+            },
+            SaveFile, World,
+        },
+        supervisor::{
+            message::{SetBlockData, TeleportData},
+            recorder::RecorderMode,
+            Message,
+        },
+        Simulation,
+    },
+    tests::common::{create_simulation, send_generate_messages},
+};
+use strum::VariantArray;
+use ultraviolet::{IVec3, Vec3};
+
+#[test]
+fn grid_conversions_hold_at_arbitrary_sector_coordinates() {
+    let sector_size_in_cells = SECTOR_SIZE_IN_CELLS as i32;
+
+    for grid_position in [
+        IVec3::new(0, 0, 0),
+        IVec3::new(-9, 8, -8),
+        IVec3::new(-1_000, 2_345, -67_890),
+        IVec3::new(500_000, -500_000, 123_456),
+    ] {
+        let (sector_index, cell_index) = grid::grid_position_to_indices(grid_position);
+
+        assert_eq!(
+            grid::indices_to_grid_position(sector_index, cell_index),
+            grid_position
+        );
+
+        let world_position = grid::grid_position_to_world_position(grid_position);
+
+        assert_eq!(
+            grid::world_position_to_grid_position(world_position),
+            grid_position
+        );
+
+        let sector_coordinate = SectorIndex::to_sector_coordinate(&sector_index);
+        let offset = grid_position - grid::sector_coordinate_to_grid_position(sector_coordinate);
+
+        assert!(offset.abs().component_max() <= sector_size_in_cells / 2);
+    }
+
+    assert_eq!(
+        grid::grid_position_to_sector_coordinate(IVec3::new(-9, 8, 9)),
+        IVec3::new(-1, 0, 1)
+    );
+}
+
+#[test]
+fn sectors_stream_around_persons() {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    send_generate_messages(813, &message_tx);
+
+    Simulation::run_headless(
+        20,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let far_world_position = Vec3::new(4_000.0, -4_000.0, 2_000.0);
+    let far_grid_position = grid::world_position_to_grid_position(far_world_position);
+    let block_grid_position = far_grid_position - IVec3::new(0, 0, 3);
+
+    message_tx
+        .send(Message::Teleport(TeleportData {
+            person_id: PersonID::JUDGE_ID_1,
+            world_position: far_world_position,
+        }))
+        .unwrap();
+
+    message_tx
+        .send(Message::SetBlock(SetBlockData {
+            person_id: PersonID::JUDGE_ID_1,
+            grid_position: block_grid_position,
+            direction: Direction::East,
+            block_kind_option: Some(BlockKind::EagleStone),
+        }))
+        .unwrap();
+
+    Simulation::run_headless(
+        2,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let world = &simulation.state.world;
+    let block_sector_index = grid::grid_position_to_sector_index(block_grid_position);
+
+    assert!(world.sector_map.contains_key(&block_sector_index));
+    assert!(World::get_block(block_grid_position, &world.sector_map).is_some());
+
+    let hit = World::raycast_to_block(
+        grid::grid_position_to_world_position(far_grid_position),
+        -Vec3::unit_z(),
+        8.0,
+        world,
+    );
+
+    assert_eq!(hit, Some((block_grid_position, IVec3::unit_z())));
+
+    message_tx
+        .send(Message::Teleport(TeleportData {
+            person_id: PersonID::JUDGE_ID_1,
+            world_position: -far_world_position,
+        }))
+        .unwrap();
+
+    Simulation::run_headless(
+        2,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let world = &simulation.state.world;
+
+    assert!(!world.sector_map.contains_key(&block_sector_index));
+    assert!(SectorStore::contains(
+        &block_sector_index,
+        &world.sector_store
+    ));
+    assert!(World::get_block(block_grid_position, &world.sector_map).is_none());
+
+    let save_path = std::env::temp_dir().join("last_ditch_world_stream_test.ldsave");
+
+    SaveFile::write(
+        &save_path,
+        &simulation.state,
+        &simulation.supervisor.scheduler,
+    )
+    .expect("Failed to write save");
+
+    let (state, _) = SaveFile::read(&save_path).expect("Failed to read save");
+
+    std::fs::remove_file(&save_path).unwrap();
+
+    assert!(SectorStore::contains(
+        &block_sector_index,
+        &state.world.sector_store
+    ));
+
+    simulation.state = state;
+
+    message_tx
+        .send(Message::Teleport(TeleportData {
+            person_id: PersonID::JUDGE_ID_1,
+            world_position: far_world_position,
+        }))
+        .unwrap();
+
+    Simulation::run_headless(
+        2,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let world = &simulation.state.world;
+
+    assert!(world.sector_map.contains_key(&block_sector_index));
+    assert!(!SectorStore::contains(
+        &block_sector_index,
+        &world.sector_store
+    ));
+
+    let block = World::get_block(block_grid_position, &world.sector_map).expect("Block was lost");
+
+    assert_eq!(block.block_kind, BlockKind::EagleStone);
+}
+
+#[test]
+fn sector_write_failure_keeps_sector_resident() {
+    let (mut simulation, message_tx) = create_simulation(RecorderMode::Off);
+
+    send_generate_messages(813, &message_tx);
+
+    Simulation::run_headless(
+        20,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let far_world_position = Vec3::new(4_000.0, -4_000.0, 2_000.0);
+    let block_grid_position =
+        grid::world_position_to_grid_position(far_world_position) - IVec3::new(0, 0, 3);
+
+    message_tx
+        .send(Message::Teleport(TeleportData {
+            person_id: PersonID::JUDGE_ID_1,
+            world_position: far_world_position,
+        }))
+        .unwrap();
+
+    message_tx
+        .send(Message::SetBlock(SetBlockData {
+            person_id: PersonID::JUDGE_ID_1,
+            grid_position: block_grid_position,
+            direction: Direction::East,
+            block_kind_option: Some(BlockKind::EagleStone),
+        }))
+        .unwrap();
+
+    Simulation::run_headless(
+        2,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    let blocking_file_path = std::env::temp_dir().join(format!(
+        "last_ditch_sector_store_blocker_{}",
+        std::process::id()
+    ));
+
+    std::fs::write(&blocking_file_path, b"").expect("Failed to write blocking file");
+
+    simulation.state.world.sector_store.directory = blocking_file_path.join("store");
+
+    message_tx
+        .send(Message::Teleport(TeleportData {
+            person_id: PersonID::JUDGE_ID_1,
+            world_position: -far_world_position,
+        }))
+        .unwrap();
+
+    Simulation::run_headless(
+        2,
+        &mut simulation.supervisor,
+        &mut simulation.state,
+        |_, _| (),
+    );
+
+    std::fs::remove_file(&blocking_file_path).expect("Failed to remove blocking file");
+
+    let world = &simulation.state.world;
+    let block_sector_index = grid::grid_position_to_sector_index(block_grid_position);
+
+    assert!(world.sector_map.contains_key(&block_sector_index));
+    assert!(!SectorStore::contains(
+        &block_sector_index,
+        &world.sector_store
+    ));
+    assert!(World::get_block(block_grid_position, &world.sector_map).is_some());
+}
+
+#[test]
+fn block_storage_packs_and_collapses_to_uniform() {
+    let sector_index = SectorIndex::new(IVec3::new(-3, 7, 1));
+    let mut sector = Sector::new(sector_index);
+
+    assert!(Sector::is_empty(&sector));
+
+    let cell_index_vec = grid::cell_index_vec();
+
+    for (index, cell_index) in cell_index_vec.iter().enumerate() {
+        let block_kind = &BlockKind::VARIANTS[index % BlockKind::VARIANTS.len()];
+        let direction = &Direction::ALL[index % 4];
+        let grid_position = grid::indices_to_grid_position(sector_index, *cell_index);
+
+        let block = Block::new(block_kind, grid_position, direction);
+
+        Sector::set_block(*cell_index, Some(block), &mut sector);
+    }
+
+    assert!(sector.block_storage.bits_per_index >= 8);
+
+    for (index, cell_index) in cell_index_vec.iter().enumerate() {
+        let block = Sector::get_block(*cell_index, &sector).expect("Block was not stored");
+
+        assert_eq!(
+            block.block_kind,
+            BlockKind::VARIANTS[index % BlockKind::VARIANTS.len()]
+        );
+        assert_eq!(block.direction, Direction::ALL[index % 4]);
+        assert_eq!(
+            block.grid_position,
+            grid::indices_to_grid_position(sector_index, *cell_index)
+        );
+    }
+
+    for cell_index in &cell_index_vec {
+        let grid_position = grid::indices_to_grid_position(sector_index, *cell_index);
+        let block = Block::new(&BlockKind::EagleStone, grid_position, &Direction::East);
+
+        Sector::set_block(*cell_index, Some(block), &mut sector);
+    }
+
+    assert!(BlockStorage::is_uniform(&sector.block_storage));
+    assert!(sector.block_storage.word_vec.is_empty());
+
+    for cell_index in &cell_index_vec {
+        Sector::set_block(*cell_index, None, &mut sector);
+    }
+
+    assert!(Sector::is_empty(&sector));
+}
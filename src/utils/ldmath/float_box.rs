@@ -1,7 +1,8 @@
 use crate::simulation::constants::CELL_RADIUS_IN_METERS;
+use serde::{Deserialize, Serialize};
 use ultraviolet::Vec3;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FloatBox {
     pub center_position: Vec3,
     pub radius: Vec3,
@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use ultraviolet::IVec3;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IntBox {
     pub min: IVec3,
     pub max: IVec3,